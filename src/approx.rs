@@ -0,0 +1,268 @@
+use std::fmt::Write;
+
+use crate::{scalar::Field, LalgrsMatrix, LalgrsVector};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Approximate equality
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maximum number of differing elements listed by the assertion macros
+const MAX_REPORTED_MISMATCHES: usize = 20;
+
+/// ## Whether `a` and `b` differ by at most `abs_tol`, or by at most `rel_tol` times the larger of their magnitudes
+/// Equal values, including equal infinities, always compare equal. NaN never compares equal
+pub fn scalar_approx_eq<T: Field>(a: &T, b: &T, abs_tol: f64, rel_tol: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    let difference = (a.clone() - b.clone()).magnitude();
+    difference <= abs_tol || difference <= rel_tol * a.magnitude().max(b.magnitude())
+}
+
+/// ## Distance between floating point numbers in units in the last place
+/// The distance is the number of representable values between the two numbers, so that it adapts to their magnitude.
+/// Implemented for `f32` and `f64`
+pub trait Ulps {
+    /// ## Number of representable values between `self` and `other`
+    /// Zeros of either sign are zero apart. If either value is NaN, returns `None`
+    fn ulps_distance(&self, other: &Self) -> Option<u64>;
+}
+
+macro_rules! impl_ulps {
+    ($($t:ty => $bits:ty),*) => {
+        $(
+            impl Ulps for $t {
+                fn ulps_distance(&self, other: &Self) -> Option<u64> {
+                    if self.is_nan() || other.is_nan() {
+                        return None;
+                    }
+                    // Maps the bit patterns to integers ordered like the numbers they represent
+                    let ordered = |value: $t| {
+                        let bits = value.to_bits() as $bits;
+                        if bits < 0 {
+                            <$bits>::MIN - bits
+                        } else {
+                            bits
+                        }
+                    };
+                    let distance = (ordered(*self) as i128 - ordered(*other) as i128).unsigned_abs();
+                    Some(u64::try_from(distance).unwrap_or(u64::MAX))
+                }
+            }
+        )*
+    };
+}
+
+impl_ulps!(f32 => i32, f64 => i64);
+
+impl<T: Field> LalgrsMatrix<T> {
+    /// ## Whether both matrices have the same shape, and every pair of elements is approximately equal
+    /// Elements are compared as in `scalar_approx_eq`
+    pub fn approx_eq(&self, other: &LalgrsMatrix<T>, abs_tol: f64, rel_tol: f64) -> bool {
+        self.shape() == other.shape()
+            && self
+                .as_column_major_slice()
+                .iter()
+                .zip(other.as_column_major_slice())
+                .all(|(a, b)| scalar_approx_eq(a, b, abs_tol, rel_tol))
+    }
+}
+
+impl<T: Field + Ulps> LalgrsMatrix<T> {
+    /// ## Whether both matrices have the same shape, and every pair of elements is at most `max_ulps` representable values apart
+    pub fn ulps_eq(&self, other: &LalgrsMatrix<T>, max_ulps: u64) -> bool {
+        self.shape() == other.shape()
+            && self
+                .as_column_major_slice()
+                .iter()
+                .zip(other.as_column_major_slice())
+                .all(|(a, b)| a.ulps_distance(b).is_some_and(|d| d <= max_ulps))
+    }
+}
+
+impl<T: Field> LalgrsVector<T> {
+    /// ## Whether both vectors have the same size, and every pair of elements is approximately equal
+    /// Elements are compared as in `scalar_approx_eq`
+    pub fn approx_eq(&self, other: &LalgrsVector<T>, abs_tol: f64, rel_tol: f64) -> bool {
+        self.size() == other.size()
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .all(|(a, b)| scalar_approx_eq(a, b, abs_tol, rel_tol))
+    }
+}
+
+impl<T: Field + Ulps> LalgrsVector<T> {
+    /// ## Whether both vectors have the same size, and every pair of elements is at most `max_ulps` representable values apart
+    pub fn ulps_eq(&self, other: &LalgrsVector<T>, max_ulps: u64) -> bool {
+        self.size() == other.size()
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .all(|(a, b)| a.ulps_distance(b).is_some_and(|d| d <= max_ulps))
+    }
+}
+
+/// ## Lists the elements that are not approximately equal, followed by the largest deviation
+/// `elements` yields the label of each position with the two values found there
+fn mismatch_report<'a, T: Field + 'a>(
+    elements: impl Iterator<Item = (String, &'a T, &'a T)>,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> Option<String> {
+    let mut report = String::new();
+    let mut mismatches = 0;
+    let mut largest: Option<(String, f64)> = None;
+    for (position, a, b) in elements {
+        let deviation = if a == b {
+            0.0
+        } else {
+            (a.clone() - b.clone()).magnitude()
+        };
+        // A NaN deviation is the largest one
+        let replace = match &largest {
+            None => true,
+            Some((_, max)) => !max.is_nan() && (deviation > *max || deviation.is_nan()),
+        };
+        if replace {
+            largest = Some((position.clone(), deviation));
+        }
+        if !scalar_approx_eq(a, b, abs_tol, rel_tol) {
+            if mismatches < MAX_REPORTED_MISMATCHES {
+                let _ = writeln!(
+                    report,
+                    "  {position}: left = {a}, right = {b}, difference = {deviation}"
+                );
+            }
+            mismatches += 1;
+        }
+    }
+    if mismatches == 0 {
+        return None;
+    }
+    if mismatches > MAX_REPORTED_MISMATCHES {
+        let _ = writeln!(
+            report,
+            "  ... and {} more",
+            mismatches - MAX_REPORTED_MISMATCHES
+        );
+    }
+    if let Some((position, deviation)) = largest {
+        let _ = write!(
+            report,
+            "{mismatches} differing elements, maximum deviation {deviation} at {position} \
+             (absolute tolerance {abs_tol}, relative tolerance {rel_tol})"
+        );
+    }
+    Some(report)
+}
+
+/// ## Description of the differences between two matrices, used by `assert_matrix_approx_eq!`
+/// Returns `None` if the matrices are approximately equal
+#[doc(hidden)]
+pub fn matrix_mismatch_report<T: Field>(
+    left: &LalgrsMatrix<T>,
+    right: &LalgrsMatrix<T>,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> Option<String> {
+    if left.shape() != right.shape() {
+        return Some(format!(
+            "shapes differ: left is {}, right is {}",
+            left.shape(),
+            right.shape()
+        ));
+    }
+    let elements = (0..left.rows()).flat_map(|i| {
+        (0..left.columns()).map(move |j| (format!("({i}, {j})"), &left[(i, j)], &right[(i, j)]))
+    });
+    mismatch_report(elements, abs_tol, rel_tol)
+}
+
+/// ## Description of the differences between two vectors, used by `assert_vector_approx_eq!`
+/// Returns `None` if the vectors are approximately equal
+#[doc(hidden)]
+pub fn vector_mismatch_report<T: Field>(
+    left: &LalgrsVector<T>,
+    right: &LalgrsVector<T>,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> Option<String> {
+    if left.size() != right.size() {
+        return Some(format!(
+            "sizes differ: left has {} elements, right has {}",
+            left.size(),
+            right.size()
+        ));
+    }
+    let elements = left
+        .values
+        .iter()
+        .zip(right.values.iter())
+        .enumerate()
+        .map(|(i, (a, b))| (format!("[{i}]"), a, b));
+    mismatch_report(elements, abs_tol, rel_tol)
+}
+
+/// ## Asserts that two matrices are approximately equal, as `LalgrsMatrix::approx_eq`
+/// With a single tolerance, it is used as both the absolute and the relative tolerance.
+/// On failure, prints the differing elements and the largest deviation.
+///
+/// ```
+/// # use lalgrs::{assert_matrix_approx_eq, LalgrsMatrix};
+/// let a = LalgrsMatrix::from_rows(vec![vec![1.0, 2.0]]).unwrap();
+/// let b = LalgrsMatrix::from_rows(vec![vec![1.0 + 1e-14, 2.0]]).unwrap();
+/// assert_matrix_approx_eq!(a, b, 1e-12);
+/// assert_matrix_approx_eq!(a, b, 1e-12, 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($left:expr, $right:expr, $tol:expr $(,)?) => {{
+        let tol = $tol;
+        $crate::assert_matrix_approx_eq!($left, $right, tol, tol)
+    }};
+    ($left:expr, $right:expr, $abs_tol:expr, $rel_tol:expr $(,)?) => {
+        if let Some(report) =
+            $crate::approx::matrix_mismatch_report(&$left, &$right, $abs_tol, $rel_tol)
+        {
+            panic!(
+                "assertion failed: `{} ≈ {}`\n{}",
+                stringify!($left),
+                stringify!($right),
+                report
+            );
+        }
+    };
+}
+
+/// ## Asserts that two vectors are approximately equal, as `LalgrsVector::approx_eq`
+/// With a single tolerance, it is used as both the absolute and the relative tolerance.
+/// On failure, prints the differing elements and the largest deviation.
+///
+/// ```
+/// # use lalgrs::{assert_vector_approx_eq, LalgrsVector};
+/// let a = LalgrsVector::new(vec![1.0, 2.0]);
+/// let b = LalgrsVector::new(vec![1.0, 2.0 + 1e-14]);
+/// assert_vector_approx_eq!(a, b, 1e-12);
+/// ```
+#[macro_export]
+macro_rules! assert_vector_approx_eq {
+    ($left:expr, $right:expr, $tol:expr $(,)?) => {{
+        let tol = $tol;
+        $crate::assert_vector_approx_eq!($left, $right, tol, tol)
+    }};
+    ($left:expr, $right:expr, $abs_tol:expr, $rel_tol:expr $(,)?) => {
+        if let Some(report) =
+            $crate::approx::vector_mismatch_report(&$left, &$right, $abs_tol, $rel_tol)
+        {
+            panic!(
+                "assertion failed: `{} ≈ {}`\n{}",
+                stringify!($left),
+                stringify!($right),
+                report
+            );
+        }
+    };
+}
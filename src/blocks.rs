@@ -0,0 +1,121 @@
+use std::ops::{Add, Mul};
+
+use crate::{scalar::Zero, LalgrsError, LalgrsMatrix, Shape};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Block matrices
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## For every row (or column) of the assembled matrix, the block it belongs to and its position inside that block
+fn block_positions(sizes: &[usize]) -> Vec<(usize, usize)> {
+    sizes
+        .iter()
+        .enumerate()
+        .flat_map(|(block, size)| (0..*size).map(move |k| (block, k)))
+        .collect()
+}
+
+impl<T: Add<T, Output = T> + Clone> LalgrsMatrix<T> {
+    /// ## Assembles a matrix from a grid of blocks, given row by row
+    /// All the blocks of a block row have the same number of rows, and all the blocks of a block column
+    /// the same number of columns. For example, the saddle point matrix `[A Bᵀ; B C]` is
+    /// `LalgrsMatrix::block(&[&[&a, &b_t], &[&b, &c]])`. An empty grid gives an empty matrix.
+    /// If the block rows have different lengths, or the blocks do not line up, returns an error
+    pub fn block(blocks: &[&[&LalgrsMatrix<T>]]) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        let Some(first_row) = blocks.first() else {
+            return Ok(LalgrsMatrix {
+                data: Vec::new(),
+                shape: Shape::new(0, 0),
+            });
+        };
+        if blocks.iter().any(|row| row.len() != first_row.len()) {
+            return Err(LalgrsError::InvalidMatrixDimensions);
+        }
+
+        let heights: Vec<usize> = blocks
+            .iter()
+            .map(|row| row.first().map_or(0, |m| m.rows()))
+            .collect();
+        let widths: Vec<usize> = first_row.iter().map(|m| m.columns()).collect();
+        for (row, height) in blocks.iter().zip(&heights) {
+            for (column, block) in row.iter().enumerate() {
+                let expected = Shape::new(*height, widths[column]);
+                if block.shape() != expected {
+                    return Err(LalgrsError::MismatchedMatrixDimensions {
+                        lhs: expected,
+                        rhs: block.shape(),
+                    });
+                }
+            }
+        }
+
+        let (rows, columns) = (block_positions(&heights), block_positions(&widths));
+        Ok(LalgrsMatrix::from_fn(rows.len(), columns.len(), |i, j| {
+            let ((block_row, row), (block_column, column)) = (rows[i], columns[j]);
+            blocks[block_row][block_column][(row, column)].clone()
+        }))
+    }
+
+    /// ## Places matrices with the same number of rows side by side
+    /// If the matrices have different numbers of rows, returns an error
+    pub fn hstack(matrices: &[&LalgrsMatrix<T>]) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        LalgrsMatrix::block(&[matrices])
+    }
+
+    /// ## Places matrices with the same number of columns on top of each other
+    /// If the matrices have different numbers of columns, returns an error
+    pub fn vstack(matrices: &[&LalgrsMatrix<T>]) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        let rows: Vec<[&LalgrsMatrix<T>; 1]> = matrices.iter().map(|m| [*m]).collect();
+        let rows: Vec<&[&LalgrsMatrix<T>]> = rows.iter().map(|r| r.as_slice()).collect();
+        LalgrsMatrix::block(&rows)
+    }
+
+    /// ## Splits the matrix into the rows before `row` and the rows from `row` onwards
+    /// If `row` is greater than the number of rows, returns an error
+    pub fn split_at_row(
+        &self,
+        row: usize,
+    ) -> Result<(LalgrsMatrix<T>, LalgrsMatrix<T>), LalgrsError> {
+        let top = self.submatrix(0, 0, Shape::new(row, self.columns()))?;
+        let bottom = self.submatrix(row, 0, Shape::new(self.rows() - row, self.columns()))?;
+        Ok((top.to_matrix(), bottom.to_matrix()))
+    }
+
+    /// ## Splits the matrix into the columns before `column` and the columns from `column` onwards
+    /// If `column` is greater than the number of columns, returns an error
+    pub fn split_at_column(
+        &self,
+        column: usize,
+    ) -> Result<(LalgrsMatrix<T>, LalgrsMatrix<T>), LalgrsError> {
+        let left = self.submatrix(0, 0, Shape::new(self.rows(), column))?;
+        let right = self.submatrix(0, column, Shape::new(self.rows(), self.columns() - column))?;
+        Ok((left.to_matrix(), right.to_matrix()))
+    }
+}
+
+impl<T: Add<T, Output = T> + Mul<T, Output = T> + Clone> LalgrsMatrix<T> {
+    /// ## Kronecker product `A ⊗ B`
+    /// The result is made of the blocks `a_ij * B`, so an `m x n` and a `p x q` matrix give an `mp x nq` matrix
+    pub fn kronecker(&self, other: &LalgrsMatrix<T>) -> LalgrsMatrix<T> {
+        let (p, q) = (other.rows(), other.columns());
+        LalgrsMatrix::from_fn(self.rows() * p, self.columns() * q, |i, j| {
+            self[(i / p, j / q)].clone() * other[(i % p, j % q)].clone()
+        })
+    }
+}
+
+impl<T: Add<T, Output = T> + Clone + Zero> LalgrsMatrix<T> {
+    /// ## Direct sum `A ⊕ B`, the block diagonal matrix `[A 0; 0 B]`
+    pub fn direct_sum(&self, other: &LalgrsMatrix<T>) -> LalgrsMatrix<T> {
+        let (rows, columns) = (self.rows(), self.columns());
+        LalgrsMatrix::from_fn(
+            rows + other.rows(),
+            columns + other.columns(),
+            |i, j| match (i < rows, j < columns) {
+                (true, true) => self[(i, j)].clone(),
+                (false, false) => other[(i - rows, j - columns)].clone(),
+                _ => T::zero(),
+            },
+        )
+    }
+}
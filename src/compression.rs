@@ -0,0 +1,177 @@
+use std::{
+    fmt::{self, Display},
+    path::Path,
+};
+
+use crate::{
+    decomposition::Svd,
+    image::{Image, NetpbmFormat},
+    LalgrsError, LalgrsMatrix,
+};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Image compression with truncated singular value decompositions
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Image stored as the largest singular triplets of each channel
+/// A channel of size `height x width` truncated to rank `k` needs `k * (height + width + 1)` values
+/// instead of `height * width`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressedImage {
+    factors: Vec<Svd>,
+    width: usize,
+    height: usize,
+    max_value: u16,
+}
+
+/// ## Size and quality of a compressed image compared to its original
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompressionReport {
+    pub rank: usize,
+    /// Number of values in the original image divided by the number of stored values
+    pub compression_ratio: f64,
+    /// Mean squared error between the intensities, in `[0, 1]`, of the original and reconstructed image
+    pub mse: f64,
+    /// Peak signal to noise ratio in decibels. Infinite when the reconstruction is exact
+    pub psnr: f64,
+}
+
+impl Display for CompressionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rank {}, compression ratio {:.2}, MSE {:.6}, PSNR {:.2} dB",
+            self.rank, self.compression_ratio, self.mse, self.psnr
+        )
+    }
+}
+
+/// ## Compresses every channel of `image` to its `rank` largest singular triplets
+/// If `rank` is larger than the smallest side of the image, all triplets are kept.
+/// If `rank` is zero or the image is empty, returns an error
+pub fn compress_image(image: &Image, rank: usize) -> Result<CompressedImage, LalgrsError> {
+    if rank == 0 {
+        return Err(LalgrsError::InvalidParameter {
+            name: "rank",
+            value: 0.0,
+        });
+    }
+    Ok(CompressedImage {
+        factors: image
+            .channels()
+            .iter()
+            .map(|c| Ok(c.svd()?.truncate(rank)))
+            .collect::<Result<_, LalgrsError>>()?,
+        width: image.width(),
+        height: image.height(),
+        max_value: image.max_value,
+    })
+}
+
+impl CompressedImage {
+    /// ## Number of singular triplets kept for each channel
+    pub fn rank(&self) -> usize {
+        self.factors.first().map_or(0, |f| f.singular_values.size())
+    }
+
+    /// ## Truncated decomposition of each channel
+    pub fn factors(&self) -> &[Svd] {
+        &self.factors
+    }
+
+    /// ## Number of values needed to store the truncated decompositions
+    pub fn stored_values(&self) -> usize {
+        self.factors.len() * self.rank() * (self.width + self.height + 1)
+    }
+
+    /// ## Number of values in the uncompressed image divided by the number of stored values
+    pub fn compression_ratio(&self) -> f64 {
+        (self.factors.len() * self.width * self.height) as f64 / self.stored_values() as f64
+    }
+
+    /// ## Multiplies the truncated decompositions back into an image
+    /// Intensities are clamped to `[0, 1]`
+    pub fn reconstruct(&self) -> Image {
+        let channels = self
+            .factors
+            .iter()
+            .map(|f| {
+                let channel = f.reconstruct();
+                LalgrsMatrix::from_fn(channel.rows(), channel.columns(), |i, j| {
+                    channel[(i, j)].clamp(0.0, 1.0)
+                })
+            })
+            .collect();
+        Image::from_channels(channels, self.max_value)
+    }
+
+    /// ## Saves the reconstructed image to a file in the given Netpbm format
+    pub fn save<P: AsRef<Path>>(&self, path: P, format: NetpbmFormat) -> Result<(), LalgrsError> {
+        self.reconstruct().save(path, format)
+    }
+
+    /// ## Compares the reconstruction with the original image
+    /// If `original` does not have the size and channels of the compressed image, returns an error
+    pub fn report(&self, original: &Image) -> Result<CompressionReport, LalgrsError> {
+        let mse = mean_squared_error(original, &self.reconstruct())?;
+        Ok(CompressionReport {
+            rank: self.rank(),
+            compression_ratio: self.compression_ratio(),
+            mse,
+            psnr: psnr_from_mse(mse),
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Quality metrics
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Mean squared error between the intensities of two images, over all pixels and channels
+/// If the images have different sizes or numbers of channels, returns an error
+pub fn mean_squared_error(lhs: &Image, rhs: &Image) -> Result<f64, LalgrsError> {
+    if lhs.channels().len() != rhs.channels().len() {
+        return Err(LalgrsError::InvalidImage(format!(
+            "cannot compare an image with {} channels to an image with {} channels",
+            lhs.channels().len(),
+            rhs.channels().len()
+        )));
+    }
+    let mut sum = 0.0;
+    let mut count = 0;
+    for (a, b) in lhs.channels().iter().zip(rhs.channels()) {
+        if a.shape() != b.shape() {
+            return Err(LalgrsError::MismatchedMatrixDimensions {
+                lhs: a.shape(),
+                rhs: b.shape(),
+            });
+        }
+        for (x, y) in a
+            .as_column_major_slice()
+            .iter()
+            .zip(b.as_column_major_slice())
+        {
+            sum += (x - y) * (x - y);
+        }
+        count += a.shape().size();
+    }
+    if count == 0 {
+        return Err(LalgrsError::EmptyMatrix);
+    }
+    Ok(sum / count as f64)
+}
+
+/// ## Peak signal to noise ratio between two images, in decibels
+/// The peak is the largest intensity, 1. Identical images have an infinite ratio.
+/// If the images have different sizes or numbers of channels, returns an error
+pub fn peak_signal_to_noise_ratio(lhs: &Image, rhs: &Image) -> Result<f64, LalgrsError> {
+    Ok(psnr_from_mse(mean_squared_error(lhs, rhs)?))
+}
+
+fn psnr_from_mse(mse: f64) -> f64 {
+    if mse == 0.0 {
+        f64::INFINITY
+    } else {
+        -10.0 * mse.log10()
+    }
+}
@@ -0,0 +1,71 @@
+use std::ops::{Add, Mul};
+
+use crate::{LalgrsError, LalgrsMatrix, Zero};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # 2D convolution
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## How elements outside of the input are read during a convolution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Border {
+    /// Elements outside of the input are zero
+    Zero,
+    /// Elements outside of the input repeat the closest element on the border
+    Clamp,
+    /// The input wraps around, as if it was drawn on a torus
+    Wrap,
+}
+
+impl Border {
+    /// ## Maps a possibly out of bounds index into `0..length`
+    /// Returns `None` when the element should be read as zero
+    fn resolve(&self, index: isize, length: usize) -> Option<usize> {
+        let length = length as isize;
+        match self {
+            Border::Zero => (0..length).contains(&index).then_some(index as usize),
+            Border::Clamp => Some(index.clamp(0, length - 1) as usize),
+            Border::Wrap => Some(index.rem_euclid(length) as usize),
+        }
+    }
+}
+
+impl<T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero> LalgrsMatrix<T> {
+    /// ## Two dimensional convolution with `kernel`
+    /// The result has the same shape as `self`. The kernel is flipped, as in the mathematical definition of convolution,
+    /// and centred on element `(kernel.rows() / 2, kernel.columns() / 2)`.
+    /// `border` decides how elements outside of `self` are read.
+    /// If the kernel is empty, returns an error
+    pub fn convolve(
+        &self,
+        kernel: &LalgrsMatrix<T>,
+        border: Border,
+    ) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        if kernel.is_empty() {
+            return Err(LalgrsError::EmptyMatrix);
+        }
+
+        let (center_row, center_column) = (kernel.rows() / 2, kernel.columns() / 2);
+        Ok(LalgrsMatrix::from_fn(
+            self.rows(),
+            self.columns(),
+            |i, j| {
+                let mut sum = T::zero();
+                for ki in 0..kernel.rows() {
+                    let row =
+                        border.resolve(i as isize + center_row as isize - ki as isize, self.rows());
+                    for kj in 0..kernel.columns() {
+                        let column = border.resolve(
+                            j as isize + center_column as isize - kj as isize,
+                            self.columns(),
+                        );
+                        if let (Some(row), Some(column)) = (row, column) {
+                            sum = sum + self[(row, column)].clone() * kernel[(ki, kj)].clone();
+                        }
+                    }
+                }
+                sum
+            },
+        ))
+    }
+}
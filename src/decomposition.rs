@@ -0,0 +1,1472 @@
+use crate::{complex::Complex, LalgrsError, LalgrsMatrix, LalgrsVector};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Singular value decomposition
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maximum number of Jacobi sweeps before the decomposition gives up
+const MAX_SWEEPS: usize = 64;
+
+/// ## Thin singular value decomposition `A = U * Σ * Vᵀ`
+/// For an `m x n` matrix with `k = min(m, n)`, `u` is `m x k`, `v_t` is `k x n` and both have orthonormal rows or columns.
+/// Singular values are non negative and sorted from the largest to the smallest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd {
+    pub u: LalgrsMatrix<f64>,
+    pub singular_values: LalgrsVector<f64>,
+    pub v_t: LalgrsMatrix<f64>,
+}
+
+impl Svd {
+    /// ## Number of singular values larger than `tolerance`
+    pub fn rank(&self, tolerance: f64) -> usize {
+        self.singular_values
+            .values
+            .iter()
+            .filter(|s| **s > tolerance)
+            .count()
+    }
+
+    /// ## Keeps only the `rank` largest singular values and their singular vectors
+    /// If `rank` is larger than the number of singular values, all of them are kept
+    pub fn truncate(&self, rank: usize) -> Svd {
+        let rank = rank.min(self.singular_values.size());
+        Svd {
+            u: LalgrsMatrix::from_fn(self.u.rows(), rank, |i, j| self.u[(i, j)]),
+            singular_values: LalgrsVector::new(
+                self.singular_values
+                    .values
+                    .iter()
+                    .take(rank)
+                    .copied()
+                    .collect(),
+            ),
+            v_t: LalgrsMatrix::from_fn(rank, self.v_t.columns(), |i, j| self.v_t[(i, j)]),
+        }
+    }
+
+    /// ## Multiplies the factors back together
+    pub fn reconstruct(&self) -> LalgrsMatrix<f64> {
+        let sigma = &self.singular_values.values;
+        LalgrsMatrix::from_fn(self.u.rows(), self.v_t.columns(), |i, j| {
+            (0..sigma.len())
+                .map(|k| self.u[(i, k)] * sigma[k] * self.v_t[(k, j)])
+                .sum()
+        })
+    }
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Thin singular value decomposition, computed with one sided Jacobi rotations
+    /// If the matrix is empty, or the rotations do not converge, returns an error
+    pub fn svd(&self) -> Result<Svd, LalgrsError> {
+        if self.is_empty() {
+            return Err(LalgrsError::EmptyMatrix);
+        }
+        // Rotate the columns of the taller of A and Aᵀ, so that there are at most as many columns as rows
+        if self.rows() < self.columns() {
+            let svd = self.transpose().svd()?;
+            return Ok(Svd {
+                u: svd.v_t.transpose(),
+                singular_values: svd.singular_values,
+                v_t: svd.u.transpose(),
+            });
+        }
+
+        let (rows, columns) = (self.rows(), self.columns());
+        let mut u: Vec<Vec<f64>> = self
+            .as_column_major_slice()
+            .chunks(rows)
+            .map(|c| c.to_vec())
+            .collect();
+        let mut v: Vec<Vec<f64>> = (0..columns)
+            .map(|j| {
+                (0..columns)
+                    .map(|i| if i == j { 1.0 } else { 0.0 })
+                    .collect()
+            })
+            .collect();
+
+        let norm = self
+            .as_column_major_slice()
+            .iter()
+            .map(|x| x * x)
+            .sum::<f64>()
+            .sqrt();
+        let negligible = norm * f64::EPSILON * rows as f64;
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for q in 1..columns {
+                for p in 0..q {
+                    let (alpha, beta, gamma) = u[p]
+                        .iter()
+                        .zip(&u[q])
+                        .fold((0.0, 0.0, 0.0), |(alpha, beta, gamma), (a, b)| {
+                            (alpha + a * a, beta + b * b, gamma + a * b)
+                        });
+                    // Skip pairs that are already orthogonal, or where one column is rounding noise
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt()
+                        || alpha.min(beta).sqrt() <= negligible
+                    {
+                        continue;
+                    }
+                    rotated = true;
+
+                    // Rotation that makes columns p and q orthogonal
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+                    for pair in [&mut u, &mut v] {
+                        let (left, right) = pair.split_at_mut(q);
+                        for (a, b) in left[p].iter_mut().zip(right[0].iter_mut()) {
+                            let (x, y) = (*a, *b);
+                            *a = c * x - s * y;
+                            *b = s * x + c * y;
+                        }
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(LalgrsError::NoConvergence {
+                iterations: MAX_SWEEPS,
+            });
+        }
+
+        // The singular values are the norms of the rotated columns
+        let mut order: Vec<(f64, usize)> = u
+            .iter()
+            .enumerate()
+            .map(|(j, c)| (c.iter().map(|x| x * x).sum::<f64>().sqrt(), j))
+            .collect();
+        order.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let largest = order.first().map_or(0.0, |(s, _)| *s);
+        let tolerance = largest * f64::EPSILON * rows as f64;
+        let mut u_columns: Vec<Vec<f64>> = Vec::with_capacity(columns);
+        let mut next_basis_vector = 0;
+        for (sigma, j) in &order {
+            let column = if *sigma > tolerance {
+                u[*j].iter().map(|x| x / sigma).collect()
+            } else {
+                orthonormal_complement(&u_columns, rows, &mut next_basis_vector)
+            };
+            u_columns.push(column);
+        }
+
+        Ok(Svd {
+            u: LalgrsMatrix::new(u_columns)?,
+            singular_values: LalgrsVector::new(order.iter().map(|(s, _)| *s).collect()),
+            v_t: LalgrsMatrix::new(order.iter().map(|(_, j)| v[*j].clone()).collect())?.transpose(),
+        })
+    }
+}
+
+/// ## Unit vector of length `size` orthogonal to all the given orthonormal vectors
+/// Used to complete the left singular vectors of rank deficient matrices.
+/// Standard basis vectors are tried in turn, starting from `next`, which is advanced past the one that is used
+fn orthonormal_complement(basis: &[Vec<f64>], size: usize, next: &mut usize) -> Vec<f64> {
+    let mut best = vec![0.0; size];
+    let mut best_norm = 0.0;
+    for offset in 0..size {
+        let e = (*next + offset) % size;
+        let mut candidate: Vec<f64> = (0..size).map(|i| if i == e { 1.0 } else { 0.0 }).collect();
+        // Two passes of Gram-Schmidt keep the result orthogonal to working precision
+        for _ in 0..2 {
+            for b in basis {
+                let projection: f64 = b.iter().zip(&candidate).map(|(x, y)| x * y).sum();
+                candidate
+                    .iter_mut()
+                    .zip(b)
+                    .for_each(|(c, x)| *c -= projection * x);
+            }
+        }
+        let norm = candidate.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm > best_norm {
+            best_norm = norm;
+            best = candidate;
+            *next = e + 1;
+        }
+        // Little enough of the candidate was cancelled to keep it accurate
+        if best_norm > 0.5 {
+            break;
+        }
+    }
+    best.iter().map(|x| x / best_norm).collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Pseudo-inverse and fundamental subspaces
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Svd {
+    /// ## Threshold below which singular values are treated as zero
+    /// `None` picks `max(rows, columns) * ε * σ_max`, where `ε` is the machine epsilon.
+    /// If `tolerance` is negative or NaN, returns an error
+    fn threshold(&self, tolerance: Option<f64>) -> Result<f64, LalgrsError> {
+        match tolerance {
+            Some(value) if value < 0.0 || value.is_nan() => Err(LalgrsError::InvalidParameter {
+                name: "tolerance",
+                value,
+            }),
+            Some(value) => Ok(value),
+            None => {
+                let largest = self.singular_values.values.front().copied().unwrap_or(0.0);
+                let size = self.u.rows().max(self.v_t.columns());
+                Ok(size as f64 * f64::EPSILON * largest)
+            }
+        }
+    }
+}
+
+/// ## Matrix with the given vectors as columns, keeping the number of rows when there are no vectors
+fn matrix_from_columns(columns: &[Vec<f64>], rows: usize) -> LalgrsMatrix<f64> {
+    LalgrsMatrix::from_fn(rows, columns.len(), |i, j| columns[j][i])
+}
+
+/// ## Orthonormal basis of the orthogonal complement of the span of the given orthonormal vectors
+pub(crate) fn complete_basis(basis: Vec<Vec<f64>>, size: usize) -> Vec<Vec<f64>> {
+    let known = basis.len();
+    let mut basis = basis;
+    let mut next = 0;
+    while basis.len() < size {
+        let vector = orthonormal_complement(&basis, size, &mut next);
+        basis.push(vector);
+    }
+    basis.split_off(known)
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Number of singular values larger than the tolerance
+    /// `None` uses the default tolerance of `LalgrsMatrix::pseudo_inverse`.
+    /// If the matrix is empty, or the tolerance is invalid, returns an error
+    pub fn rank(&self, tolerance: Option<f64>) -> Result<usize, LalgrsError> {
+        let svd = self.svd()?;
+        Ok(svd.rank(svd.threshold(tolerance)?))
+    }
+
+    /// ## Moore-Penrose pseudo-inverse `A⁺ = V * Σ⁺ * Uᵀ`
+    /// Singular values not larger than `tolerance` are treated as zero. `None` picks `max(rows, columns) * ε * σ_max`.
+    /// `A⁺ * b` is the least squares solution of `A * x = b` with the smallest norm.
+    /// If the matrix is empty, or the tolerance is negative, returns an error
+    pub fn pseudo_inverse(&self, tolerance: Option<f64>) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let svd = self.svd()?;
+        let threshold = svd.threshold(tolerance)?;
+        let sigma = &svd.singular_values.values;
+        Ok(LalgrsMatrix::from_fn(
+            self.columns(),
+            self.rows(),
+            |i, j| {
+                (0..sigma.len())
+                    .filter(|k| sigma[*k] > threshold)
+                    .map(|k| svd.v_t[(k, i)] * svd.u[(j, k)] / sigma[k])
+                    .sum()
+            },
+        ))
+    }
+
+    /// ## Orthonormal basis of the column space, as the columns of the result
+    /// Singular values not larger than `tolerance` are treated as zero, as in `LalgrsMatrix::pseudo_inverse`.
+    /// If the matrix is empty, or the tolerance is negative, returns an error
+    pub fn column_space(&self, tolerance: Option<f64>) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let svd = self.svd()?;
+        let rank = svd.rank(svd.threshold(tolerance)?);
+        Ok(LalgrsMatrix::from_fn(self.rows(), rank, |i, j| {
+            svd.u[(i, j)]
+        }))
+    }
+
+    /// ## Orthonormal basis of the row space, as the columns of the result
+    /// Singular values not larger than `tolerance` are treated as zero, as in `LalgrsMatrix::pseudo_inverse`.
+    /// If the matrix is empty, or the tolerance is negative, returns an error
+    pub fn row_space(&self, tolerance: Option<f64>) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let svd = self.svd()?;
+        let rank = svd.rank(svd.threshold(tolerance)?);
+        Ok(LalgrsMatrix::from_fn(self.columns(), rank, |i, j| {
+            svd.v_t[(j, i)]
+        }))
+    }
+
+    /// ## Orthonormal basis of the null space, the vectors `x` with `A * x = 0`, as the columns of the result
+    /// Singular values not larger than `tolerance` are treated as zero, as in `LalgrsMatrix::pseudo_inverse`.
+    /// If the matrix is empty, or the tolerance is negative, returns an error
+    pub fn null_space(&self, tolerance: Option<f64>) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let row_space = self.row_space(tolerance)?;
+        let basis = row_space
+            .as_column_major_slice()
+            .chunks(self.columns())
+            .map(|c| c.to_vec())
+            .collect();
+        Ok(matrix_from_columns(
+            &complete_basis(basis, self.columns()),
+            self.columns(),
+        ))
+    }
+
+    /// ## Orthonormal basis of the left null space, the vectors `y` with `Aᵀ * y = 0`, as the columns of the result
+    /// Singular values not larger than `tolerance` are treated as zero, as in `LalgrsMatrix::pseudo_inverse`.
+    /// If the matrix is empty, or the tolerance is negative, returns an error
+    pub fn left_null_space(
+        &self,
+        tolerance: Option<f64>,
+    ) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let column_space = self.column_space(tolerance)?;
+        let basis = column_space
+            .as_column_major_slice()
+            .chunks(self.rows())
+            .map(|c| c.to_vec())
+            .collect();
+        Ok(matrix_from_columns(
+            &complete_basis(basis, self.rows()),
+            self.rows(),
+        ))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Cholesky decomposition
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Returns an error if the matrix is empty or not square
+pub(crate) fn check_square(matrix: &LalgrsMatrix<f64>) -> Result<(), LalgrsError> {
+    if matrix.is_empty() {
+        return Err(LalgrsError::EmptyMatrix);
+    }
+    if !matrix.shape().is_square() {
+        return Err(LalgrsError::NonSquareMatrix {
+            shape: matrix.shape(),
+        });
+    }
+    Ok(())
+}
+
+/// ## Returns an error if `rhs` cannot be the right hand side of a system with the given matrix
+fn check_rhs(matrix: &LalgrsMatrix<f64>, rhs: &LalgrsVector<f64>) -> Result<(), LalgrsError> {
+    if rhs.size() != matrix.rows() {
+        return Err(LalgrsError::MismatchedVectorAndMatrixDimensions {
+            vector_size: rhs.size(),
+            matrix_shape: matrix.shape(),
+        });
+    }
+    Ok(())
+}
+
+/// ## Solves `L * x = b` in place, where `L` is lower triangular
+/// With `unit_diagonal`, the diagonal of `L` is taken to be one
+fn forward_substitution(l: &LalgrsMatrix<f64>, b: &mut [f64], unit_diagonal: bool) {
+    for i in 0..b.len() {
+        let sum: f64 = (0..i).map(|k| l[(i, k)] * b[k]).sum();
+        b[i] -= sum;
+        if !unit_diagonal {
+            b[i] /= l[(i, i)];
+        }
+    }
+}
+
+/// ## Solves `Lᵀ * x = b` in place, where `L` is lower triangular
+/// With `unit_diagonal`, the diagonal of `L` is taken to be one
+fn backward_substitution_transposed(l: &LalgrsMatrix<f64>, b: &mut [f64], unit_diagonal: bool) {
+    for i in (0..b.len()).rev() {
+        let sum: f64 = (i + 1..b.len()).map(|k| l[(k, i)] * b[k]).sum();
+        b[i] -= sum;
+        if !unit_diagonal {
+            b[i] /= l[(i, i)];
+        }
+    }
+}
+
+/// ## Applies `solve` to every column of `rhs`
+fn solve_columns<F: Fn(&LalgrsVector<f64>) -> Result<LalgrsVector<f64>, LalgrsError>>(
+    matrix_shape: crate::Shape,
+    rhs: &LalgrsMatrix<f64>,
+    solve: F,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    if rhs.rows() != matrix_shape.rows {
+        return Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: matrix_shape,
+            rhs: rhs.shape(),
+        });
+    }
+    let columns = rhs
+        .as_column_major_slice()
+        .chunks(rhs.rows().max(1))
+        .take(rhs.columns())
+        .map(|c| Ok(solve(&LalgrsVector::new(c.to_vec()))?.values.into()))
+        .collect::<Result<Vec<Vec<f64>>, LalgrsError>>()?;
+    LalgrsMatrix::new(columns)
+}
+
+/// ## Cholesky decomposition `A = L * Lᵀ` of a symmetric positive definite matrix
+/// `L` is lower triangular with a positive diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky {
+    l: LalgrsMatrix<f64>,
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Cholesky decomposition of a symmetric positive definite matrix
+    /// Only the lower triangle of the matrix is read.
+    /// If the matrix is empty or not square, or is not positive definite, returns an error
+    pub fn cholesky(&self) -> Result<Cholesky, LalgrsError> {
+        check_square(self)?;
+        let n = self.rows();
+        let mut l = LalgrsMatrix::zeros(n, n);
+        for j in 0..n {
+            let diagonal = self[(j, j)] - (0..j).map(|k| l[(j, k)] * l[(j, k)]).sum::<f64>();
+            // Also rejects NaN
+            if !(diagonal > 0.0 && diagonal.is_finite()) {
+                return Err(LalgrsError::NotPositiveDefinite);
+            }
+            let pivot = diagonal.sqrt();
+            l[(j, j)] = pivot;
+            for i in j + 1..n {
+                let sum: f64 = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+                l[(i, j)] = (self[(i, j)] - sum) / pivot;
+            }
+        }
+        Ok(Cholesky { l })
+    }
+}
+
+impl Cholesky {
+    /// ## Lower triangular factor
+    pub fn l(&self) -> &LalgrsMatrix<f64> {
+        &self.l
+    }
+
+    /// ## Multiplies the factors back together
+    pub fn reconstruct(&self) -> LalgrsMatrix<f64> {
+        let n = self.l.rows();
+        LalgrsMatrix::from_fn(n, n, |i, j| {
+            (0..=i.min(j))
+                .map(|k| self.l[(i, k)] * self.l[(j, k)])
+                .sum()
+        })
+    }
+
+    /// ## Solves `A * x = b`
+    /// If `b` does not have one element per row of `A`, returns an error
+    pub fn solve(&self, b: &LalgrsVector<f64>) -> Result<LalgrsVector<f64>, LalgrsError> {
+        check_rhs(&self.l, b)?;
+        let mut x: Vec<f64> = b.values.iter().copied().collect();
+        forward_substitution(&self.l, &mut x, false);
+        backward_substitution_transposed(&self.l, &mut x, false);
+        Ok(LalgrsVector::new(x))
+    }
+
+    /// ## Solves `A * X = B` for every column of `B`
+    /// If `B` does not have as many rows as `A`, returns an error
+    pub fn solve_matrix(&self, b: &LalgrsMatrix<f64>) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        solve_columns(self.l.shape(), b, |column| self.solve(column))
+    }
+
+    /// ## Determinant of `A`, the squared product of the diagonal of `L`
+    pub fn determinant(&self) -> f64 {
+        (0..self.l.rows())
+            .map(|i| self.l[(i, i)] * self.l[(i, i)])
+            .product()
+    }
+
+    /// ## Natural logarithm of the determinant of `A`
+    /// Does not overflow or underflow for large matrices, unlike the determinant
+    pub fn log_determinant(&self) -> f64 {
+        2.0 * (0..self.l.rows()).map(|i| self.l[(i, i)].ln()).sum::<f64>()
+    }
+
+    /// ## Updates the decomposition to the one of `A + x * xᵀ`, in O(n²) operations
+    /// If `x` does not have one element per row of `A`, returns an error
+    pub fn rank_one_update(&mut self, x: &LalgrsVector<f64>) -> Result<(), LalgrsError> {
+        check_rhs(&self.l, x)?;
+        self.l = self.rotate(x, 1.0)?;
+        Ok(())
+    }
+
+    /// ## Updates the decomposition to the one of `A - x * xᵀ`, in O(n²) operations
+    /// If `x` does not have one element per row of `A`, or `A - x * xᵀ` is not positive definite, returns an error
+    /// and leaves the decomposition unchanged
+    pub fn rank_one_downdate(&mut self, x: &LalgrsVector<f64>) -> Result<(), LalgrsError> {
+        check_rhs(&self.l, x)?;
+        self.l = self.rotate(x, -1.0)?;
+        Ok(())
+    }
+
+    /// ## Factor of `A + sign * x * xᵀ`, computed with a sequence of (hyperbolic, for downdates) rotations
+    fn rotate(&self, x: &LalgrsVector<f64>, sign: f64) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let mut l = self.l.clone();
+        let mut x: Vec<f64> = x.values.iter().copied().collect();
+        for k in 0..x.len() {
+            let squared = l[(k, k)] * l[(k, k)] + sign * x[k] * x[k];
+            if !(squared > 0.0 && squared.is_finite()) {
+                return Err(LalgrsError::NotPositiveDefinite);
+            }
+            let r = squared.sqrt();
+            let c = r / l[(k, k)];
+            let s = x[k] / l[(k, k)];
+            l[(k, k)] = r;
+            for i in k + 1..x.len() {
+                l[(i, k)] = (l[(i, k)] + sign * s * x[i]) / c;
+                x[i] = c * x[i] - s * l[(i, k)];
+            }
+        }
+        Ok(l)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # LDLᵀ decomposition
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Decomposition `P * A * Pᵀ = L * D * Lᵀ` of a symmetric, possibly indefinite, matrix
+/// `L` is unit lower triangular, `D` is block diagonal with 1x1 and 2x2 blocks, and `P` is the permutation
+/// chosen by Bunch-Kaufman pivoting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ldlt {
+    l: LalgrsMatrix<f64>,
+    /// Diagonal of `D`
+    diagonal: Vec<f64>,
+    /// Subdiagonal of `D`, non zero only inside 2x2 blocks
+    subdiagonal: Vec<f64>,
+    /// Row `i` of `P * A * Pᵀ` is row `permutation[i]` of `A`
+    permutation: Vec<usize>,
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## LDLᵀ decomposition of a symmetric matrix, with Bunch-Kaufman pivoting
+    /// Only the lower triangle of the matrix is read. Singular matrices can be decomposed, but not solved.
+    /// If the matrix is empty or not square, returns an error
+    pub fn ldlt(&self) -> Result<Ldlt, LalgrsError> {
+        check_square(self)?;
+        let n = self.rows();
+        // Growth bound of Bunch and Kaufman
+        let alpha = (1.0 + 17f64.sqrt()) / 8.0;
+
+        // Full symmetric working copy, whose trailing block is reduced step by step
+        let mut a = LalgrsMatrix::from_fn(n, n, |i, j| self[(i.max(j), i.min(j))]);
+        let mut l = LalgrsMatrix::identity(n);
+        let mut diagonal = vec![0.0; n];
+        let mut subdiagonal = vec![0.0; n.saturating_sub(1)];
+        let mut permutation: Vec<usize> = (0..n).collect();
+
+        let mut k = 0;
+        while k < n {
+            let diagonal_magnitude = a[(k, k)].abs();
+            let (max_row, column_max) =
+                (k + 1..n)
+                    .map(|i| (i, a[(i, k)].abs()))
+                    .fold(
+                        (k, 0.0),
+                        |best, (i, v)| if v > best.1 { (i, v) } else { best },
+                    );
+
+            let (pivot, step) = if diagonal_magnitude.max(column_max) == 0.0
+                || diagonal_magnitude >= alpha * column_max
+            {
+                (k, 1)
+            } else {
+                let row_max = (k..n)
+                    .filter(|j| *j != max_row)
+                    .map(|j| a[(max_row, j)].abs())
+                    .fold(0.0, f64::max);
+                if diagonal_magnitude * row_max >= alpha * column_max * column_max {
+                    (k, 1)
+                } else if a[(max_row, max_row)].abs() >= alpha * row_max {
+                    (max_row, 1)
+                } else {
+                    (max_row, 2)
+                }
+            };
+
+            // Move the pivot to row and column k + step - 1
+            let target = k + step - 1;
+            if pivot != target {
+                permutation.swap(pivot, target);
+                for j in 0..n {
+                    let (x, y) = (a[(pivot, j)], a[(target, j)]);
+                    a[(pivot, j)] = y;
+                    a[(target, j)] = x;
+                }
+                for i in 0..n {
+                    let (x, y) = (a[(i, pivot)], a[(i, target)]);
+                    a[(i, pivot)] = y;
+                    a[(i, target)] = x;
+                }
+                for j in 0..k {
+                    let (x, y) = (l[(pivot, j)], l[(target, j)]);
+                    l[(pivot, j)] = y;
+                    l[(target, j)] = x;
+                }
+            }
+
+            if step == 1 {
+                let d = a[(k, k)];
+                diagonal[k] = d;
+                if d != 0.0 {
+                    for i in k + 1..n {
+                        l[(i, k)] = a[(i, k)] / d;
+                    }
+                    for j in k + 1..n {
+                        for i in k + 1..n {
+                            a[(i, j)] -= l[(i, k)] * a[(j, k)];
+                        }
+                    }
+                }
+            } else {
+                let (d11, d21, d22) = (a[(k, k)], a[(k + 1, k)], a[(k + 1, k + 1)]);
+                diagonal[k] = d11;
+                diagonal[k + 1] = d22;
+                subdiagonal[k] = d21;
+                let determinant = d11 * d22 - d21 * d21;
+                for i in k + 2..n {
+                    let (x, y) = (a[(i, k)], a[(i, k + 1)]);
+                    l[(i, k)] = (d22 * x - d21 * y) / determinant;
+                    l[(i, k + 1)] = (d11 * y - d21 * x) / determinant;
+                }
+                for j in k + 2..n {
+                    for i in k + 2..n {
+                        a[(i, j)] -= l[(i, k)] * a[(j, k)] + l[(i, k + 1)] * a[(j, k + 1)];
+                    }
+                }
+            }
+            k += step;
+        }
+
+        Ok(Ldlt {
+            l,
+            diagonal,
+            subdiagonal,
+            permutation,
+        })
+    }
+}
+
+impl Ldlt {
+    /// ## Unit lower triangular factor
+    pub fn l(&self) -> &LalgrsMatrix<f64> {
+        &self.l
+    }
+
+    /// ## Block diagonal factor
+    pub fn d(&self) -> LalgrsMatrix<f64> {
+        let n = self.diagonal.len();
+        LalgrsMatrix::from_fn(n, n, |i, j| match i.abs_diff(j) {
+            0 => self.diagonal[i],
+            1 => self.subdiagonal[i.min(j)],
+            _ => 0.0,
+        })
+    }
+
+    /// ## Row `i` of `P * A * Pᵀ` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// ## Multiplies the factors back together, undoing the permutation
+    pub fn reconstruct(&self) -> LalgrsMatrix<f64> {
+        let n = self.diagonal.len();
+        let d = self.d();
+        let ld = LalgrsMatrix::from_fn(n, n, |i, j| {
+            (j.saturating_sub(1)..(j + 2).min(n))
+                .map(|k| self.l[(i, k)] * d[(k, j)])
+                .sum::<f64>()
+        });
+        let mut a = LalgrsMatrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                a[(self.permutation[i], self.permutation[j])] =
+                    (0..n).map(|k| ld[(i, k)] * self.l[(j, k)]).sum();
+            }
+        }
+        a
+    }
+
+    /// ## Sizes of the diagonal blocks of `D`, from the top left
+    fn blocks(&self) -> Vec<(usize, usize)> {
+        let mut blocks = Vec::new();
+        let mut k = 0;
+        while k < self.diagonal.len() {
+            let size = if self.subdiagonal.get(k).is_some_and(|s| *s != 0.0) {
+                2
+            } else {
+                1
+            };
+            blocks.push((k, size));
+            k += size;
+        }
+        blocks
+    }
+
+    /// ## Determinant of `A`, the product of the determinants of the blocks of `D`
+    pub fn determinant(&self) -> f64 {
+        self.blocks()
+            .iter()
+            .map(|(k, size)| match size {
+                1 => self.diagonal[*k],
+                _ => {
+                    self.diagonal[*k] * self.diagonal[k + 1]
+                        - self.subdiagonal[*k] * self.subdiagonal[*k]
+                }
+            })
+            .product()
+    }
+
+    /// ## Numbers of positive, negative and zero eigenvalues of `A`
+    /// By Sylvester's law of inertia, these are the same as the ones of `D`
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        let mut inertia = (0, 0, 0);
+        for (k, size) in self.blocks() {
+            let d = self.diagonal[k];
+            if size == 2 {
+                // Bunch-Kaufman only picks 2x2 blocks with one positive and one negative eigenvalue
+                inertia.0 += 1;
+                inertia.1 += 1;
+            } else if d > 0.0 {
+                inertia.0 += 1;
+            } else if d < 0.0 {
+                inertia.1 += 1;
+            } else {
+                inertia.2 += 1;
+            }
+        }
+        inertia
+    }
+
+    /// ## Solves `A * x = b`
+    /// If `b` does not have one element per row of `A`, or `A` is singular, returns an error
+    pub fn solve(&self, b: &LalgrsVector<f64>) -> Result<LalgrsVector<f64>, LalgrsError> {
+        check_rhs(&self.l, b)?;
+        let mut y: Vec<f64> = self.permutation.iter().map(|p| b.values[*p]).collect();
+        forward_substitution(&self.l, &mut y, true);
+        for (k, size) in self.blocks() {
+            if size == 1 {
+                if self.diagonal[k] == 0.0 {
+                    return Err(LalgrsError::SingularMatrix);
+                }
+                y[k] /= self.diagonal[k];
+            } else {
+                let (d11, d21, d22) = (self.diagonal[k], self.subdiagonal[k], self.diagonal[k + 1]);
+                let determinant = d11 * d22 - d21 * d21;
+                let (u, v) = (y[k], y[k + 1]);
+                y[k] = (d22 * u - d21 * v) / determinant;
+                y[k + 1] = (d11 * v - d21 * u) / determinant;
+            }
+        }
+        backward_substitution_transposed(&self.l, &mut y, true);
+
+        let mut x = vec![0.0; y.len()];
+        for (i, p) in self.permutation.iter().enumerate() {
+            x[*p] = y[i];
+        }
+        Ok(LalgrsVector::new(x))
+    }
+
+    /// ## Solves `A * X = B` for every column of `B`
+    /// If `B` does not have as many rows as `A`, or `A` is singular, returns an error
+    pub fn solve_matrix(&self, b: &LalgrsMatrix<f64>) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        solve_columns(self.l.shape(), b, |column| self.solve(column))
+    }
+
+    /// ## Updates the decomposition to the one of `A + x * xᵀ`
+    /// If `x` does not have one element per row of `A`, returns an error
+    pub fn rank_one_update(&mut self, x: &LalgrsVector<f64>) -> Result<(), LalgrsError> {
+        self.modify(x, 1.0)
+    }
+
+    /// ## Updates the decomposition to the one of `A - x * xᵀ`
+    /// If `x` does not have one element per row of `A`, returns an error
+    pub fn rank_one_downdate(&mut self, x: &LalgrsVector<f64>) -> Result<(), LalgrsError> {
+        self.modify(x, -1.0)
+    }
+
+    /// ## Decomposition of `A + sign * x * xᵀ`
+    /// When `D` is diagonal this takes O(n²) operations. When `D` has 2x2 blocks, or the fast update would divide by a
+    /// vanishing pivot, the modified matrix is decomposed again with pivoting.
+    fn modify(&mut self, x: &LalgrsVector<f64>, sign: f64) -> Result<(), LalgrsError> {
+        check_rhs(&self.l, x)?;
+        if self.subdiagonal.iter().all(|s| *s == 0.0) {
+            if let Some(l) = self.fast_modify(x, sign) {
+                *self = l;
+                return Ok(());
+            }
+        }
+        let mut a = self.reconstruct();
+        for i in 0..x.size() {
+            for j in 0..x.size() {
+                a[(i, j)] += sign * x.values[i] * x.values[j];
+            }
+        }
+        *self = a.ldlt()?;
+        Ok(())
+    }
+
+    /// ## Rank one modification of a decomposition with diagonal `D`, without pivoting
+    /// Returns `None` if a pivot becomes too small for the update to be stable
+    fn fast_modify(&self, x: &LalgrsVector<f64>, sign: f64) -> Option<Ldlt> {
+        let mut updated = self.clone();
+        let mut w: Vec<f64> = self.permutation.iter().map(|p| x.values[*p]).collect();
+        let mut alpha = sign;
+        for k in 0..w.len() {
+            let p = w[k];
+            let d = updated.diagonal[k];
+            let new_d = d + alpha * p * p;
+            if new_d.abs() <= f64::EPSILON.sqrt() * (d.abs() + (alpha * p * p).abs())
+                || !new_d.is_finite()
+            {
+                return None;
+            }
+            let beta = p * alpha / new_d;
+            alpha *= d / new_d;
+            updated.diagonal[k] = new_d;
+            for (i, w) in w.iter_mut().enumerate().skip(k + 1) {
+                *w -= p * updated.l[(i, k)];
+                updated.l[(i, k)] += beta * *w;
+            }
+        }
+        Some(updated)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # LU decomposition
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Decomposition `P * A = L * U` of a square matrix, with partial pivoting
+/// `L` is unit lower triangular and `U` upper triangular; both are stored in a single matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu {
+    lu: LalgrsMatrix<f64>,
+    /// Row `i` of `P * A` is row `permutation[i]` of `A`
+    permutation: Vec<usize>,
+    /// Whether `P` is an odd permutation
+    odd: bool,
+    /// Pivots not larger than this are treated as zero when solving
+    tolerance: f64,
+    /// 1-norm of `A`, used to estimate the condition number
+    norm: f64,
+}
+
+/// Maximum number of iterations of the 1-norm estimator; in practice it stops after two or three
+const MAX_ESTIMATOR_ITERATIONS: usize = 5;
+
+impl LalgrsMatrix<f64> {
+    /// ## LU decomposition with partial pivoting
+    /// Singular matrices can be decomposed, but not solved or inverted.
+    /// If the matrix is empty or not square, returns an error
+    pub fn lu(&self) -> Result<Lu, LalgrsError> {
+        check_square(self)?;
+        let n = self.rows();
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut odd = false;
+        let scale = self
+            .as_column_major_slice()
+            .iter()
+            .fold(0.0, |max: f64, v| max.max(v.abs()));
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|a, b| lu[(*a, k)].abs().total_cmp(&lu[(*b, k)].abs()))
+                .unwrap_or(k);
+            if pivot != k {
+                permutation.swap(pivot, k);
+                odd = !odd;
+                for j in 0..n {
+                    let value = lu[(k, j)];
+                    lu[(k, j)] = lu[(pivot, j)];
+                    lu[(pivot, j)] = value;
+                }
+            }
+            if lu[(k, k)] == 0.0 {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = lu[(i, k)] / lu[(k, k)];
+                lu[(i, k)] = factor;
+                for j in k + 1..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+            }
+        }
+
+        Ok(Lu {
+            lu,
+            permutation,
+            odd,
+            tolerance: n as f64 * f64::EPSILON * scale,
+            norm: self.one_norm(),
+        })
+    }
+
+    /// ## Determinant, computed with an LU decomposition
+    /// If the matrix is empty or not square, returns an error
+    pub fn determinant(&self) -> Result<f64, LalgrsError> {
+        Ok(self.lu()?.determinant())
+    }
+
+    /// ## Inverse, computed with an LU decomposition
+    /// If the matrix is empty, not square or singular, returns an error
+    pub fn inverse(&self) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        self.lu()?.inverse()
+    }
+
+    /// ## Solves `A * x = b`, with an LU decomposition
+    /// If the matrix is empty, not square or singular, or `b` does not have one element per row, returns an error
+    pub fn solve(&self, b: &LalgrsVector<f64>) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.lu()?.solve(b)
+    }
+
+    /// ## Solves `A * x = b` as `LalgrsMatrix::solve`, refusing ill conditioned systems
+    /// See `Lu::solve_checked`.
+    /// If the matrix is empty, not square, singular or has an estimated condition number above `limit`,
+    /// or `b` does not have one element per row, returns an error
+    pub fn solve_checked(
+        &self,
+        b: &LalgrsVector<f64>,
+        limit: f64,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.lu()?.solve_checked(b, limit)
+    }
+}
+
+impl Lu {
+    /// ## Unit lower triangular factor
+    pub fn l(&self) -> LalgrsMatrix<f64> {
+        let n = self.lu.rows();
+        LalgrsMatrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => self.lu[(i, j)],
+            std::cmp::Ordering::Equal => 1.0,
+            std::cmp::Ordering::Less => 0.0,
+        })
+    }
+
+    /// ## Upper triangular factor
+    pub fn u(&self) -> LalgrsMatrix<f64> {
+        let n = self.lu.rows();
+        LalgrsMatrix::from_fn(n, n, |i, j| if i <= j { self.lu[(i, j)] } else { 0.0 })
+    }
+
+    /// ## Row `i` of `P * A` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// ## Determinant of `A`, the product of the diagonal of `U` with the sign of the permutation
+    pub fn determinant(&self) -> f64 {
+        let product: f64 = (0..self.lu.rows()).map(|i| self.lu[(i, i)]).product();
+        if self.odd {
+            -product
+        } else {
+            product
+        }
+    }
+
+    /// ## Whether a pivot is too small for `A` to be solved
+    pub fn is_singular(&self) -> bool {
+        (0..self.lu.rows()).any(|i| self.lu[(i, i)].abs() <= self.tolerance)
+    }
+
+    /// ## Solves `A * x = b`
+    /// If `b` does not have one element per row of `A`, or `A` is singular, returns an error
+    pub fn solve(&self, b: &LalgrsVector<f64>) -> Result<LalgrsVector<f64>, LalgrsError> {
+        check_rhs(&self.lu, b)?;
+        if self.is_singular() {
+            return Err(LalgrsError::SingularMatrix);
+        }
+        let mut x: Vec<f64> = b.values.iter().copied().collect();
+        self.solve_in_place(&mut x);
+        Ok(LalgrsVector::new(x))
+    }
+
+    /// ## Solves `A * X = B` for every column of `B`
+    /// If `B` does not have as many rows as `A`, or `A` is singular, returns an error
+    pub fn solve_matrix(&self, b: &LalgrsMatrix<f64>) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        solve_columns(self.lu.shape(), b, |column| self.solve(column))
+    }
+
+    /// ## Inverse of `A`
+    /// If `A` is singular, returns an error
+    pub fn inverse(&self) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        self.solve_matrix(&LalgrsMatrix::identity(self.lu.rows()))
+    }
+
+    /// ## Solves `A * x = b` in place, assuming `A` is not singular
+    fn solve_in_place(&self, b: &mut [f64]) {
+        let mut x: Vec<f64> = self.permutation.iter().map(|p| b[*p]).collect();
+        forward_substitution(&self.lu, &mut x, true);
+        for i in (0..x.len()).rev() {
+            let sum: f64 = (i + 1..x.len()).map(|k| self.lu[(i, k)] * x[k]).sum();
+            x[i] = (x[i] - sum) / self.lu[(i, i)];
+        }
+        b.copy_from_slice(&x);
+    }
+
+    /// ## Solves `Aᵀ * x = b` in place, assuming `A` is not singular
+    /// `Aᵀ = Uᵀ * Lᵀ * P`, so the triangular factors are applied in the opposite order
+    fn solve_transposed_in_place(&self, b: &mut [f64]) {
+        for i in 0..b.len() {
+            let sum: f64 = (0..i).map(|k| self.lu[(k, i)] * b[k]).sum();
+            b[i] = (b[i] - sum) / self.lu[(i, i)];
+        }
+        backward_substitution_transposed(&self.lu, b, true);
+        let mut x = vec![0.0; b.len()];
+        for (i, p) in self.permutation.iter().enumerate() {
+            x[*p] = b[i];
+        }
+        b.copy_from_slice(&x);
+    }
+
+    /// ## Estimate of the condition number `‖A‖₁ * ‖A⁻¹‖₁`
+    /// `‖A⁻¹‖₁` is estimated without forming the inverse, with a few solves against the factors
+    /// (Hager's method, with Higham's refinements). The estimate is a lower bound,
+    /// rarely more than a factor of 3 below the true value.
+    /// Singular matrices have an infinite condition number
+    pub fn condition_estimate(&self) -> f64 {
+        if self.is_singular() {
+            return f64::INFINITY;
+        }
+        let n = self.lu.rows();
+        let one_norm = |v: &[f64]| v.iter().map(|x| x.abs()).sum::<f64>();
+
+        let mut x = vec![1.0 / n as f64; n];
+        let mut estimate = 0.0;
+        let mut previous_index = None;
+        for iteration in 0..MAX_ESTIMATOR_ITERATIONS {
+            let mut y = x.clone();
+            self.solve_in_place(&mut y);
+            let norm = one_norm(&y);
+            if iteration > 0 && norm <= estimate {
+                break;
+            }
+            estimate = norm;
+
+            let mut z: Vec<f64> = y
+                .iter()
+                .map(|v| if *v < 0.0 { -1.0 } else { 1.0 })
+                .collect();
+            self.solve_transposed_in_place(&mut z);
+            let (index, largest) =
+                z.iter()
+                    .enumerate()
+                    .fold((0, 0.0), |(index, largest), (i, v)| {
+                        if v.abs() > largest {
+                            (i, v.abs())
+                        } else {
+                            (index, largest)
+                        }
+                    });
+            // No direction increases the estimate, or the same column is visited again
+            let gradient: f64 = z.iter().zip(x.iter()).map(|(z, x)| z * x).sum();
+            if iteration > 0 && (largest <= gradient || previous_index == Some(index)) {
+                break;
+            }
+            previous_index = Some(index);
+            x = vec![0.0; n];
+            x[index] = 1.0;
+        }
+
+        // Alternating vector catching matrices where the gradient steps stall
+        let mut alternating: Vec<f64> = (0..n)
+            .map(|i| {
+                let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+                sign * (1.0 + i as f64 / (n.max(2) - 1) as f64)
+            })
+            .collect();
+        self.solve_in_place(&mut alternating);
+        let alternative = 2.0 * one_norm(&alternating) / (3.0 * n as f64);
+
+        self.norm * estimate.max(alternative)
+    }
+
+    /// ## Checks that `A` is well conditioned enough to be solved
+    /// Returns the estimate of the condition number given by `Lu::condition_estimate`,
+    /// so that callers can also warn about moderately ill conditioned systems.
+    /// If `limit` is negative or NaN, `A` is singular, or the estimate exceeds `limit`, returns an error
+    pub fn check_condition(&self, limit: f64) -> Result<f64, LalgrsError> {
+        if limit < 0.0 || limit.is_nan() {
+            return Err(LalgrsError::InvalidParameter {
+                name: "limit",
+                value: limit,
+            });
+        }
+        if self.is_singular() {
+            return Err(LalgrsError::SingularMatrix);
+        }
+        let estimate = self.condition_estimate();
+        if estimate > limit {
+            return Err(LalgrsError::IllConditioned { estimate });
+        }
+        Ok(estimate)
+    }
+
+    /// ## Solves `A * x = b`, refusing ill conditioned systems
+    /// The condition number is estimated as in `Lu::check_condition` before solving.
+    /// If `b` does not have one element per row of `A`, `limit` is negative or NaN, `A` is singular,
+    /// or the estimated condition number exceeds `limit`, returns an error
+    pub fn solve_checked(
+        &self,
+        b: &LalgrsVector<f64>,
+        limit: f64,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        check_rhs(&self.lu, b)?;
+        self.check_condition(limit)?;
+        self.solve(b)
+    }
+
+    /// ## Solves `A * X = B` for every column of `B`, refusing ill conditioned systems
+    /// The condition number is estimated once, as in `Lu::check_condition`.
+    /// If `B` does not have as many rows as `A`, `limit` is negative or NaN, `A` is singular,
+    /// or the estimated condition number exceeds `limit`, returns an error
+    pub fn solve_matrix_checked(
+        &self,
+        b: &LalgrsMatrix<f64>,
+        limit: f64,
+    ) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        if b.rows() != self.lu.rows() {
+            return Err(LalgrsError::MismatchedMatrixDimensions {
+                lhs: self.lu.shape(),
+                rhs: b.shape(),
+            });
+        }
+        self.check_condition(limit)?;
+        self.solve_matrix(b)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Eigenvalues
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Scales rows and columns by powers of two so that each row has about the same norm as its column
+/// The result `D⁻¹ * A * D` has the same eigenvalues, but they are less sensitive to rounding.
+/// Powers of two keep the scaling exact. Parlett and Reinsch's algorithm, as in EISPACK's `balanc`
+fn balance(a: &mut LalgrsMatrix<f64>) {
+    const RADIX: f64 = 2.0;
+    let n = a.rows();
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..n {
+            let mut column: f64 = (0..n).filter(|j| *j != i).map(|j| a[(j, i)].abs()).sum();
+            let row: f64 = (0..n).filter(|j| *j != i).map(|j| a[(i, j)].abs()).sum();
+            if column == 0.0 || row == 0.0 {
+                continue;
+            }
+            let sum = column + row;
+            let mut factor = 1.0;
+            while column < row / RADIX {
+                factor *= RADIX;
+                column *= RADIX * RADIX;
+            }
+            while column > row * RADIX {
+                factor /= RADIX;
+                column /= RADIX * RADIX;
+            }
+            if (column + row) / factor < 0.95 * sum {
+                done = false;
+                for j in 0..n {
+                    a[(i, j)] /= factor;
+                    a[(j, i)] *= factor;
+                }
+            }
+        }
+    }
+}
+
+/// ## Reduces `A` to upper Hessenberg form `H = Qᵀ * A * Q` in place, with Householder reflections
+/// If `q` is given, it is multiplied on the right by the reflections, so that starting from the identity it ends as `Q`
+pub(crate) fn reduce_to_hessenberg(
+    a: &mut LalgrsMatrix<f64>,
+    mut q: Option<&mut LalgrsMatrix<f64>>,
+) {
+    let n = a.rows();
+    for k in 0..n.saturating_sub(2) {
+        let norm = (k + 1..n).map(|i| a[(i, k)].powi(2)).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let alpha = if a[(k + 1, k)] > 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = (k + 1..n).map(|i| a[(i, k)]).collect();
+        v[0] -= alpha;
+        let length = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        v.iter_mut().for_each(|x| *x /= length);
+
+        // A = (I - 2vvᵀ) * A * (I - 2vvᵀ), with v supported on rows k + 1..n
+        for j in k..n {
+            let dot: f64 = v
+                .iter()
+                .enumerate()
+                .map(|(l, x)| x * a[(k + 1 + l, j)])
+                .sum();
+            for (l, x) in v.iter().enumerate() {
+                a[(k + 1 + l, j)] -= 2.0 * x * dot;
+            }
+        }
+        let reflect_columns = |matrix: &mut LalgrsMatrix<f64>| {
+            for i in 0..matrix.rows() {
+                let dot: f64 = v
+                    .iter()
+                    .enumerate()
+                    .map(|(l, x)| x * matrix[(i, k + 1 + l)])
+                    .sum();
+                for (l, x) in v.iter().enumerate() {
+                    matrix[(i, k + 1 + l)] -= 2.0 * x * dot;
+                }
+            }
+        };
+        reflect_columns(a);
+        if let Some(q) = q.as_deref_mut() {
+            reflect_columns(q);
+        }
+        a[(k + 1, k)] = alpha;
+        for i in k + 2..n {
+            a[(i, k)] = 0.0;
+        }
+    }
+}
+
+/// ## Reduces an upper Hessenberg matrix to real Schur form `T = Qᵀ * H * Q` in place, with Francis double shift QR steps
+/// `T` is upper quasi-triangular: complex conjugate eigenvalues are the eigenvalues of 2x2 blocks on its diagonal.
+/// If `vectors` is given, it is multiplied on the right by the orthogonal transformations.
+/// Returns the eigenvalues in the order they appear on the diagonal.
+/// The iteration follows EISPACK's `hqr2`, with Wilkinson's and MATLAB's exceptional shifts.
+/// If the iteration does not converge, returns an error
+pub(crate) fn francis_qr(
+    h: &mut LalgrsMatrix<f64>,
+    mut vectors: Option<&mut LalgrsMatrix<f64>>,
+) -> Result<Vec<Complex>, LalgrsError> {
+    let size = h.rows();
+    let mut eigenvalues = vec![Complex::default(); size];
+    let norm: f64 = (0..size)
+        .flat_map(|i| (i.saturating_sub(1)..size).map(move |j| (i, j)))
+        .map(|(i, j)| h[(i, j)].abs())
+        .sum();
+    let max_iterations = 30 * size.max(10);
+    let mut total_iterations = 0;
+    let mut iterations = 0;
+    let mut exceptional_shift = 0.0;
+
+    // Rows and columns 0..active are not reduced yet
+    let mut active = size;
+    while active > 0 {
+        let n = active - 1;
+        // Look for a single small subdiagonal element
+        let mut l = n;
+        while l > 0 {
+            let mut s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+            if s == 0.0 {
+                s = norm;
+            }
+            if h[(l, l - 1)].abs() < f64::EPSILON * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == n {
+            // One real eigenvalue
+            if n > 0 {
+                h[(n, n - 1)] = 0.0;
+            }
+            h[(n, n)] += exceptional_shift;
+            eigenvalues[n] = Complex::from(h[(n, n)]);
+            active -= 1;
+            iterations = 0;
+        } else if l + 1 == n {
+            // Two eigenvalues, from the trailing 2x2 block
+            if n > 1 {
+                h[(n - 1, n - 2)] = 0.0;
+            }
+            let w = h[(n, n - 1)] * h[(n - 1, n)];
+            let p = (h[(n - 1, n - 1)] - h[(n, n)]) / 2.0;
+            let discriminant = p * p + w;
+            let root = discriminant.abs().sqrt();
+            h[(n, n)] += exceptional_shift;
+            h[(n - 1, n - 1)] += exceptional_shift;
+            let x = h[(n, n)];
+            if discriminant >= 0.0 {
+                // Real pair: rotate the block to upper triangular form
+                let z = if p >= 0.0 { p + root } else { p - root };
+                let first = x + z;
+                let second = if z != 0.0 { x - w / z } else { first };
+                let (c, s) = {
+                    let sub = h[(n, n - 1)];
+                    let r = sub.hypot(z);
+                    (z / r, sub / r)
+                };
+                for j in n - 1..size {
+                    let t = h[(n - 1, j)];
+                    h[(n - 1, j)] = c * t + s * h[(n, j)];
+                    h[(n, j)] = c * h[(n, j)] - s * t;
+                }
+                for i in 0..=n {
+                    let t = h[(i, n - 1)];
+                    h[(i, n - 1)] = c * t + s * h[(i, n)];
+                    h[(i, n)] = c * h[(i, n)] - s * t;
+                }
+                if let Some(vectors) = vectors.as_deref_mut() {
+                    for i in 0..size {
+                        let t = vectors[(i, n - 1)];
+                        vectors[(i, n - 1)] = c * t + s * vectors[(i, n)];
+                        vectors[(i, n)] = c * vectors[(i, n)] - s * t;
+                    }
+                }
+                h[(n, n - 1)] = 0.0;
+                eigenvalues[n - 1] = Complex::from(first);
+                eigenvalues[n] = Complex::from(second);
+            } else {
+                eigenvalues[n - 1] = Complex::new(x + p, root);
+                eigenvalues[n] = Complex::new(x + p, -root);
+            }
+            active -= 2;
+            iterations = 0;
+        } else {
+            if total_iterations == max_iterations {
+                return Err(LalgrsError::NoConvergence {
+                    iterations: total_iterations,
+                });
+            }
+            // Shifts from the trailing 2x2 block
+            let mut x = h[(n, n)];
+            let mut y = h[(n - 1, n - 1)];
+            let mut w = h[(n, n - 1)] * h[(n - 1, n)];
+            if iterations == 10 {
+                // Wilkinson's exceptional shift
+                exceptional_shift += x;
+                for i in 0..=n {
+                    h[(i, i)] -= x;
+                }
+                let s = h[(n, n - 1)].abs() + h[(n - 1, n - 2)].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            if iterations == 30 {
+                // MATLAB's exceptional shift
+                let half = (y - x) / 2.0;
+                let s = half * half + w;
+                if s > 0.0 {
+                    let root = if y < x { -s.sqrt() } else { s.sqrt() };
+                    let shift = x - w / (half + root);
+                    for i in 0..=n {
+                        h[(i, i)] -= shift;
+                    }
+                    exceptional_shift += shift;
+                    x = 0.964;
+                    y = x;
+                    w = x;
+                }
+            }
+            iterations += 1;
+            total_iterations += 1;
+
+            // Look for two consecutive small subdiagonal elements
+            let mut m = n - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = h[(m, m)];
+                let (rx, sy) = (x - z, y - z);
+                p = (rx * sy - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                q = h[(m + 1, m + 1)] - z - rx - sy;
+                r = h[(m + 2, m + 1)];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let coupling = h[(m, m - 1)].abs() * (q.abs() + r.abs());
+                let scale = p.abs() * (h[(m - 1, m - 1)].abs() + z.abs() + h[(m + 1, m + 1)].abs());
+                if coupling < f64::EPSILON * scale {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m + 2..=n {
+                h[(i, i - 2)] = 0.0;
+                if i > m + 2 {
+                    h[(i, i - 3)] = 0.0;
+                }
+            }
+
+            // Double QR step on rows l..=n and columns m..=n, chasing the bulge with 3x3 reflections
+            for k in m..n {
+                let not_last = k != n - 1;
+                let mut scale = 1.0;
+                if k != m {
+                    p = h[(k, k - 1)];
+                    q = h[(k + 1, k - 1)];
+                    r = if not_last { h[(k + 2, k - 1)] } else { 0.0 };
+                    scale = p.abs() + q.abs() + r.abs();
+                    if scale == 0.0 {
+                        continue;
+                    }
+                    p /= scale;
+                    q /= scale;
+                    r /= scale;
+                }
+                let mut s = (p * p + q * q + r * r).sqrt();
+                if p < 0.0 {
+                    s = -s;
+                }
+                if s == 0.0 {
+                    continue;
+                }
+                if k != m {
+                    h[(k, k - 1)] = -s * scale;
+                } else if l != m {
+                    h[(k, k - 1)] = -h[(k, k - 1)];
+                }
+                p += s;
+                let (vx, vy, vz) = (p / s, q / s, r / s);
+                let (qratio, r_ratio) = (q / p, r / p);
+                for j in k..size {
+                    let mut t = h[(k, j)] + qratio * h[(k + 1, j)];
+                    if not_last {
+                        t += r_ratio * h[(k + 2, j)];
+                        h[(k + 2, j)] -= t * vz;
+                    }
+                    h[(k, j)] -= t * vx;
+                    h[(k + 1, j)] -= t * vy;
+                }
+                let reflect_columns = |matrix: &mut LalgrsMatrix<f64>, rows: usize| {
+                    for i in 0..rows {
+                        let mut t = vx * matrix[(i, k)] + vy * matrix[(i, k + 1)];
+                        if not_last {
+                            t += vz * matrix[(i, k + 2)];
+                            matrix[(i, k + 2)] -= t * r_ratio;
+                        }
+                        matrix[(i, k)] -= t;
+                        matrix[(i, k + 1)] -= t * qratio;
+                    }
+                };
+                reflect_columns(h, n.min(k + 3) + 1);
+                if let Some(vectors) = vectors.as_deref_mut() {
+                    reflect_columns(vectors, size);
+                }
+            }
+        }
+    }
+    Ok(eigenvalues)
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Eigenvalues of a square matrix, computed with the QR algorithm
+    /// The matrix is balanced and reduced to Hessenberg form first.
+    /// Complex eigenvalues come in conjugate pairs, the one with positive imaginary part first;
+    /// otherwise the eigenvalues are in no particular order.
+    /// If the matrix is empty or not square, or the iteration does not converge, returns an error
+    pub fn eigenvalues(&self) -> Result<Vec<Complex>, LalgrsError> {
+        check_square(self)?;
+        let mut h = self.clone();
+        balance(&mut h);
+        reduce_to_hessenberg(&mut h, None);
+        francis_qr(&mut h, None)
+    }
+}
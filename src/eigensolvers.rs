@@ -0,0 +1,623 @@
+use std::ops::Add;
+
+use crate::{
+    complex::Complex,
+    decomposition::{francis_qr, Lu},
+    random::Rng,
+    view::{mul_vector, AsMatrixView},
+    LalgrsError, LalgrsMatrix, LalgrsVector, Shape,
+};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Linear operators
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Anything that can multiply a vector, such as a matrix or a matrix free operator
+/// Iterative eigensolvers only need products `A * x`, so large structured or implicit operators can be used
+/// without storing them as a dense matrix. Implemented for matrices, references to matrices and views.
+pub trait LinearOperator {
+    /// ## Shape of the matrix the operator represents
+    fn shape(&self) -> Shape;
+
+    /// ## Product `A * x`
+    /// If `x` does not have one element per column, returns an error
+    fn apply(&self, x: &LalgrsVector<f64>) -> Result<LalgrsVector<f64>, LalgrsError>;
+}
+
+impl<M: AsMatrixView<f64>> LinearOperator for M {
+    fn shape(&self) -> Shape {
+        self.as_matrix_view().shape()
+    }
+
+    fn apply(&self, x: &LalgrsVector<f64>) -> Result<LalgrsVector<f64>, LalgrsError> {
+        mul_vector(self.as_matrix_view(), x)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Iterative eigensolvers
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Eigenpair found by an iterative method
+/// `vector` has unit norm, `residual` is `‖A * v - λ * v‖` and `iterations` is the number of iterations it took to converge,
+/// which for Krylov methods is the dimension of the Krylov subspace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigenPair<T: Add<T, Output = T>> {
+    pub value: T,
+    pub vector: LalgrsVector<T>,
+    pub residual: f64,
+    pub iterations: usize,
+}
+
+/// ## Which end of the spectrum a Krylov method looks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extreme {
+    /// Eigenvalues with the largest modulus
+    LargestMagnitude,
+    /// Eigenvalues with the largest real part
+    Largest,
+    /// Eigenvalues with the smallest real part
+    Smallest,
+}
+
+impl Extreme {
+    /// ## Sort key that puts the wanted eigenvalues first
+    fn key(self, value: Complex) -> f64 {
+        match self {
+            Extreme::LargestMagnitude => -value.abs(),
+            Extreme::Largest => -value.re,
+            Extreme::Smallest => value.re,
+        }
+    }
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+fn norm(x: &[f64]) -> f64 {
+    dot(x, x).sqrt()
+}
+
+/// ## `x - a * y`
+fn subtract_scaled(x: &[f64], a: f64, y: &[f64]) -> Vec<f64> {
+    x.iter().zip(y).map(|(x, y)| x - a * y).collect()
+}
+
+fn normalized(x: &[f64]) -> Vec<f64> {
+    let length = norm(x);
+    x.iter().map(|v| v / length).collect()
+}
+
+/// ## `A * x` for a vector stored as a slice
+fn apply<A: LinearOperator + ?Sized>(operator: &A, x: &[f64]) -> Result<Vec<f64>, LalgrsError> {
+    Ok(operator
+        .apply(&LalgrsVector::new(x.to_vec()))?
+        .iter()
+        .copied()
+        .collect())
+}
+
+/// ## Returns an error if the operator is empty or not square, or `tolerance` is not positive
+fn check_operator<A: LinearOperator + ?Sized>(
+    operator: &A,
+    tolerance: f64,
+) -> Result<usize, LalgrsError> {
+    let shape = operator.shape();
+    if shape.is_empty() {
+        return Err(LalgrsError::EmptyMatrix);
+    }
+    if !shape.is_square() {
+        return Err(LalgrsError::NonSquareMatrix { shape });
+    }
+    if tolerance <= 0.0 || tolerance.is_nan() {
+        return Err(LalgrsError::InvalidParameter {
+            name: "tolerance",
+            value: tolerance,
+        });
+    }
+    Ok(shape.rows)
+}
+
+/// ## Unit vector with normally distributed directions, from a fixed seed so that results are reproducible
+/// It is orthogonalised twice against `basis`, which must have fewer than `size` orthonormal vectors
+fn start_vector(size: usize, basis: &[Vec<f64>], rng: &mut Rng) -> Vec<f64> {
+    loop {
+        let mut x: Vec<f64> = (0..size).map(|_| rng.normal()).collect();
+        for _ in 0..2 {
+            for b in basis {
+                x = subtract_scaled(&x, dot(&x, b), b);
+            }
+        }
+        if norm(&x) > f64::EPSILON {
+            return normalized(&x);
+        }
+    }
+}
+
+/// ## Rayleigh quotient `xᵀ * A * x` of a unit vector and the residual norm `‖A * x - λ * x‖`
+fn rayleigh<A: LinearOperator + ?Sized>(
+    operator: &A,
+    x: &[f64],
+) -> Result<(f64, f64, Vec<f64>), LalgrsError> {
+    let ax = apply(operator, x)?;
+    let value = dot(x, &ax);
+    let residual = norm(&subtract_scaled(&ax, value, x));
+    Ok((value, residual, ax))
+}
+
+/// ## Dominant eigenpair, the one with the eigenvalue of largest modulus, computed with the power iteration
+/// The iteration multiplies a fixed starting vector by `A` until `‖A * v - λ * v‖ ≤ tolerance * |λ|`,
+/// where `λ` is the Rayleigh quotient of `v`. It converges at the rate `|λ₂ / λ₁|`, and does not converge
+/// when the dominant eigenvalue is not unique, for example for a complex conjugate pair.
+/// If the operator is empty or not square, `tolerance` is not positive, or the iteration does not converge, returns an error
+pub fn power_iteration<A: LinearOperator + ?Sized>(
+    operator: &A,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<EigenPair<f64>, LalgrsError> {
+    let n = check_operator(operator, tolerance)?;
+    let mut x = start_vector(n, &[], &mut Rng::seed_from_u64(0));
+    for iteration in 1..=max_iterations {
+        let (value, residual, ax) = rayleigh(operator, &x)?;
+        if residual <= tolerance * value.abs() {
+            return Ok(EigenPair {
+                value,
+                vector: LalgrsVector::new(x),
+                residual,
+                iterations: iteration,
+            });
+        }
+        x = normalized(&ax);
+    }
+    Err(LalgrsError::NoConvergence {
+        iterations: max_iterations,
+    })
+}
+
+/// ## Eigenvalues and orthonormal eigenvectors, as columns, of a symmetric tridiagonal matrix
+/// `diagonal` has the `n` diagonal elements and `off_diagonal` the `n - 1` elements below the diagonal.
+/// With `last_row_only`, only the last row of the eigenvector matrix is computed, in `O(n²)` rather than `O(n³)`.
+/// Implicit QL iteration, following EISPACK's `tql2`. The eigenvalues are sorted in increasing order.
+/// If the iteration does not converge, returns an error
+fn tridiagonal_eigen(
+    diagonal: &[f64],
+    off_diagonal: &[f64],
+    last_row_only: bool,
+) -> Result<(Vec<f64>, LalgrsMatrix<f64>), LalgrsError> {
+    const MAX_ITERATIONS: usize = 64;
+    let n = diagonal.len();
+    let mut d = diagonal.to_vec();
+    let mut e = off_diagonal.to_vec();
+    e.resize(n, 0.0);
+    // Each row of the eigenvector matrix is rotated independently, so a subset of the rows of the identity can be used
+    let first_row = if last_row_only { n - 1 } else { 0 };
+    let mut vectors =
+        LalgrsMatrix::from_fn(
+            n - first_row,
+            n,
+            |i, j| {
+                if i + first_row == j {
+                    1.0
+                } else {
+                    0.0
+                }
+            },
+        );
+
+    let mut shift = 0.0;
+    let mut scale: f64 = 0.0;
+    for l in 0..n {
+        scale = scale.max(d[l].abs() + e[l].abs());
+        let mut m = l;
+        while m < n - 1 && e[m].abs() > f64::EPSILON * scale {
+            m += 1;
+        }
+        let mut iterations = 0;
+        while m > l && e[l].abs() > f64::EPSILON * scale {
+            iterations += 1;
+            if iterations > MAX_ITERATIONS {
+                return Err(LalgrsError::NoConvergence {
+                    iterations: MAX_ITERATIONS,
+                });
+            }
+            // Wilkinson shift from the leading 2x2 block
+            let g = d[l];
+            let mut p = (d[l + 1] - g) / (2.0 * e[l]);
+            let mut r = p.hypot(1.0);
+            if p < 0.0 {
+                r = -r;
+            }
+            d[l] = e[l] / (p + r);
+            d[l + 1] = e[l] * (p + r);
+            let next = d[l + 1];
+            let h = g - d[l];
+            for value in d.iter_mut().skip(l + 2) {
+                *value -= h;
+            }
+            shift += h;
+
+            // Implicit QL sweep with Givens rotations from m up to l
+            p = d[m];
+            let (mut c, mut c2, mut c3) = (1.0, 1.0, 1.0);
+            let (mut s, mut s2) = (0.0, 0.0);
+            let first = e[l + 1];
+            for i in (l..m).rev() {
+                c3 = c2;
+                c2 = c;
+                s2 = s;
+                let g = c * e[i];
+                let h = c * p;
+                r = p.hypot(e[i]);
+                e[i + 1] = s * r;
+                s = e[i] / r;
+                c = p / r;
+                p = c * d[i] - s * g;
+                d[i + 1] = h + s * (c * g + s * d[i]);
+                for k in 0..vectors.rows() {
+                    let h = vectors[(k, i + 1)];
+                    vectors[(k, i + 1)] = s * vectors[(k, i)] + c * h;
+                    vectors[(k, i)] = c * vectors[(k, i)] - s * h;
+                }
+            }
+            p = -s * s2 * c3 * first * e[l] / next;
+            e[l] = s * p;
+            d[l] = c * p;
+        }
+        d[l] += shift;
+        e[l] = 0.0;
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|a, b| d[*a].total_cmp(&d[*b]));
+    Ok((
+        order.iter().map(|k| d[*k]).collect(),
+        LalgrsMatrix::from_fn(vectors.rows(), n, |i, j| vectors[(i, order[j])]),
+    ))
+}
+
+/// ## Checks the number of wanted eigenpairs and returns the largest Krylov subspace dimension
+fn krylov_dimension(k: usize, n: usize, max_iterations: usize) -> Result<usize, LalgrsError> {
+    if k == 0 || k > n {
+        return Err(LalgrsError::InvalidParameter {
+            name: "k",
+            value: k as f64,
+        });
+    }
+    let dimension = max_iterations.min(n);
+    if dimension < k {
+        return Err(LalgrsError::InvalidParameter {
+            name: "max_iterations",
+            value: max_iterations as f64,
+        });
+    }
+    Ok(dimension)
+}
+
+/// ## Extends an orthonormal Krylov basis with `A * v_j`, orthogonalised twice against the basis with Gram-Schmidt
+/// Returns the orthogonalisation coefficients and the norm of the remainder. When the remainder vanishes, the basis spans
+/// an invariant subspace and is continued with a random vector, with a zero norm returned.
+fn extend_basis<A: LinearOperator + ?Sized>(
+    operator: &A,
+    basis: &mut Vec<Vec<f64>>,
+    rng: &mut Rng,
+) -> Result<(Vec<f64>, f64), LalgrsError> {
+    let j = basis.len() - 1;
+    let mut w = apply(operator, &basis[j])?;
+    let scale = norm(&w);
+    let mut coefficients = vec![0.0; basis.len()];
+    for _ in 0..2 {
+        for (i, b) in basis.iter().enumerate() {
+            let c = dot(&w, b);
+            coefficients[i] += c;
+            w = subtract_scaled(&w, c, b);
+        }
+    }
+    let beta = norm(&w);
+    let n = w.len();
+    if basis.len() < n {
+        if beta > f64::EPSILON * scale.max(f64::MIN_POSITIVE) * n as f64 {
+            basis.push(normalized(&w));
+            return Ok((coefficients, beta));
+        }
+        basis.push(start_vector(n, basis, rng));
+    }
+    Ok((coefficients, 0.0))
+}
+
+/// ## The `k` extreme eigenpairs of a symmetric operator, computed with the Lanczos method
+/// The Krylov subspace is grown one vector at a time, with full reorthogonalisation, until the `k` wanted Ritz pairs have
+/// residuals at most `tolerance` times the largest Ritz value in modulus, or until it reaches `max_iterations` vectors.
+/// Only the largest or smallest eigenvalues are wanted with `Extreme::Largest` and `Extreme::Smallest`.
+/// The pairs are sorted from the most wanted. The operator is assumed to be symmetric.
+/// If the operator is empty or not square, `k` is zero or larger than the size, `tolerance` is not positive,
+/// `max_iterations` is smaller than `k`, or the method does not converge, returns an error
+pub fn lanczos<A: LinearOperator + ?Sized>(
+    operator: &A,
+    k: usize,
+    extreme: Extreme,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<Vec<EigenPair<f64>>, LalgrsError> {
+    let n = check_operator(operator, tolerance)?;
+    let dimension = krylov_dimension(k, n, max_iterations)?;
+    let mut rng = Rng::seed_from_u64(0);
+    let mut basis = vec![start_vector(n, &[], &mut rng)];
+    let (mut alpha, mut beta) = (Vec::new(), Vec::new());
+
+    for m in 1..=dimension {
+        let (coefficients, b) = extend_basis(operator, &mut basis, &mut rng)?;
+        alpha.push(coefficients[m - 1]);
+        let (values, last_row) = tridiagonal_eigen(&alpha, &beta, true)?;
+        if m < k {
+            beta.push(b);
+            continue;
+        }
+
+        // The residual of the Ritz pair (θ, V * s) is |β_m * s_m|
+        let scale = values.iter().fold(0.0, |max: f64, v| max.max(v.abs()));
+        let mut wanted: Vec<usize> = (0..m).collect();
+        wanted.sort_by(|a, b| {
+            let (a, b) = (Complex::from(values[*a]), Complex::from(values[*b]));
+            extreme.key(a).total_cmp(&extreme.key(b))
+        });
+        wanted.truncate(k);
+        let converged = m == n
+            || wanted
+                .iter()
+                .all(|i| (b * last_row[(0, *i)]).abs() <= tolerance * scale);
+        if !converged {
+            beta.push(b);
+            continue;
+        }
+        let (_, vectors) = tridiagonal_eigen(&alpha, &beta, false)?;
+        return wanted
+            .iter()
+            .map(|i| {
+                let x: Vec<f64> = (0..n)
+                    .map(|r| (0..m).map(|c| basis[c][r] * vectors[(c, *i)]).sum())
+                    .collect();
+                let x = normalized(&x);
+                let ax = apply(operator, &x)?;
+                Ok(EigenPair {
+                    value: values[*i],
+                    residual: norm(&subtract_scaled(&ax, values[*i], &x)),
+                    vector: LalgrsVector::new(x),
+                    iterations: m,
+                })
+            })
+            .collect();
+    }
+    Err(LalgrsError::NoConvergence {
+        iterations: dimension,
+    })
+}
+
+/// ## Unit null vector of `H - θI` for an eigenvalue `θ` of a small real matrix, computed with inverse iteration
+/// Gaussian elimination with partial pivoting in complex arithmetic; pivots that vanish because `θ` is an
+/// eigenvalue are replaced by a tiny multiple of the norm of `H`, as is usual for inverse iteration
+fn complex_null_vector(h: &LalgrsMatrix<f64>, theta: Complex) -> Vec<Complex> {
+    let n = h.rows();
+    let scale = h.one_norm().max(f64::MIN_POSITIVE);
+    let mut a: Vec<Vec<Complex>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| Complex::from(h[(i, j)]) - if i == j { theta } else { Complex::default() })
+                .collect()
+        })
+        .collect();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|x, y| a[*x][k].abs().total_cmp(&a[*y][k].abs()))
+            .unwrap_or(k);
+        a.swap(k, pivot);
+        permutation.swap(k, pivot);
+        if a[k][k].abs() <= f64::EPSILON * scale {
+            a[k][k] = Complex::from(f64::EPSILON * scale);
+        }
+        let (top, bottom) = a.split_at_mut(k + 1);
+        let pivot_row = &top[k];
+        for row in bottom {
+            let factor = row[k] / pivot_row[k];
+            row[k] = factor;
+            for (x, p) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                *x = *x - factor * *p;
+            }
+        }
+    }
+
+    let mut x = vec![Complex::from(1.0); n];
+    for _ in 0..2 {
+        let mut y: Vec<Complex> = permutation.iter().map(|p| x[*p]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                y[i] = y[i] - a[i][j] * y[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                y[i] = y[i] - a[i][j] * y[j];
+            }
+            y[i] = y[i] / a[i][i];
+        }
+        let length = y.iter().map(|v| v.norm_sqr()).sum::<f64>().sqrt();
+        x = y.iter().map(|v| *v / Complex::from(length)).collect();
+    }
+    x
+}
+
+/// ## The `k` extreme eigenpairs of a general operator, computed with the Arnoldi method
+/// The Krylov subspace is grown one vector at a time, with full reorthogonalisation, until the `k` wanted Ritz pairs have
+/// residuals at most `tolerance` times the largest Ritz value in modulus, or until it reaches `max_iterations` vectors.
+/// Eigenvalues and eigenvectors can be complex; complex eigenvalues of a real operator come in conjugate pairs.
+/// The pairs are sorted from the most wanted.
+/// If the operator is empty or not square, `k` is zero or larger than the size, `tolerance` is not positive,
+/// `max_iterations` is smaller than `k`, or the method does not converge, returns an error
+pub fn arnoldi<A: LinearOperator + ?Sized>(
+    operator: &A,
+    k: usize,
+    extreme: Extreme,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<Vec<EigenPair<Complex>>, LalgrsError> {
+    let n = check_operator(operator, tolerance)?;
+    let dimension = krylov_dimension(k, n, max_iterations)?;
+    let mut rng = Rng::seed_from_u64(0);
+    let mut basis = vec![start_vector(n, &[], &mut rng)];
+    let mut hessenberg = LalgrsMatrix::zeros(dimension + 1, dimension);
+
+    for m in 1..=dimension {
+        let (coefficients, b) = extend_basis(operator, &mut basis, &mut rng)?;
+        for (i, c) in coefficients.into_iter().enumerate() {
+            hessenberg[(i, m - 1)] = c;
+        }
+        hessenberg[(m, m - 1)] = b;
+        if m < k {
+            continue;
+        }
+
+        let h = LalgrsMatrix::from_fn(m, m, |i, j| hessenberg[(i, j)]);
+        let mut values = francis_qr(&mut h.clone(), None)?;
+        values.sort_by(|a, b| extreme.key(*a).total_cmp(&extreme.key(*b)));
+        values.truncate(k);
+        // The residual of the Ritz pair (θ, V * y) is |h_(m+1, m) * y_m|
+        let vectors: Vec<Vec<Complex>> =
+            values.iter().map(|v| complex_null_vector(&h, *v)).collect();
+        let scale = values.iter().fold(0.0, |max: f64, v| max.max(v.abs()));
+        let converged = m == n
+            || vectors
+                .iter()
+                .all(|y| (y[m - 1].abs() * b) <= tolerance * scale);
+        if !converged {
+            continue;
+        }
+        return values
+            .iter()
+            .zip(&vectors)
+            .map(|(value, y)| {
+                let x: Vec<Complex> = (0..n)
+                    .map(|r| {
+                        (0..m).fold(Complex::default(), |acc, c| {
+                            acc + Complex::from(basis[c][r]) * y[c]
+                        })
+                    })
+                    .collect();
+                let length = x.iter().map(|v| v.norm_sqr()).sum::<f64>().sqrt();
+                let x: Vec<Complex> = x.iter().map(|v| *v / Complex::from(length)).collect();
+                // A is real, so A * x is computed from the real and imaginary parts separately
+                let re = apply(operator, &x.iter().map(|v| v.re).collect::<Vec<f64>>())?;
+                let im = apply(operator, &x.iter().map(|v| v.im).collect::<Vec<f64>>())?;
+                let residual = x
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| (Complex::new(re[i], im[i]) - *value * *v).norm_sqr())
+                    .sum::<f64>()
+                    .sqrt();
+                Ok(EigenPair {
+                    value: *value,
+                    vector: LalgrsVector::new(x),
+                    residual,
+                    iterations: m,
+                })
+            })
+            .collect();
+    }
+    Err(LalgrsError::NoConvergence {
+        iterations: dimension,
+    })
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## LU decomposition of `A - σI`, with the shift moved slightly if it is an eigenvalue up to rounding
+    fn shifted_lu(&self, shift: f64) -> Result<Lu, LalgrsError> {
+        let n = self.rows();
+        let shifted = |shift: f64| {
+            LalgrsMatrix::from_fn(n, n, |i, j| self[(i, j)] - if i == j { shift } else { 0.0 })
+        };
+        let lu = shifted(shift).lu()?;
+        if !lu.is_singular() {
+            return Ok(lu);
+        }
+        // Any nearby shift still amplifies the eigenvector enough to find it in a single iteration
+        shifted(shift + f64::EPSILON.sqrt() * self.one_norm().max(1.0)).lu()
+    }
+
+    /// ## Eigenpair with the eigenvalue closest to `shift`, computed with shifted inverse iteration
+    /// Each iteration solves `(A - σI) * y = v`, with a single LU decomposition, until `‖A * v - λ * v‖ ≤ tolerance * ‖A‖₁`,
+    /// where `λ` is the Rayleigh quotient of `v`. The closer the shift to an eigenvalue, the faster the convergence.
+    /// If the matrix is empty or not square, `tolerance` is not positive, or the iteration does not converge, returns an error
+    pub fn inverse_iteration(
+        &self,
+        shift: f64,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<EigenPair<f64>, LalgrsError> {
+        let n = check_operator(self, tolerance)?;
+        let lu = self.shifted_lu(shift)?;
+        let limit = tolerance * self.one_norm();
+        let mut x = start_vector(n, &[], &mut Rng::seed_from_u64(0));
+        for iteration in 1..=max_iterations {
+            let y: Vec<f64> = lu.solve(&LalgrsVector::new(x))?.iter().copied().collect();
+            x = normalized(&y);
+            let (value, residual, _) = rayleigh(self, &x)?;
+            if residual <= limit {
+                return Ok(EigenPair {
+                    value,
+                    vector: LalgrsVector::new(x),
+                    residual,
+                    iterations: iteration,
+                });
+            }
+        }
+        Err(LalgrsError::NoConvergence {
+            iterations: max_iterations,
+        })
+    }
+
+    /// ## Eigenpair near the starting vector, computed with Rayleigh quotient iteration
+    /// Inverse iteration with the Rayleigh quotient of the current vector as the shift, which converges cubically for
+    /// symmetric matrices, but needs an LU decomposition per iteration. It stops when `‖A * v - λ * v‖ ≤ tolerance * ‖A‖₁`.
+    /// Which eigenpair it finds depends on `initial`; it may not converge to a real eigenpair of a non symmetric matrix.
+    /// If the matrix is empty or not square, `initial` does not match or is zero, `tolerance` is not positive,
+    /// or the iteration does not converge, returns an error
+    pub fn rayleigh_quotient_iteration(
+        &self,
+        initial: &LalgrsVector<f64>,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<EigenPair<f64>, LalgrsError> {
+        check_operator(self, tolerance)?;
+        if initial.size() != self.rows() {
+            return Err(LalgrsError::MismatchedVectorAndMatrixDimensions {
+                vector_size: initial.size(),
+                matrix_shape: self.shape(),
+            });
+        }
+        let x: Vec<f64> = initial.iter().copied().collect();
+        if norm(&x) == 0.0 {
+            return Err(LalgrsError::InvalidParameter {
+                name: "initial",
+                value: 0.0,
+            });
+        }
+        let limit = tolerance * self.one_norm();
+        let mut x = normalized(&x);
+        for iteration in 1..=max_iterations {
+            let (value, residual, _) = rayleigh(self, &x)?;
+            if residual <= limit {
+                return Ok(EigenPair {
+                    value,
+                    vector: LalgrsVector::new(x),
+                    residual,
+                    iterations: iteration,
+                });
+            }
+            let y = self.shifted_lu(value)?.solve(&LalgrsVector::new(x))?;
+            x = normalized(&y.iter().copied().collect::<Vec<f64>>());
+        }
+        Err(LalgrsError::NoConvergence {
+            iterations: max_iterations,
+        })
+    }
+}
@@ -0,0 +1,303 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{scalar::Field, LalgrsError, LalgrsMatrix, LalgrsVector};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Element wise operations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Smaller of two values, or `a` if they are not comparable
+fn min<T: PartialOrd + Clone>(a: &T, b: &T) -> T {
+    if b < a {
+        b.clone()
+    } else {
+        a.clone()
+    }
+}
+
+/// ## Larger of two values, or `a` if they are not comparable
+fn max<T: PartialOrd + Clone>(a: &T, b: &T) -> T {
+    if b > a {
+        b.clone()
+    } else {
+        a.clone()
+    }
+}
+
+/// ## Absolute value, or `None` on overflow
+fn abs<T: Field>(value: &T) -> Option<T> {
+    if *value < T::zero() {
+        value.checked_neg()
+    } else {
+        Some(value.clone())
+    }
+}
+
+/// ## Integer power, computed with repeated squaring. Negative powers are powers of the reciprocal
+/// Returns `None` on overflow, or for a negative power of an exact zero
+fn powi<T: Field>(value: &T, n: i32) -> Option<T> {
+    let mut base = if n < 0 {
+        T::one().checked_div(value)?
+    } else {
+        value.clone()
+    };
+    let mut exponent = n.unsigned_abs();
+    let mut result = T::one();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(&base)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base.checked_mul(&base)?;
+        }
+    }
+    Some(result)
+}
+
+/// ## A vector to pair with every row or every column of a matrix
+/// For example, subtracting the mean of each column from a matrix of observations uses the row of means:
+/// `data.broadcast_sub(Broadcast::Row(&means))`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Broadcast<'a, T: Add<T, Output = T>> {
+    /// One element per column, repeated for every row
+    Row(&'a LalgrsVector<T>),
+    /// One element per row, repeated for every column
+    Column(&'a LalgrsVector<T>),
+}
+
+impl<T: Add<T, Output = T>> LalgrsMatrix<T> {
+    /// ## New matrix with `f` applied to every element
+    pub fn map<U: Add<U, Output = U>, F: FnMut(&T) -> U>(&self, f: F) -> LalgrsMatrix<U> {
+        LalgrsMatrix {
+            shape: self.shape,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// ## New matrix with `f` applied to every pair of elements at the same position
+    /// See `LalgrsVector::zip_map` for vectors.
+    /// If the matrices have different shapes, returns an error
+    pub fn zip_with<U, V, F>(
+        &self,
+        other: &LalgrsMatrix<U>,
+        mut f: F,
+    ) -> Result<LalgrsMatrix<V>, LalgrsError>
+    where
+        U: Add<U, Output = U>,
+        V: Add<V, Output = V>,
+        F: FnMut(&T, &U) -> V,
+    {
+        if self.shape != other.shape {
+            return Err(LalgrsError::MismatchedMatrixDimensions {
+                lhs: self.shape,
+                rhs: other.shape,
+            });
+        }
+        Ok(LalgrsMatrix {
+            shape: self.shape,
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        })
+    }
+
+    /// ## Applies `f` to every element and a vector broadcast across the matrix
+    /// A `Broadcast::Row` vector has one element per column and is paired with every row,
+    /// a `Broadcast::Column` vector has one element per row and is paired with every column.
+    /// If the vector does not have one element per column or per row, returns an error
+    pub fn broadcast_with<U, V, F>(
+        &self,
+        vector: Broadcast<'_, U>,
+        mut f: F,
+    ) -> Result<LalgrsMatrix<V>, LalgrsError>
+    where
+        U: Add<U, Output = U>,
+        V: Add<V, Output = V>,
+        F: FnMut(&T, &U) -> V,
+    {
+        let (values, expected) = match vector {
+            Broadcast::Row(row) => (row, self.columns()),
+            Broadcast::Column(column) => (column, self.rows()),
+        };
+        if values.size() != expected {
+            return Err(LalgrsError::MismatchedVectorAndMatrixDimensions {
+                vector_size: values.size(),
+                matrix_shape: self.shape,
+            });
+        }
+        let rows = self.rows().max(1);
+        Ok(LalgrsMatrix {
+            shape: self.shape,
+            data: self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(k, a)| {
+                    // Column major: element k is at row k % rows and column k / rows
+                    let index = match vector {
+                        Broadcast::Row(_) => k / rows,
+                        Broadcast::Column(_) => k % rows,
+                    };
+                    f(a, &values.values[index])
+                })
+                .collect(),
+        })
+    }
+}
+
+impl<T> LalgrsMatrix<T>
+where
+    T: Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T> + Div<T, Output = T> + Clone,
+{
+    /// ## Element wise product, also known as the Hadamard product
+    /// If the matrices have different shapes, returns an error
+    pub fn elementwise_mul(&self, other: &LalgrsMatrix<T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.zip_with(other, |a, b| a.clone() * b.clone())
+    }
+
+    /// ## Element wise quotient
+    /// If the matrices have different shapes, returns an error
+    pub fn elementwise_div(&self, other: &LalgrsMatrix<T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.zip_with(other, |a, b| a.clone() / b.clone())
+    }
+
+    /// ## Adds a vector to every row or every column
+    /// If the vector does not have one element per column or per row, returns an error
+    pub fn broadcast_add(&self, vector: Broadcast<'_, T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.broadcast_with(vector, |a, b| a.clone() + b.clone())
+    }
+
+    /// ## Subtracts a vector from every row or every column
+    /// If the vector does not have one element per column or per row, returns an error
+    pub fn broadcast_sub(&self, vector: Broadcast<'_, T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.broadcast_with(vector, |a, b| a.clone() - b.clone())
+    }
+
+    /// ## Multiplies every row or every column by a vector, element by element
+    /// If the vector does not have one element per column or per row, returns an error
+    pub fn broadcast_mul(&self, vector: Broadcast<'_, T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.broadcast_with(vector, |a, b| a.clone() * b.clone())
+    }
+
+    /// ## Divides every row or every column by a vector, element by element
+    /// If the vector does not have one element per column or per row, returns an error
+    pub fn broadcast_div(&self, vector: Broadcast<'_, T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.broadcast_with(vector, |a, b| a.clone() / b.clone())
+    }
+}
+
+impl<T: Add<T, Output = T> + PartialOrd + Clone> LalgrsMatrix<T> {
+    /// ## Element wise minimum. Incomparable pairs, such as those with a NaN, keep the element of `self`
+    /// If the matrices have different shapes, returns an error
+    pub fn elementwise_min(&self, other: &LalgrsMatrix<T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.zip_with(other, min)
+    }
+
+    /// ## Element wise maximum. Incomparable pairs, such as those with a NaN, keep the element of `self`
+    /// If the matrices have different shapes, returns an error
+    pub fn elementwise_max(&self, other: &LalgrsMatrix<T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.zip_with(other, max)
+    }
+}
+
+impl<T: Field> LalgrsMatrix<T> {
+    /// ## New matrix with the fallible `f` applied to every element
+    /// If `f` returns `None` for an element, returns `LalgrsError::ArithmeticOverflow`
+    fn checked_map<F: FnMut(&T) -> Option<T>>(&self, f: F) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        Ok(LalgrsMatrix {
+            shape: self.shape,
+            data: self
+                .data
+                .iter()
+                .map(f)
+                .collect::<Option<_>>()
+                .ok_or(LalgrsError::ArithmeticOverflow)?,
+        })
+    }
+
+    /// ## Absolute value of every element
+    /// If an exact scalar overflows, returns an error
+    pub fn abs(&self) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.checked_map(abs)
+    }
+
+    /// ## Every element raised to the integer power `n`
+    /// Unlike `LalgrsMatrix::powi`, this is not a matrix power.
+    /// If an exact scalar overflows, or a negative power of an exact zero is taken, returns an error
+    pub fn elementwise_powi(&self, n: i32) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        self.checked_map(|v| powi(v, n))
+    }
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Every element raised to the real power `p`
+    /// Unlike `LalgrsMatrix::powf`, this is not a matrix power
+    pub fn elementwise_powf(&self, p: f64) -> LalgrsMatrix<f64> {
+        self.map(|v| v.powf(p))
+    }
+}
+
+impl<T: Add<T, Output = T> + Mul<T, Output = T> + Div<T, Output = T> + Clone> LalgrsVector<T> {
+    /// ## Element wise product
+    /// If the vectors have different sizes, returns an error
+    pub fn elementwise_mul(&self, other: &LalgrsVector<T>) -> Result<LalgrsVector<T>, LalgrsError> {
+        self.zip_map(other, |a, b| a.clone() * b.clone())
+    }
+
+    /// ## Element wise quotient
+    /// If the vectors have different sizes, returns an error
+    pub fn elementwise_div(&self, other: &LalgrsVector<T>) -> Result<LalgrsVector<T>, LalgrsError> {
+        self.zip_map(other, |a, b| a.clone() / b.clone())
+    }
+}
+
+impl<T: Add<T, Output = T> + PartialOrd + Clone> LalgrsVector<T> {
+    /// ## Element wise minimum. Incomparable pairs, such as those with a NaN, keep the element of `self`
+    /// If the vectors have different sizes, returns an error
+    pub fn elementwise_min(&self, other: &LalgrsVector<T>) -> Result<LalgrsVector<T>, LalgrsError> {
+        self.zip_map(other, min)
+    }
+
+    /// ## Element wise maximum. Incomparable pairs, such as those with a NaN, keep the element of `self`
+    /// If the vectors have different sizes, returns an error
+    pub fn elementwise_max(&self, other: &LalgrsVector<T>) -> Result<LalgrsVector<T>, LalgrsError> {
+        self.zip_map(other, max)
+    }
+}
+
+impl<T: Field> LalgrsVector<T> {
+    /// ## New vector with the fallible `f` applied to every element
+    /// If `f` returns `None` for an element, returns `LalgrsError::ArithmeticOverflow`
+    fn checked_map<F: FnMut(&T) -> Option<T>>(&self, f: F) -> Result<LalgrsVector<T>, LalgrsError> {
+        Ok(LalgrsVector::new(
+            self.values
+                .iter()
+                .map(f)
+                .collect::<Option<_>>()
+                .ok_or(LalgrsError::ArithmeticOverflow)?,
+        ))
+    }
+
+    /// ## Absolute value of every element
+    /// If an exact scalar overflows, returns an error
+    pub fn abs(&self) -> Result<LalgrsVector<T>, LalgrsError> {
+        self.checked_map(abs)
+    }
+
+    /// ## Every element raised to the integer power `n`
+    /// If an exact scalar overflows, or a negative power of an exact zero is taken, returns an error
+    pub fn elementwise_powi(&self, n: i32) -> Result<LalgrsVector<T>, LalgrsError> {
+        self.checked_map(|v| powi(v, n))
+    }
+}
+
+impl LalgrsVector<f64> {
+    /// ## Every element raised to the real power `p`
+    pub fn elementwise_powf(&self, p: f64) -> LalgrsVector<f64> {
+        self.map(|v| v.powf(p))
+    }
+}
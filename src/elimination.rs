@@ -0,0 +1,401 @@
+use std::fmt::{self, Display};
+
+use crate::{scalar::Field, LalgrsError, LalgrsMatrix};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Gaussian elimination
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Elementary row operation
+/// Rows are counted from zero, but are displayed counting from one, as in textbooks.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowOperation<T> {
+    /// Swaps two rows
+    Swap { first: usize, second: usize },
+    /// Multiplies a row by a non zero factor
+    Scale { row: usize, factor: T },
+    /// Adds `factor` times row `source` to row `target`
+    AddMultiple {
+        target: usize,
+        source: usize,
+        factor: T,
+    },
+    /// Sets the elements of `column` in `rows`, which are equal to `value` up to rounding errors, to exactly `value`.
+    /// Only floating point eliminations use it
+    Round {
+        rows: Vec<usize>,
+        column: usize,
+        value: T,
+    },
+}
+
+impl<T: Field> RowOperation<T> {
+    /// ## Applies the operation to `matrix`
+    /// If an exact scalar overflows, returns an error and leaves `matrix` partly updated
+    pub fn apply(&self, matrix: &mut LalgrsMatrix<T>) -> Result<(), LalgrsError> {
+        let columns = matrix.columns();
+        match self {
+            RowOperation::Swap { first, second } => {
+                for j in 0..columns {
+                    let value = matrix[(*first, j)].clone();
+                    matrix[(*first, j)] = matrix[(*second, j)].clone();
+                    matrix[(*second, j)] = value;
+                }
+            }
+            RowOperation::Scale { row, factor } => {
+                for j in 0..columns {
+                    matrix[(*row, j)] = matrix[(*row, j)]
+                        .checked_mul(factor)
+                        .ok_or(LalgrsError::ArithmeticOverflow)?;
+                }
+            }
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => {
+                for j in 0..columns {
+                    matrix[(*target, j)] = factor
+                        .checked_mul(&matrix[(*source, j)])
+                        .and_then(|product| matrix[(*target, j)].checked_add(&product))
+                        .ok_or(LalgrsError::ArithmeticOverflow)?;
+                }
+            }
+            RowOperation::Round {
+                rows,
+                column,
+                value,
+            } => {
+                for row in rows {
+                    matrix[(*row, *column)] = value.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// ## The operation in LaTeX, e.g. `R_2 \to R_2 - 3 R_1`
+    pub fn to_latex(&self) -> String {
+        match self {
+            RowOperation::Swap { first, second } => {
+                format!("R_{{{}}} \\leftrightarrow R_{{{}}}", first + 1, second + 1)
+            }
+            RowOperation::Scale { row, factor } => {
+                format!("R_{{{0}}} \\to {1} R_{{{0}}}", row + 1, factor.to_latex())
+            }
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => {
+                let (sign, magnitude) = signed(factor);
+                format!(
+                    "R_{{{0}}} \\to R_{{{0}}} {1} {2} R_{{{3}}}",
+                    target + 1,
+                    sign,
+                    magnitude.to_latex(),
+                    source + 1
+                )
+            }
+            RowOperation::Round {
+                rows,
+                column,
+                value,
+            } => {
+                let elements: Vec<String> = rows
+                    .iter()
+                    .map(|row| format!("a_{{{},{}}}", row + 1, column + 1))
+                    .collect();
+                format!("{} \\approx {}", elements.join(", "), value.to_latex())
+            }
+        }
+    }
+}
+
+/// ## Sign and absolute value of `value`
+fn signed<T: Field>(value: &T) -> (char, T) {
+    if *value < T::zero() {
+        ('-', -value.clone())
+    } else {
+        ('+', value.clone())
+    }
+}
+
+/// ## Operation setting the elements of `column` in `rows` that differ from `value` to exactly `value`
+/// Returns `None` if they are all exact
+fn rounding<T: Field>(
+    matrix: &LalgrsMatrix<T>,
+    rows: impl Iterator<Item = usize>,
+    column: usize,
+    value: T,
+) -> Option<RowOperation<T>> {
+    let rows: Vec<usize> = rows.filter(|i| matrix[(*i, column)] != value).collect();
+    (!rows.is_empty()).then_some(RowOperation::Round {
+        rows,
+        column,
+        value,
+    })
+}
+
+/// ## The operation as text, e.g. `R2 -> R2 - 3 R1`
+impl<T: Field> Display for RowOperation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowOperation::Swap { first, second } => write!(f, "R{} <-> R{}", first + 1, second + 1),
+            RowOperation::Scale { row, factor } => {
+                write!(f, "R{0} -> ({1}) R{0}", row + 1, factor)
+            }
+            RowOperation::AddMultiple {
+                target,
+                source,
+                factor,
+            } => {
+                let (sign, magnitude) = signed(factor);
+                write!(
+                    f,
+                    "R{0} -> R{0} {1} ({2}) R{3}",
+                    target + 1,
+                    sign,
+                    magnitude,
+                    source + 1
+                )
+            }
+            RowOperation::Round {
+                rows,
+                column,
+                value,
+            } => {
+                let elements: Vec<String> = rows
+                    .iter()
+                    .map(|row| format!("a({},{})", row + 1, column + 1))
+                    .collect();
+                write!(f, "{} ~ {}", elements.join(", "), value)
+            }
+        }
+    }
+}
+
+/// ## Result of Gaussian elimination
+#[derive(Debug, Clone, PartialEq)]
+pub struct Echelon<T: Field> {
+    /// The matrix in row echelon or reduced row echelon form
+    pub matrix: LalgrsMatrix<T>,
+    /// Column of the pivot of each non zero row
+    pub pivots: Vec<usize>,
+}
+
+impl<T: Field> Echelon<T> {
+    /// ## Number of pivots
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+}
+
+/// ## Every row operation of an elimination, with the matrix obtained after it
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliminationTrace<T: Field> {
+    pub start: LalgrsMatrix<T>,
+    pub steps: Vec<(RowOperation<T>, LalgrsMatrix<T>)>,
+}
+
+/// ## Rows of `matrix` as text, with right aligned columns
+fn matrix_to_text<T: Field>(matrix: &LalgrsMatrix<T>) -> String {
+    let cells: Vec<Vec<String>> = (0..matrix.rows())
+        .map(|i| {
+            (0..matrix.columns())
+                .map(|j| matrix[(i, j)].to_string())
+                .collect()
+        })
+        .collect();
+    let width = cells.iter().flatten().map(|c| c.len()).max().unwrap_or(0);
+    cells
+        .iter()
+        .map(|row| {
+            let row: Vec<String> = row.iter().map(|c| format!("{c:>width$}")).collect();
+            format!("[ {} ]\n", row.join("  "))
+        })
+        .collect()
+}
+
+/// ## `matrix` as a LaTeX `bmatrix`
+fn matrix_to_latex<T: Field>(matrix: &LalgrsMatrix<T>) -> String {
+    let rows: Vec<String> = (0..matrix.rows())
+        .map(|i| {
+            let row: Vec<String> = (0..matrix.columns())
+                .map(|j| matrix[(i, j)].to_latex())
+                .collect();
+            row.join(" & ")
+        })
+        .collect();
+    format!(
+        "\\begin{{bmatrix}} {} \\end{{bmatrix}}",
+        rows.join(" \\\\ ")
+    )
+}
+
+impl<T: Field> EliminationTrace<T> {
+    /// ## The starting matrix followed by every operation and the matrix it produces, as plain text
+    pub fn to_text(&self) -> String {
+        let mut text = matrix_to_text(&self.start);
+        for (operation, matrix) in &self.steps {
+            text.push_str(&format!("\n{operation}\n"));
+            text.push_str(&matrix_to_text(matrix));
+        }
+        text
+    }
+
+    /// ## The elimination as a chain of matrices linked by arrows labelled with the operations
+    /// The result is meant to be placed in a LaTeX math environment such as `align*`,
+    /// with one step per line
+    pub fn to_latex(&self) -> String {
+        let mut latex = matrix_to_latex(&self.start);
+        for (operation, matrix) in &self.steps {
+            latex.push_str(&format!(
+                " \\\\\n&\\xrightarrow{{{}}} {}",
+                operation.to_latex(),
+                matrix_to_latex(matrix)
+            ));
+        }
+        latex
+    }
+}
+
+impl<T: Field> LalgrsMatrix<T> {
+    /// ## Row echelon form, computed with Gaussian elimination
+    /// Exact scalars such as `Rational` use the first non zero pivot of each column,
+    /// floating point scalars the largest one, and treat values not larger than
+    /// `max(rows, columns) * ε * max|a_ij|` as zero.
+    /// If an exact scalar overflows, returns an error
+    pub fn row_echelon(&self) -> Result<Echelon<T>, LalgrsError> {
+        self.eliminate(false, None)
+    }
+
+    /// ## Row echelon form, with every row operation used to compute it
+    /// If an exact scalar overflows, returns an error
+    pub fn row_echelon_traced(&self) -> Result<(Echelon<T>, EliminationTrace<T>), LalgrsError> {
+        let mut steps = Vec::new();
+        let echelon = self.eliminate(false, Some(&mut steps))?;
+        Ok((
+            echelon,
+            EliminationTrace {
+                start: self.clone(),
+                steps,
+            },
+        ))
+    }
+
+    /// ## Reduced row echelon form, computed with Gauss-Jordan elimination
+    /// Pivots are chosen as in `LalgrsMatrix::row_echelon`.
+    /// If an exact scalar overflows, returns an error
+    pub fn rref(&self) -> Result<Echelon<T>, LalgrsError> {
+        self.eliminate(true, None)
+    }
+
+    /// ## Reduced row echelon form, with every row operation used to compute it
+    /// If an exact scalar overflows, returns an error
+    pub fn rref_traced(&self) -> Result<(Echelon<T>, EliminationTrace<T>), LalgrsError> {
+        let mut steps = Vec::new();
+        let echelon = self.eliminate(true, Some(&mut steps))?;
+        Ok((
+            echelon,
+            EliminationTrace {
+                start: self.clone(),
+                steps,
+            },
+        ))
+    }
+
+    fn eliminate(
+        &self,
+        reduced: bool,
+        mut trace: Option<&mut Vec<(RowOperation<T>, LalgrsMatrix<T>)>>,
+    ) -> Result<Echelon<T>, LalgrsError> {
+        let (rows, columns) = (self.rows(), self.columns());
+        let scale = self
+            .as_column_major_slice()
+            .iter()
+            .map(|v| v.magnitude())
+            .fold(0.0, f64::max);
+        let tolerance = T::tolerance(scale, rows.max(columns));
+        let is_zero = |value: &T| value.magnitude() <= tolerance;
+
+        let mut matrix = self.clone();
+        let mut apply = |matrix: &mut LalgrsMatrix<T>, operation: RowOperation<T>| {
+            operation.apply(matrix)?;
+            if let Some(steps) = trace.as_deref_mut() {
+                steps.push((operation, matrix.clone()));
+            }
+            Ok::<(), LalgrsError>(())
+        };
+
+        let mut pivots = Vec::new();
+        for column in 0..columns {
+            let row = pivots.len();
+            if row == rows {
+                break;
+            }
+            let candidates = (row..rows).filter(|i| !is_zero(&matrix[(*i, column)]));
+            let pivot = if T::EXACT {
+                candidates.min()
+            } else {
+                candidates.max_by(|a, b| {
+                    matrix[(*a, column)]
+                        .magnitude()
+                        .total_cmp(&matrix[(*b, column)].magnitude())
+                })
+            };
+            let Some(pivot) = pivot else {
+                // Every remaining element of the column is a rounding error
+                if let Some(operation) = rounding(&matrix, row..rows, column, T::zero()) {
+                    apply(&mut matrix, operation)?;
+                }
+                continue;
+            };
+
+            if pivot != row {
+                apply(
+                    &mut matrix,
+                    RowOperation::Swap {
+                        first: row,
+                        second: pivot,
+                    },
+                )?;
+            }
+            if reduced && matrix[(row, column)] != T::one() {
+                let factor = T::one()
+                    .checked_div(&matrix[(row, column)])
+                    .ok_or(LalgrsError::ArithmeticOverflow)?;
+                apply(&mut matrix, RowOperation::Scale { row, factor })?;
+                if let Some(operation) = rounding(&matrix, row..=row, column, T::one()) {
+                    apply(&mut matrix, operation)?;
+                }
+            }
+
+            let targets = if reduced { 0..rows } else { row + 1..rows };
+            for target in targets.clone().filter(|i| *i != row) {
+                if !is_zero(&matrix[(target, column)]) {
+                    let factor = matrix[(target, column)]
+                        .checked_div(&matrix[(row, column)])
+                        .and_then(|ratio| ratio.checked_neg())
+                        .ok_or(LalgrsError::ArithmeticOverflow)?;
+                    apply(
+                        &mut matrix,
+                        RowOperation::AddMultiple {
+                            target,
+                            source: row,
+                            factor,
+                        },
+                    )?;
+                }
+            }
+            // The eliminated elements, and those that were already negligible, are left with rounding errors
+            let targets = targets.filter(|i| *i != row);
+            if let Some(operation) = rounding(&matrix, targets, column, T::zero()) {
+                apply(&mut matrix, operation)?;
+            }
+            pivots.push(column);
+        }
+
+        Ok(Echelon { matrix, pivots })
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{decomposition::check_square, LalgrsError, LalgrsMatrix};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Matrix functions
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maximum number of square roots or Denman-Beavers iterations before giving up
+const MAX_ITERATIONS: usize = 100;
+
+/// ## Coefficients of the Padé approximants of degree 3, 5, 7 and 9 of the exponential,
+/// with the largest 1-norm for which each is accurate to double precision (Higham, 2005)
+const PADE_APPROXIMANTS: [(f64, &[f64]); 4] = [
+    (1.495585217958292e-2, &[120.0, 60.0, 12.0, 1.0]),
+    (
+        2.53939833006323e-1,
+        &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0],
+    ),
+    (
+        9.504178996162932e-1,
+        &[
+            17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+        ],
+    ),
+    (
+        2.097847961257068e0,
+        &[
+            17643225600.0,
+            8821612800.0,
+            2075673600.0,
+            302702400.0,
+            30270240.0,
+            2162160.0,
+            110880.0,
+            3960.0,
+            90.0,
+            1.0,
+        ],
+    ),
+];
+
+/// ## Coefficients of the Padé approximant of degree 13 of the exponential and the largest 1-norm it handles
+const PADE_13: (f64, [f64; 14]) = (
+    5.371920351148152e0,
+    [
+        64764752532480000.0,
+        32382376266240000.0,
+        7771770303897600.0,
+        1187353796428800.0,
+        129060195264000.0,
+        10559470521600.0,
+        670442572800.0,
+        33522128640.0,
+        1323241920.0,
+        40840800.0,
+        960960.0,
+        16380.0,
+        182.0,
+        1.0,
+    ],
+);
+
+/// ## Nodes and weights of the 8 point Gauss-Legendre rule on `[-1, 1]`, positive nodes only
+const GAUSS_LEGENDRE_8: [(f64, f64); 4] = [
+    (0.1834346424956498, 0.362683783378362),
+    (0.525532409916329, 0.3137066458778873),
+    (0.7966664774136267, 0.2223810344533745),
+    (0.9602898564975363, 0.1012285362903763),
+];
+
+/// ## Product of two matrices whose shapes are known to match
+fn product(lhs: &LalgrsMatrix<f64>, rhs: &LalgrsMatrix<f64>) -> LalgrsMatrix<f64> {
+    LalgrsMatrix::from_fn(lhs.rows(), rhs.columns(), |i, j| {
+        (0..lhs.columns()).map(|k| lhs[(i, k)] * rhs[(k, j)]).sum()
+    })
+}
+
+/// ## `Σ cᵢ * Mᵢ` for matrices of the same shape
+fn linear_combination(
+    terms: &[(f64, &LalgrsMatrix<f64>)],
+    shape: crate::Shape,
+) -> LalgrsMatrix<f64> {
+    LalgrsMatrix::from_fn(shape.rows, shape.columns, |i, j| {
+        terms.iter().map(|(c, m)| c * m[(i, j)]).sum()
+    })
+}
+
+/// ## Solves `(V - U) * X = V + U`, the rational approximation of the exponential
+fn pade_quotient(
+    u: &LalgrsMatrix<f64>,
+    v: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    let shape = u.shape();
+    let numerator = linear_combination(&[(1.0, v), (1.0, u)], shape);
+    let denominator = linear_combination(&[(1.0, v), (-1.0, u)], shape);
+    denominator.lu()?.solve_matrix(&numerator)
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Matrix exponential `e^A`, computed with scaling and squaring and Padé approximants
+    /// The matrix is scaled by a power of two until a Padé approximant of degree at most 13 is accurate,
+    /// and the approximant is squared back (Higham, 2005).
+    /// If the matrix is empty or not square, or has an infinite or NaN element, returns an error
+    pub fn expm(&self) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        check_square(self)?;
+        // A non finite norm would ask for an unbounded number of squarings
+        if let Some(value) = self.as_column_major_slice().iter().find(|x| !x.is_finite()) {
+            return Err(LalgrsError::InvalidParameter {
+                name: "element",
+                value: *value,
+            });
+        }
+        let n = self.rows();
+        let shape = self.shape();
+        let identity = LalgrsMatrix::identity(n);
+        let norm = self.one_norm();
+
+        let a2 = product(self, self);
+        for (theta, coefficients) in PADE_APPROXIMANTS {
+            if norm <= theta {
+                // Even powers of A, up to the degree of the approximant
+                let mut powers = vec![identity.clone(), a2.clone()];
+                while powers.len() * 2 < coefficients.len() {
+                    let next = product(&powers[powers.len() - 1], &a2);
+                    powers.push(next);
+                }
+                let odd: Vec<(f64, &LalgrsMatrix<f64>)> = powers
+                    .iter()
+                    .enumerate()
+                    .map(|(k, p)| (coefficients[2 * k + 1], p))
+                    .collect();
+                let even: Vec<(f64, &LalgrsMatrix<f64>)> = powers
+                    .iter()
+                    .enumerate()
+                    .map(|(k, p)| (coefficients[2 * k], p))
+                    .collect();
+                let u = product(self, &linear_combination(&odd, shape));
+                let v = linear_combination(&even, shape);
+                return pade_quotient(&u, &v);
+            }
+        }
+
+        let (theta, b) = PADE_13;
+        let squarings = if norm > theta {
+            (norm / theta).log2().ceil() as i32
+        } else {
+            0
+        };
+        let factor = 2f64.powi(-squarings);
+        let a = self.clone() * factor;
+        let a2 = a2 * (factor * factor);
+        let a4 = product(&a2, &a2);
+        let a6 = product(&a4, &a2);
+
+        let u_inner = product(
+            &a6,
+            &linear_combination(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)], shape),
+        );
+        let u = product(
+            &a,
+            &linear_combination(
+                &[
+                    (1.0, &u_inner),
+                    (b[7], &a6),
+                    (b[5], &a4),
+                    (b[3], &a2),
+                    (b[1], &identity),
+                ],
+                shape,
+            ),
+        );
+        let v_inner = product(
+            &a6,
+            &linear_combination(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)], shape),
+        );
+        let v = linear_combination(
+            &[
+                (1.0, &v_inner),
+                (b[6], &a6),
+                (b[4], &a4),
+                (b[2], &a2),
+                (b[0], &identity),
+            ],
+            shape,
+        );
+
+        let mut result = pade_quotient(&u, &v)?;
+        for _ in 0..squarings {
+            result = product(&result, &result);
+        }
+        Ok(result)
+    }
+
+    /// ## Principal square root, the square root whose eigenvalues have positive real part
+    /// Computed with the Denman-Beavers iteration, scaled by determinants to speed up convergence.
+    /// If the matrix is empty, not square or singular, returns an error.
+    /// Matrices with eigenvalues on the negative real axis have no real principal square root:
+    /// the iteration does not converge and an error is returned
+    pub fn sqrtm(&self) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        check_square(self)?;
+        let n = self.rows();
+        let shape = self.shape();
+        if self.lu()?.is_singular() {
+            return Err(LalgrsError::SingularMatrix);
+        }
+
+        let mut y = self.clone();
+        let mut z = LalgrsMatrix::identity(n);
+        let mut converged = false;
+        for _ in 0..MAX_ITERATIONS {
+            let (y_lu, z_lu) = (y.lu()?, z.lu()?);
+            let (Ok(y_inverse), Ok(z_inverse)) = (y_lu.inverse(), z_lu.inverse()) else {
+                break;
+            };
+            let mut scale = (y_lu.determinant() * z_lu.determinant())
+                .abs()
+                .powf(-0.5 / n as f64);
+            if !scale.is_finite() || scale == 0.0 || converged {
+                scale = 1.0;
+            }
+
+            let next_y = linear_combination(&[(scale / 2.0, &y), (0.5 / scale, &z_inverse)], shape);
+            let next_z = linear_combination(&[(scale / 2.0, &z), (0.5 / scale, &y_inverse)], shape);
+            let change = linear_combination(&[(1.0, &next_y), (-1.0, &y)], shape).one_norm();
+            y = next_y;
+            z = next_z;
+
+            // The iteration converges quadratically: once the change is around the square root of the
+            // machine epsilon, one more unscaled iteration reaches full precision
+            if converged {
+                return Ok(y);
+            }
+            converged = change <= f64::EPSILON.sqrt() * y.one_norm();
+        }
+        Err(LalgrsError::NoConvergence {
+            iterations: MAX_ITERATIONS,
+        })
+    }
+
+    /// ## Principal logarithm, the logarithm whose eigenvalues have imaginary part in `(-π, π)`
+    /// Computed with inverse scaling and squaring: square roots are taken until the matrix is close to the identity,
+    /// then `log(I + E)` is integrated with Gauss-Legendre quadrature.
+    /// If the matrix is empty, not square or singular, or has eigenvalues on the negative real axis, returns an error
+    pub fn logm(&self) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        check_square(self)?;
+        let n = self.rows();
+        let shape = self.shape();
+        let identity = LalgrsMatrix::identity(n);
+
+        let mut x = self.clone();
+        let mut roots: i32 = 0;
+        let mut e = linear_combination(&[(1.0, &x), (-1.0, &identity)], shape);
+        while e.one_norm() > 0.25 {
+            if roots as usize == MAX_ITERATIONS {
+                return Err(LalgrsError::NoConvergence {
+                    iterations: MAX_ITERATIONS,
+                });
+            }
+            x = x.sqrtm()?;
+            roots += 1;
+            e = linear_combination(&[(1.0, &x), (-1.0, &identity)], shape);
+        }
+
+        // log(I + E) = ∫₀¹ E * (I + t * E)⁻¹ dt
+        let mut log = LalgrsMatrix::zeros(n, n);
+        for (node, weight) in GAUSS_LEGENDRE_8 {
+            for t in [(1.0 - node) / 2.0, (1.0 + node) / 2.0] {
+                let shifted = linear_combination(&[(1.0, &identity), (t, &e)], shape);
+                let term = shifted.lu()?.solve_matrix(&e)?;
+                log = linear_combination(&[(1.0, &log), (weight / 2.0, &term)], shape);
+            }
+        }
+        Ok(log * 2f64.powi(roots))
+    }
+
+    /// ## Integer power, computed with repeated squaring
+    /// Negative powers are powers of the inverse, and the zeroth power is the identity.
+    /// If the matrix is empty or not square, or the power is negative and the matrix is singular, returns an error
+    pub fn powi(&self, n: i32) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        check_square(self)?;
+        let mut base = if n < 0 { self.inverse()? } else { self.clone() };
+        let mut exponent = n.unsigned_abs();
+        let mut result = LalgrsMatrix::identity(self.rows());
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = product(&result, &base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = product(&base, &base);
+            }
+        }
+        Ok(result)
+    }
+
+    /// ## Real power `A^p = e^(p * log(A))`, using the principal logarithm
+    /// Integer powers are computed with `LalgrsMatrix::powi` and work for any square matrix.
+    /// Other powers need a matrix without eigenvalues on the closed negative real axis, as `LalgrsMatrix::logm`.
+    /// If the matrix is empty or not square, `p` is not finite, or the power is not defined, returns an error
+    pub fn powf(&self, p: f64) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        if !p.is_finite() {
+            return Err(LalgrsError::InvalidParameter {
+                name: "p",
+                value: p,
+            });
+        }
+        if p.fract() == 0.0 && p.abs() <= i32::MAX as f64 {
+            return self.powi(p as i32);
+        }
+        (self.logm()? * p).expm()
+    }
+}
@@ -0,0 +1,733 @@
+use std::ops;
+
+use crate::{
+    LalgrsError, LalgrsMatrix, LalgrsVector, SMatrix, SMatrix2, SMatrix3, SMatrix4, SVector,
+    SVector2, SVector3,
+};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Conversions between LalgrsVector and static vectors
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn to_static<const N: usize>(vector: &LalgrsVector<f64>) -> Result<SVector<f64, N>, LalgrsError> {
+    SVector::try_from(vector)
+}
+
+fn to_dynamic<const N: usize>(vector: SVector<f64, N>) -> LalgrsVector<f64> {
+    vector.into()
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # 2D rotations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Counterclockwise rotation in the plane
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation2 {
+    matrix: SMatrix2<f64>,
+}
+
+impl Rotation2 {
+    /// ## Rotation by `angle` radians, counterclockwise
+    pub fn new(angle: f64) -> Rotation2 {
+        let (sin, cos) = angle.sin_cos();
+        Rotation2 {
+            matrix: SMatrix::from_rows([[cos, -sin], [sin, cos]]),
+        }
+    }
+
+    pub fn identity() -> Rotation2 {
+        Rotation2::new(0.0)
+    }
+
+    /// ## Rotation angle in radians, in `(-pi, pi]`
+    pub fn angle(&self) -> f64 {
+        self.matrix[(1, 0)].atan2(self.matrix[(0, 0)])
+    }
+
+    pub fn matrix(&self) -> SMatrix2<f64> {
+        self.matrix
+    }
+
+    pub fn inverse(&self) -> Rotation2 {
+        Rotation2 {
+            matrix: self.matrix.transpose(),
+        }
+    }
+
+    pub fn rotate(&self, vector: SVector2<f64>) -> SVector2<f64> {
+        self.matrix * vector
+    }
+
+    /// ## Rotates a vector with 2 elements
+    /// If the vector does not have 2 elements, returns an error
+    pub fn transform_vector(
+        &self,
+        vector: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(to_dynamic(self.rotate(to_static(vector)?)))
+    }
+
+    /// ## Rotates a point with 2 elements around the origin
+    /// If the point does not have 2 elements, returns an error
+    pub fn transform_point(
+        &self,
+        point: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.transform_vector(point)
+    }
+}
+
+/// ## Composition of two rotations. `a * b` applies `b` first
+impl ops::Mul<Rotation2> for Rotation2 {
+    type Output = Rotation2;
+    fn mul(self, rhs: Rotation2) -> Self::Output {
+        Rotation2 {
+            matrix: self.matrix * rhs.matrix,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # 3D rotations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Rotation in 3 dimensional space, stored as an orthogonal matrix
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation3 {
+    matrix: SMatrix3<f64>,
+}
+
+impl Rotation3 {
+    pub fn identity() -> Rotation3 {
+        Rotation3 {
+            matrix: SMatrix::identity(),
+        }
+    }
+
+    /// ## Rotation by `angle` radians around `axis`, following the right hand rule
+    /// The axis does not need to be normalised. If it has zero length, returns an error
+    pub fn from_axis_angle(axis: SVector3<f64>, angle: f64) -> Result<Rotation3, LalgrsError> {
+        Ok(UnitQuaternion::from_axis_angle(axis, angle)?.to_rotation())
+    }
+
+    /// ## Rotation from roll, pitch and yaw angles in radians
+    /// Rotates by `roll` around the x axis, then by `pitch` around the y axis, then by `yaw` around the z axis
+    pub fn from_euler_angles(roll: f64, pitch: f64, yaw: f64) -> Rotation3 {
+        let (sr, cr) = roll.sin_cos();
+        let (sp, cp) = pitch.sin_cos();
+        let (sy, cy) = yaw.sin_cos();
+        Rotation3 {
+            matrix: SMatrix::from_rows([
+                [cy * cp, cy * sp * sr - sy * cr, cy * sp * cr + sy * sr],
+                [sy * cp, sy * sp * sr + cy * cr, sy * sp * cr - cy * sr],
+                [-sp, cp * sr, cp * cr],
+            ]),
+        }
+    }
+
+    /// ## Roll, pitch and yaw angles of the rotation, as used by `from_euler_angles`
+    /// When the pitch is close to +-pi/2 the roll and yaw are not unique, and the roll is set to zero
+    pub fn euler_angles(&self) -> (f64, f64, f64) {
+        let m = &self.matrix;
+        let pitch = (-m[(2, 0)]).clamp(-1.0, 1.0).asin();
+        if m[(2, 0)].abs() < 1.0 - 1e-12 {
+            (
+                m[(2, 1)].atan2(m[(2, 2)]),
+                pitch,
+                m[(1, 0)].atan2(m[(0, 0)]),
+            )
+        } else {
+            (0.0, pitch, (-m[(0, 1)]).atan2(m[(1, 1)]))
+        }
+    }
+
+    /// ## Builds a rotation from a matrix, without checking that it is orthogonal
+    pub fn from_matrix_unchecked(matrix: SMatrix3<f64>) -> Rotation3 {
+        Rotation3 { matrix }
+    }
+
+    pub fn matrix(&self) -> SMatrix3<f64> {
+        self.matrix
+    }
+
+    /// ## Rotation axis and angle in radians, with the angle in `[0, pi]`
+    /// Returns `None` for the identity rotation, which has no defined axis
+    pub fn axis_angle(&self) -> Option<(SVector3<f64>, f64)> {
+        UnitQuaternion::from_rotation(self).axis_angle()
+    }
+
+    pub fn inverse(&self) -> Rotation3 {
+        Rotation3 {
+            matrix: self.matrix.transpose(),
+        }
+    }
+
+    pub fn rotate(&self, vector: SVector3<f64>) -> SVector3<f64> {
+        self.matrix * vector
+    }
+
+    /// ## Rotates a vector with 3 elements
+    /// If the vector does not have 3 elements, returns an error
+    pub fn transform_vector(
+        &self,
+        vector: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(to_dynamic(self.rotate(to_static(vector)?)))
+    }
+
+    /// ## Rotates a point with 3 elements around the origin
+    /// If the point does not have 3 elements, returns an error
+    pub fn transform_point(
+        &self,
+        point: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.transform_vector(point)
+    }
+}
+
+/// ## Composition of two rotations. `a * b` applies `b` first
+impl ops::Mul<Rotation3> for Rotation3 {
+    type Output = Rotation3;
+    fn mul(self, rhs: Rotation3) -> Self::Output {
+        Rotation3 {
+            matrix: self.matrix * rhs.matrix,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Quaternions
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Quaternion of unit length, representing a 3D rotation
+/// `w` is the scalar part and `(i, j, k)` the vector part
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitQuaternion {
+    w: f64,
+    i: f64,
+    j: f64,
+    k: f64,
+}
+
+impl UnitQuaternion {
+    pub fn identity() -> UnitQuaternion {
+        UnitQuaternion {
+            w: 1.0,
+            i: 0.0,
+            j: 0.0,
+            k: 0.0,
+        }
+    }
+
+    /// ## Normalises the quaternion `w + i x + j y + k z`
+    /// If it has zero length, returns an error
+    pub fn new(w: f64, i: f64, j: f64, k: f64) -> Result<UnitQuaternion, LalgrsError> {
+        let norm = (w * w + i * i + j * j + k * k).sqrt();
+        if norm == 0.0 || !norm.is_finite() {
+            return Err(LalgrsError::ZeroLengthQuaternion);
+        }
+        Ok(UnitQuaternion {
+            w: w / norm,
+            i: i / norm,
+            j: j / norm,
+            k: k / norm,
+        })
+    }
+
+    /// ## Rotation by `angle` radians around `axis`, following the right hand rule
+    /// The axis does not need to be normalised. If it has zero length, returns an error
+    pub fn from_axis_angle(axis: SVector3<f64>, angle: f64) -> Result<UnitQuaternion, LalgrsError> {
+        let norm = axis.norm();
+        if norm == 0.0 || !norm.is_finite() {
+            return Err(LalgrsError::ZeroLengthAxis);
+        }
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        UnitQuaternion::new(
+            cos,
+            sin * axis[0] / norm,
+            sin * axis[1] / norm,
+            sin * axis[2] / norm,
+        )
+    }
+
+    /// ## Quaternion of a rotation matrix
+    pub fn from_rotation(rotation: &Rotation3) -> UnitQuaternion {
+        let m = &rotation.matrix;
+        let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+        // Shepperd's method: divide by the largest of the four candidate components to stay accurate
+        let (w, i, j, k) = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            (
+                s / 4.0,
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(1, 0)] - m[(0, 1)]) / s,
+            )
+        } else if m[(0, 0)] > m[(1, 1)] && m[(0, 0)] > m[(2, 2)] {
+            let s = (1.0 + m[(0, 0)] - m[(1, 1)] - m[(2, 2)]).sqrt() * 2.0;
+            (
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                s / 4.0,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+            )
+        } else if m[(1, 1)] > m[(2, 2)] {
+            let s = (1.0 + m[(1, 1)] - m[(0, 0)] - m[(2, 2)]).sqrt() * 2.0;
+            (
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                s / 4.0,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+            )
+        } else {
+            let s = (1.0 + m[(2, 2)] - m[(0, 0)] - m[(1, 1)]).sqrt() * 2.0;
+            (
+                (m[(1, 0)] - m[(0, 1)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+                s / 4.0,
+            )
+        };
+        UnitQuaternion::new(w, i, j, k).unwrap_or(UnitQuaternion::identity())
+    }
+
+    /// ## Components as `(w, i, j, k)`
+    pub fn components(&self) -> (f64, f64, f64, f64) {
+        (self.w, self.i, self.j, self.k)
+    }
+
+    pub fn to_rotation(&self) -> Rotation3 {
+        let UnitQuaternion { w, i, j, k } = *self;
+        Rotation3 {
+            matrix: SMatrix::from_rows([
+                [
+                    1.0 - 2.0 * (j * j + k * k),
+                    2.0 * (i * j - w * k),
+                    2.0 * (i * k + w * j),
+                ],
+                [
+                    2.0 * (i * j + w * k),
+                    1.0 - 2.0 * (i * i + k * k),
+                    2.0 * (j * k - w * i),
+                ],
+                [
+                    2.0 * (i * k - w * j),
+                    2.0 * (j * k + w * i),
+                    1.0 - 2.0 * (i * i + j * j),
+                ],
+            ]),
+        }
+    }
+
+    /// ## Rotation angle in radians, in `[0, pi]`
+    pub fn angle(&self) -> f64 {
+        let vector_norm = (self.i * self.i + self.j * self.j + self.k * self.k).sqrt();
+        2.0 * vector_norm.atan2(self.w.abs())
+    }
+
+    /// ## Rotation axis and angle in radians, with the angle in `[0, pi]`
+    /// Returns `None` for the identity rotation, which has no defined axis
+    pub fn axis_angle(&self) -> Option<(SVector3<f64>, f64)> {
+        let vector_norm = (self.i * self.i + self.j * self.j + self.k * self.k).sqrt();
+        if vector_norm < f64::EPSILON {
+            return None;
+        }
+        // q and -q are the same rotation: pick the one with a non negative scalar part
+        let sign = if self.w < 0.0 { -1.0 } else { 1.0 };
+        Some((
+            SVector::new([self.i, self.j, self.k]) * (sign / vector_norm),
+            self.angle(),
+        ))
+    }
+
+    /// ## Inverse rotation
+    pub fn conjugate(&self) -> UnitQuaternion {
+        UnitQuaternion {
+            w: self.w,
+            i: -self.i,
+            j: -self.j,
+            k: -self.k,
+        }
+    }
+
+    pub fn inverse(&self) -> UnitQuaternion {
+        self.conjugate()
+    }
+
+    /// ## Four dimensional dot product between the two quaternions
+    pub fn dot(&self, other: &UnitQuaternion) -> f64 {
+        self.w * other.w + self.i * other.i + self.j * other.j + self.k * other.k
+    }
+
+    /// ## Angle in radians of the rotation that takes `self` to `other`
+    pub fn angle_to(&self, other: &UnitQuaternion) -> f64 {
+        (self.inverse() * *other).angle()
+    }
+
+    /// ## Spherical linear interpolation
+    /// Returns `self` for `t = 0` and `other` for `t = 1`, following the shortest arc at constant angular velocity
+    pub fn slerp(&self, other: &UnitQuaternion, t: f64) -> UnitQuaternion {
+        let mut cos = self.dot(other);
+        // q and -q are the same rotation, go the short way round
+        let other = if cos < 0.0 {
+            cos = -cos;
+            UnitQuaternion {
+                w: -other.w,
+                i: -other.i,
+                j: -other.j,
+                k: -other.k,
+            }
+        } else {
+            *other
+        };
+
+        let (a, b) = if cos > 1.0 - 1e-9 {
+            // Nearly identical rotations: the interpolation is linear up to rounding
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+
+        UnitQuaternion::new(
+            a * self.w + b * other.w,
+            a * self.i + b * other.i,
+            a * self.j + b * other.j,
+            a * self.k + b * other.k,
+        )
+        .unwrap_or(*self)
+    }
+
+    pub fn rotate(&self, vector: SVector3<f64>) -> SVector3<f64> {
+        // v' = v + 2 q_v x (q_v x v + w v)
+        let q = SVector::new([self.i, self.j, self.k]);
+        let t = q.cross(&vector) * 2.0;
+        vector + t * self.w + q.cross(&t)
+    }
+
+    /// ## Rotates a vector with 3 elements
+    /// If the vector does not have 3 elements, returns an error
+    pub fn transform_vector(
+        &self,
+        vector: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(to_dynamic(self.rotate(to_static(vector)?)))
+    }
+
+    /// ## Rotates a point with 3 elements around the origin
+    /// If the point does not have 3 elements, returns an error
+    pub fn transform_point(
+        &self,
+        point: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.transform_vector(point)
+    }
+}
+
+/// ## Composition of two rotations (Hamilton product). `a * b` applies `b` first
+impl ops::Mul<UnitQuaternion> for UnitQuaternion {
+    type Output = UnitQuaternion;
+    fn mul(self, rhs: UnitQuaternion) -> Self::Output {
+        let (a, b) = (self, rhs);
+        let product = UnitQuaternion {
+            w: a.w * b.w - a.i * b.i - a.j * b.j - a.k * b.k,
+            i: a.w * b.i + a.i * b.w + a.j * b.k - a.k * b.j,
+            j: a.w * b.j - a.i * b.k + a.j * b.w + a.k * b.i,
+            k: a.w * b.k + a.i * b.j - a.j * b.i + a.k * b.w,
+        };
+        // Renormalise so rounding errors do not accumulate over long chains of products
+        UnitQuaternion::new(product.w, product.i, product.j, product.k).unwrap_or(product)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Translations and rigid motions
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Translation in 3 dimensional space
+/// Moves points, leaves vectors unchanged
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Translation {
+    pub vector: SVector3<f64>,
+}
+
+impl Translation {
+    pub fn new(x: f64, y: f64, z: f64) -> Translation {
+        Translation {
+            vector: SVector::new([x, y, z]),
+        }
+    }
+
+    pub fn identity() -> Translation {
+        Translation::new(0.0, 0.0, 0.0)
+    }
+
+    pub fn inverse(&self) -> Translation {
+        Translation {
+            vector: -self.vector,
+        }
+    }
+
+    /// ## Translates a point with 3 elements
+    /// If the point does not have 3 elements, returns an error
+    pub fn transform_point(
+        &self,
+        point: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(to_dynamic(to_static(point)? + self.vector))
+    }
+
+    /// ## Translations do not change vectors: returns a copy of `vector`
+    /// If the vector does not have 3 elements, returns an error
+    pub fn transform_vector(
+        &self,
+        vector: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(to_dynamic(to_static::<3>(vector)?))
+    }
+
+    pub fn to_homogeneous(&self) -> SMatrix4<f64> {
+        let mut matrix = SMatrix::identity();
+        for i in 0..3 {
+            matrix[(i, 3)] = self.vector[i];
+        }
+        matrix
+    }
+}
+
+/// ## Composition of two translations
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl ops::Mul<Translation> for Translation {
+    type Output = Translation;
+    fn mul(self, rhs: Translation) -> Self::Output {
+        Translation {
+            vector: self.vector + rhs.vector,
+        }
+    }
+}
+
+/// ## Rigid motion: a rotation followed by a translation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Isometry3 {
+    pub rotation: UnitQuaternion,
+    pub translation: Translation,
+}
+
+impl Isometry3 {
+    pub fn new(translation: Translation, rotation: UnitQuaternion) -> Isometry3 {
+        Isometry3 {
+            rotation,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Isometry3 {
+        Isometry3::new(Translation::identity(), UnitQuaternion::identity())
+    }
+
+    pub fn inverse(&self) -> Isometry3 {
+        let rotation = self.rotation.inverse();
+        Isometry3 {
+            rotation,
+            translation: Translation {
+                vector: -rotation.rotate(self.translation.vector),
+            },
+        }
+    }
+
+    /// ## Rotates then translates a point with 3 elements
+    /// If the point does not have 3 elements, returns an error
+    pub fn transform_point(
+        &self,
+        point: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(to_dynamic(
+            self.rotation.rotate(to_static(point)?) + self.translation.vector,
+        ))
+    }
+
+    /// ## Rotates a vector with 3 elements. The translation does not apply to vectors
+    /// If the vector does not have 3 elements, returns an error
+    pub fn transform_vector(
+        &self,
+        vector: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.rotation.transform_vector(vector)
+    }
+
+    pub fn to_homogeneous(&self) -> SMatrix4<f64> {
+        let rotation = self.rotation.to_rotation().matrix();
+        SMatrix::from_fn(|i, j| match (i, j) {
+            (3, 3) => 1.0,
+            (3, _) => 0.0,
+            (_, 3) => self.translation.vector[i],
+            _ => rotation[(i, j)],
+        })
+    }
+}
+
+/// ## Composition of two rigid motions. `a * b` applies `b` first
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl ops::Mul<Isometry3> for Isometry3 {
+    type Output = Isometry3;
+    fn mul(self, rhs: Isometry3) -> Self::Output {
+        Isometry3 {
+            rotation: self.rotation * rhs.rotation,
+            translation: Translation {
+                vector: self.rotation.rotate(rhs.translation.vector) + self.translation.vector,
+            },
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Homogeneous transforms
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## General projective transform of 3 dimensional space, as a 4x4 homogeneous matrix
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    matrix: SMatrix4<f64>,
+}
+
+impl Transform {
+    pub fn identity() -> Transform {
+        Transform {
+            matrix: SMatrix::identity(),
+        }
+    }
+
+    pub fn from_matrix(matrix: SMatrix4<f64>) -> Transform {
+        Transform { matrix }
+    }
+
+    pub fn matrix(&self) -> SMatrix4<f64> {
+        self.matrix
+    }
+
+    /// ## Right handed perspective projection, mapping the view frustum to the `[-1, 1]` cube
+    /// `fov_y` is the vertical field of view in radians and `aspect` the width to height ratio.
+    /// The camera looks down the negative z axis, as in OpenGL.
+    /// If the parameters do not describe a valid frustum, returns an error
+    pub fn perspective(
+        fov_y: f64,
+        aspect: f64,
+        near: f64,
+        far: f64,
+    ) -> Result<Transform, LalgrsError> {
+        if !(near > 0.0
+            && far > near
+            && aspect > 0.0
+            && fov_y > 0.0
+            && fov_y < std::f64::consts::PI)
+        {
+            return Err(LalgrsError::InvalidProjection { near, far });
+        }
+        let f = 1.0 / (fov_y / 2.0).tan();
+        let mut matrix = SMatrix::zeros();
+        matrix[(0, 0)] = f / aspect;
+        matrix[(1, 1)] = f;
+        matrix[(2, 2)] = (far + near) / (near - far);
+        matrix[(2, 3)] = 2.0 * far * near / (near - far);
+        matrix[(3, 2)] = -1.0;
+        Ok(Transform { matrix })
+    }
+
+    /// ## Right handed orthographic projection, mapping the given box to the `[-1, 1]` cube
+    /// If the box is empty along one of its axes, returns an error
+    pub fn orthographic(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Result<Transform, LalgrsError> {
+        if left == right || bottom == top || near == far {
+            return Err(LalgrsError::InvalidProjection { near, far });
+        }
+        let mut matrix = SMatrix::identity();
+        matrix[(0, 0)] = 2.0 / (right - left);
+        matrix[(1, 1)] = 2.0 / (top - bottom);
+        matrix[(2, 2)] = -2.0 / (far - near);
+        matrix[(0, 3)] = -(right + left) / (right - left);
+        matrix[(1, 3)] = -(top + bottom) / (top - bottom);
+        matrix[(2, 3)] = -(far + near) / (far - near);
+        Ok(Transform { matrix })
+    }
+
+    /// ## Transforms a point with 3 elements, dividing by the homogeneous coordinate
+    /// If the point does not have 3 elements or is mapped to infinity, returns an error
+    pub fn transform_point(
+        &self,
+        point: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        let p = to_static::<3>(point)?;
+        let h = self.matrix * SVector::new([p[0], p[1], p[2], 1.0]);
+        if h[3] == 0.0 {
+            return Err(LalgrsError::PointAtInfinity);
+        }
+        Ok(LalgrsVector::new(vec![
+            h[0] / h[3],
+            h[1] / h[3],
+            h[2] / h[3],
+        ]))
+    }
+
+    /// ## Transforms a vector with 3 elements, ignoring the translation and projective parts
+    /// If the vector does not have 3 elements, returns an error
+    pub fn transform_vector(
+        &self,
+        vector: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        let v = to_static::<3>(vector)?;
+        let h = self.matrix * SVector::new([v[0], v[1], v[2], 0.0]);
+        Ok(LalgrsVector::new(vec![h[0], h[1], h[2]]))
+    }
+}
+
+/// ## Composition of two transforms. `a * b` applies `b` first
+impl ops::Mul<Transform> for Transform {
+    type Output = Transform;
+    fn mul(self, rhs: Transform) -> Self::Output {
+        Transform {
+            matrix: self.matrix * rhs.matrix,
+        }
+    }
+}
+
+impl From<Isometry3> for Transform {
+    fn from(value: Isometry3) -> Self {
+        Transform {
+            matrix: value.to_homogeneous(),
+        }
+    }
+}
+
+impl From<Translation> for Transform {
+    fn from(value: Translation) -> Self {
+        Transform {
+            matrix: value.to_homogeneous(),
+        }
+    }
+}
+
+impl From<Rotation3> for Transform {
+    fn from(value: Rotation3) -> Self {
+        Transform {
+            matrix: SMatrix::from_fn(|i, j| match (i, j) {
+                (3, 3) => 1.0,
+                (3, _) | (_, 3) => 0.0,
+                _ => value.matrix[(i, j)],
+            }),
+        }
+    }
+}
+
+impl From<Transform> for LalgrsMatrix<f64> {
+    fn from(value: Transform) -> Self {
+        value.matrix.into()
+    }
+}
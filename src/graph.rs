@@ -0,0 +1,356 @@
+use std::cmp::Reverse;
+
+use crate::{LalgrsError, LalgrsMatrix, LalgrsVector};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Graphs
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maximum number of power iterations before PageRank gives up
+const MAX_PAGERANK_ITERATIONS: usize = 1000;
+
+/// ## Row sums of a square matrix, the (out) degrees of the vertices of an adjacency matrix
+fn row_sums(adjacency: &LalgrsMatrix<f64>) -> Vec<f64> {
+    let n = adjacency.rows();
+    (0..n)
+        .map(|i| (0..n).map(|j| adjacency[(i, j)]).sum())
+        .collect()
+}
+
+/// ## Graph on the vertices `0..vertices`, with weighted edges
+/// Parallel edges add their weights. The matrices are dense, with one row and one column per vertex.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph {
+    vertices: usize,
+    edges: Vec<(usize, usize, f64)>,
+    directed: bool,
+}
+
+impl Graph {
+    /// ## Undirected graph with unit weight edges
+    /// If an edge has a vertex outside of `0..vertices`, returns an error
+    pub fn undirected(vertices: usize, edges: &[(usize, usize)]) -> Result<Graph, LalgrsError> {
+        let edges: Vec<(usize, usize, f64)> = edges.iter().map(|(u, v)| (*u, *v, 1.0)).collect();
+        Graph::weighted(vertices, &edges, false)
+    }
+
+    /// ## Directed graph with unit weight edges from the first vertex of each pair to the second
+    /// If an edge has a vertex outside of `0..vertices`, returns an error
+    pub fn directed(vertices: usize, edges: &[(usize, usize)]) -> Result<Graph, LalgrsError> {
+        let edges: Vec<(usize, usize, f64)> = edges.iter().map(|(u, v)| (*u, *v, 1.0)).collect();
+        Graph::weighted(vertices, &edges, true)
+    }
+
+    /// ## Graph with edges `(from, to, weight)`
+    /// If an edge has a vertex outside of `0..vertices`, or a negative or non finite weight, returns an error
+    pub fn weighted(
+        vertices: usize,
+        edges: &[(usize, usize, f64)],
+        directed: bool,
+    ) -> Result<Graph, LalgrsError> {
+        for (u, v, weight) in edges {
+            if let Some(vertex) = [*u, *v].into_iter().find(|x| *x >= vertices) {
+                return Err(LalgrsError::InvalidParameter {
+                    name: "vertex",
+                    value: vertex as f64,
+                });
+            }
+            if *weight < 0.0 || !weight.is_finite() {
+                return Err(LalgrsError::InvalidParameter {
+                    name: "weight",
+                    value: *weight,
+                });
+            }
+        }
+        Ok(Graph {
+            vertices,
+            edges: edges.to_vec(),
+            directed,
+        })
+    }
+
+    pub fn vertices(&self) -> usize {
+        self.vertices
+    }
+
+    pub fn edges(&self) -> &[(usize, usize, f64)] {
+        &self.edges
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// ## Same graph with every edge made undirected
+    fn undirected_view(&self) -> Graph {
+        Graph {
+            directed: false,
+            ..self.clone()
+        }
+    }
+
+    /// ## Adjacency matrix, with the total weight of the edges from `i` to `j` at `(i, j)`
+    /// Undirected graphs have a symmetric adjacency matrix; a self loop adds its weight to the diagonal once
+    pub fn adjacency_matrix(&self) -> LalgrsMatrix<f64> {
+        let mut adjacency = LalgrsMatrix::zeros(self.vertices, self.vertices);
+        for (u, v, weight) in &self.edges {
+            adjacency[(*u, *v)] += weight;
+            if !self.directed && u != v {
+                adjacency[(*v, *u)] += weight;
+            }
+        }
+        adjacency
+    }
+
+    /// ## Diagonal matrix of the (out) degrees, the row sums of the adjacency matrix
+    pub fn degree_matrix(&self) -> LalgrsMatrix<f64> {
+        let degrees = row_sums(&self.adjacency_matrix());
+        let mut degree = LalgrsMatrix::zeros(self.vertices, self.vertices);
+        for (i, d) in degrees.into_iter().enumerate() {
+            degree[(i, i)] = d;
+        }
+        degree
+    }
+
+    /// ## Laplacian matrix `L = D - A`
+    /// For undirected graphs it is symmetric positive semidefinite, and its null space is spanned by the
+    /// indicator vectors of the connected components
+    pub fn laplacian_matrix(&self) -> LalgrsMatrix<f64> {
+        let adjacency = self.adjacency_matrix();
+        let mut laplacian = -adjacency.clone();
+        for (i, d) in row_sums(&adjacency).into_iter().enumerate() {
+            laplacian[(i, i)] += d;
+        }
+        laplacian
+    }
+
+    /// ## Normalised Laplacian `I - D^(-1/2) * A * D^(-1/2)`
+    /// Rows and columns of isolated vertices are zero
+    pub fn normalized_laplacian(&self) -> LalgrsMatrix<f64> {
+        let adjacency = self.adjacency_matrix();
+        let scale: Vec<f64> = row_sums(&adjacency)
+            .into_iter()
+            .map(|degree| {
+                if degree > 0.0 {
+                    1.0 / degree.sqrt()
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        LalgrsMatrix::from_fn(self.vertices, self.vertices, |i, j| {
+            let identity = if i == j && scale[i] > 0.0 { 1.0 } else { 0.0 };
+            identity - scale[i] * adjacency[(i, j)] * scale[j]
+        })
+    }
+
+    /// ## PageRank of every vertex, computed with the power iteration
+    /// A random surfer follows an edge with probability `damping`, chosen in proportion to the edge weights,
+    /// and jumps to a uniformly random vertex otherwise, or when it reaches a vertex without outgoing edges.
+    /// Iterates until the 1-norm of the change is at most `tolerance`. The ranks sum to one.
+    /// If `damping` is not in `[0, 1)`, `tolerance` is not positive, or the iteration does not converge, returns an error
+    pub fn pagerank(&self, damping: f64, tolerance: f64) -> Result<LalgrsVector<f64>, LalgrsError> {
+        if !(0.0..1.0).contains(&damping) {
+            return Err(LalgrsError::InvalidParameter {
+                name: "damping",
+                value: damping,
+            });
+        }
+        if tolerance <= 0.0 || tolerance.is_nan() {
+            return Err(LalgrsError::InvalidParameter {
+                name: "tolerance",
+                value: tolerance,
+            });
+        }
+        let n = self.vertices;
+        if n == 0 {
+            return Ok(LalgrsVector::new(Vec::new()));
+        }
+        let adjacency = self.adjacency_matrix();
+        let out_weights = row_sums(&adjacency);
+
+        let mut rank = vec![1.0 / n as f64; n];
+        for _ in 0..MAX_PAGERANK_ITERATIONS {
+            let dangling: f64 = (0..n)
+                .filter(|i| out_weights[*i] == 0.0)
+                .map(|i| rank[i])
+                .sum();
+            let jump = (1.0 - damping + damping * dangling) / n as f64;
+            let next: Vec<f64> = (0..n)
+                .map(|j| {
+                    jump + damping
+                        * (0..n)
+                            .filter(|i| out_weights[*i] > 0.0)
+                            .map(|i| rank[i] * adjacency[(i, j)] / out_weights[i])
+                            .sum::<f64>()
+                })
+                .collect();
+            let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+            rank = next;
+            if change <= tolerance {
+                return Ok(LalgrsVector::new(rank));
+            }
+        }
+        Err(LalgrsError::NoConvergence {
+            iterations: MAX_PAGERANK_ITERATIONS,
+        })
+    }
+
+    /// ## Connected component of every vertex, numbered from zero in order of their first vertex
+    /// The components are read from the null space of the Laplacian: the projector onto it is
+    /// `1 / |C|` between two vertices of the same component `C`, and zero otherwise.
+    /// Directed graphs give their weakly connected components. The number of components is the largest label plus one.
+    /// If the singular value decomposition of the Laplacian does not converge, returns an error
+    pub fn connected_components(&self) -> Result<Vec<usize>, LalgrsError> {
+        let n = self.vertices;
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        let null_space = self.undirected_view().laplacian_matrix().null_space(None)?;
+        let projector = (null_space.clone() * null_space.transpose())?;
+
+        let mut labels: Vec<Option<usize>> = vec![None; n];
+        let mut count = 0;
+        for i in 0..n {
+            if labels[i].is_some() {
+                continue;
+            }
+            for j in i..n {
+                if labels[j].is_none() && projector[(i, j)] > projector[(i, i)] / 2.0 {
+                    labels[j] = Some(count);
+                }
+            }
+            count += 1;
+        }
+        Ok(labels.into_iter().map(|l| l.unwrap_or(0)).collect())
+    }
+
+    /// ## Fiedler vector, the unit eigenvector of the second smallest eigenvalue of the Laplacian
+    /// It is orthogonal to the constant vector, also for disconnected graphs, where the eigenvalue is zero.
+    /// Directed graphs use their undirected Laplacian. The sign is chosen so that the largest entry in magnitude is positive.
+    /// If the graph has fewer than two vertices, or the singular value decomposition does not converge, returns an error
+    pub fn fiedler_vector(&self) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(self.fiedler()?.1)
+    }
+
+    /// ## Algebraic connectivity, the second smallest eigenvalue of the Laplacian
+    /// It is zero if and only if the graph is disconnected.
+    /// If the graph has fewer than two vertices, or the singular value decomposition does not converge, returns an error
+    pub fn algebraic_connectivity(&self) -> Result<f64, LalgrsError> {
+        Ok(self.fiedler()?.0)
+    }
+
+    /// ## Second smallest eigenvalue of the undirected Laplacian and its eigenvector
+    fn fiedler(&self) -> Result<(f64, LalgrsVector<f64>), LalgrsError> {
+        let n = self.vertices;
+        if n < 2 {
+            return Err(LalgrsError::InvalidParameter {
+                name: "vertices",
+                value: n as f64,
+            });
+        }
+        // The constant vector is an eigenvector of L with eigenvalue zero. Adding (s / n) * J, where J is the
+        // matrix of ones, moves it to the eigenvalue s > trace(L) ≥ λ_max and leaves the other eigenpairs alone,
+        // so the smallest eigenpair of the sum is the Fiedler pair. The sum is symmetric positive semidefinite,
+        // so its eigenvectors are its singular vectors
+        let laplacian = self.undirected_view().laplacian_matrix();
+        let shift = ((0..n).map(|i| laplacian[(i, i)]).sum::<f64>() + 1.0) / n as f64;
+        let svd = laplacian.map(|x| x + shift).svd()?;
+        // Singular values are sorted from the largest to the smallest
+        let value = svd.singular_values.values[n - 1];
+        let mut vector: Vec<f64> = (0..n).map(|i| svd.v_t[(n - 1, i)]).collect();
+        let largest = vector
+            .iter()
+            .copied()
+            .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+            .unwrap_or(0.0);
+        if largest < 0.0 {
+            vector.iter_mut().for_each(|x| *x = -*x);
+        }
+        Ok((value, LalgrsVector::new(vector)))
+    }
+
+    /// ## Subgraph induced by the given vertices, renumbered in the given order
+    fn induced_subgraph(&self, vertices: &[usize]) -> Graph {
+        let mut index = vec![None; self.vertices];
+        for (k, v) in vertices.iter().enumerate() {
+            index[*v] = Some(k);
+        }
+        Graph {
+            vertices: vertices.len(),
+            edges: self
+                .edges
+                .iter()
+                .filter_map(|(u, v, weight)| Some((index[*u]?, index[*v]?, *weight)))
+                .collect(),
+            directed: self.directed,
+        }
+    }
+
+    /// ## Splits the vertices of a graph in two at the best sweep cut of its Fiedler vector
+    /// The vertices are sorted by their Fiedler vector entry, and the sorted order is cut where the
+    /// ratio cut `cut / |left| + cut / |right|` is the smallest. Returns the positions of the vertices on each side
+    fn bisect(&self) -> Result<(Vec<usize>, Vec<usize>), LalgrsError> {
+        let n = self.vertices;
+        let fiedler = self.fiedler_vector()?;
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|a, b| fiedler.values[*a].total_cmp(&fiedler.values[*b]));
+
+        let adjacency = self.undirected_view().adjacency_matrix();
+        let mut left = vec![false; n];
+        let (mut cut, mut best, mut best_ratio) = (0.0, 1, f64::INFINITY);
+        for (k, v) in order.iter().take(n - 1).enumerate() {
+            // Moving v to the left cuts its edges to the right and restores its edges to the left
+            left[*v] = true;
+            for u in (0..n).filter(|u| u != v) {
+                if left[u] {
+                    cut -= adjacency[(*v, u)];
+                } else {
+                    cut += adjacency[(*v, u)];
+                }
+            }
+            let ratio = cut / (k + 1) as f64 + cut / (n - k - 1) as f64;
+            if ratio < best_ratio {
+                (best, best_ratio) = (k + 1, ratio);
+            }
+        }
+        let right = order.split_off(best);
+        Ok((order, right))
+    }
+
+    /// ## Partitions the vertices into `clusters` clusters by recursive spectral bisection
+    /// The largest cluster is split in two at the best sweep cut of the Fiedler vector of its induced subgraph,
+    /// the cut along the ordering of the vertices by their Fiedler vector entry with the smallest ratio cut,
+    /// until there are `clusters` clusters. Clusters are numbered from zero in order of their first vertex.
+    /// If `clusters` is zero or larger than the number of vertices,
+    /// or the singular value decomposition does not converge, returns an error
+    pub fn spectral_clustering(&self, clusters: usize) -> Result<Vec<usize>, LalgrsError> {
+        if clusters == 0 || clusters > self.vertices {
+            return Err(LalgrsError::InvalidParameter {
+                name: "clusters",
+                value: clusters as f64,
+            });
+        }
+        let mut parts: Vec<Vec<usize>> = vec![(0..self.vertices).collect()];
+        while parts.len() < clusters {
+            let largest = parts
+                .iter()
+                .enumerate()
+                .max_by_key(|(k, part)| (part.len(), Reverse(*k)))
+                .map_or(0, |(k, _)| k);
+            let part = parts.swap_remove(largest);
+            let (left, right) = self.induced_subgraph(&part).bisect()?;
+            parts.push(left.into_iter().map(|k| part[k]).collect());
+            parts.push(right.into_iter().map(|k| part[k]).collect());
+        }
+
+        parts.sort_by_key(|part| part.iter().min().copied());
+        let mut labels = vec![0; self.vertices];
+        for (label, part) in parts.iter().enumerate() {
+            for v in part {
+                labels[*v] = label;
+            }
+        }
+        Ok(labels)
+    }
+}
@@ -0,0 +1,503 @@
+use std::{fs, path::Path};
+
+use crate::{
+    convolution::Border, geometry::Rotation2, LalgrsError, LalgrsMatrix, LalgrsVector, SMatrix2,
+    SVector,
+};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Image struct definitions
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Grayscale or color image
+/// Each channel is a matrix with one row per pixel row and one column per pixel column.
+/// Intensities are normalised to `[0, 1]`; `max_value` is the Netpbm maximum value used when the image is saved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    channels: Vec<LalgrsMatrix<f64>>,
+    pub max_value: u16,
+}
+
+/// ## Netpbm variants supported when saving an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetpbmFormat {
+    /// P2, grayscale stored as text
+    PlainGray,
+    /// P3, RGB stored as text
+    PlainColor,
+    /// P5, grayscale stored as bytes
+    BinaryGray,
+    /// P6, RGB stored as bytes
+    BinaryColor,
+}
+
+impl NetpbmFormat {
+    fn magic_number(&self) -> &'static str {
+        match self {
+            NetpbmFormat::PlainGray => "P2",
+            NetpbmFormat::PlainColor => "P3",
+            NetpbmFormat::BinaryGray => "P5",
+            NetpbmFormat::BinaryColor => "P6",
+        }
+    }
+
+    fn channels(&self) -> usize {
+        match self {
+            NetpbmFormat::PlainGray | NetpbmFormat::BinaryGray => 1,
+            NetpbmFormat::PlainColor | NetpbmFormat::BinaryColor => 3,
+        }
+    }
+
+    fn is_binary(&self) -> bool {
+        matches!(self, NetpbmFormat::BinaryGray | NetpbmFormat::BinaryColor)
+    }
+}
+
+/// ## Gradient operators used for edge detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeOperator {
+    Sobel,
+    Prewitt,
+}
+
+/// ## Weights of the red, green and blue channels in the ITU-R BT.601 luma
+pub fn luma_weights() -> LalgrsVector<f64> {
+    LalgrsVector::new(vec![0.299, 0.587, 0.114])
+}
+
+impl Image {
+    /// ## Creates a grayscale image from a matrix of intensities in `[0, 1]`
+    pub fn grayscale(channel: LalgrsMatrix<f64>) -> Image {
+        Image {
+            channels: vec![channel],
+            max_value: 255,
+        }
+    }
+
+    /// ## Creates a color image from red, green and blue intensities in `[0, 1]`
+    /// If the three channels do not have the same shape, returns an error
+    pub fn rgb(
+        red: LalgrsMatrix<f64>,
+        green: LalgrsMatrix<f64>,
+        blue: LalgrsMatrix<f64>,
+    ) -> Result<Image, LalgrsError> {
+        for other in [&green, &blue] {
+            if other.shape() != red.shape() {
+                return Err(LalgrsError::MismatchedMatrixDimensions {
+                    lhs: red.shape(),
+                    rhs: other.shape(),
+                });
+            }
+        }
+        Ok(Image {
+            channels: vec![red, green, blue],
+            max_value: 255,
+        })
+    }
+
+    /// ## Image with the given channels, which must all have the same shape
+    pub(crate) fn from_channels(channels: Vec<LalgrsMatrix<f64>>, max_value: u16) -> Image {
+        Image {
+            channels,
+            max_value,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.channels.first().map_or(0, |c| c.columns())
+    }
+
+    pub fn height(&self) -> usize {
+        self.channels.first().map_or(0, |c| c.rows())
+    }
+
+    pub fn is_color(&self) -> bool {
+        self.channels.len() == 3
+    }
+
+    /// ## Channels of the image: one for grayscale images, red, green and blue for color images
+    pub fn channels(&self) -> &[LalgrsMatrix<f64>] {
+        &self.channels
+    }
+
+    /// ## Applies `f` to every channel, keeping the maximum value
+    pub fn map_channels<F: FnMut(&LalgrsMatrix<f64>) -> Result<LalgrsMatrix<f64>, LalgrsError>>(
+        &self,
+        f: F,
+    ) -> Result<Image, LalgrsError> {
+        Ok(Image {
+            channels: self.channels.iter().map(f).collect::<Result<_, _>>()?,
+            max_value: self.max_value,
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Netpbm input and output
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Reads whitespace separated tokens, skipping `#` comments
+struct NetpbmReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> NetpbmReader<'a> {
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(&byte) = self.bytes.get(self.position) {
+            if byte == b'#' {
+                while self.bytes.get(self.position).is_some_and(|&b| b != b'\n') {
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn token(&mut self) -> Result<&'a str, LalgrsError> {
+        self.skip_whitespace_and_comments();
+        let start = self.position;
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(|b| !b.is_ascii_whitespace() && *b != b'#')
+        {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(LalgrsError::InvalidImage(
+                "unexpected end of file".to_string(),
+            ));
+        }
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .map_err(|_| LalgrsError::InvalidImage("header is not valid text".to_string()))
+    }
+
+    fn number(&mut self) -> Result<usize, LalgrsError> {
+        let token = self.token()?;
+        token
+            .parse()
+            .map_err(|_| LalgrsError::InvalidImage(format!("expected a number, found {token:?}")))
+    }
+}
+
+impl Image {
+    /// ## Parses a P2, P3, P5 or P6 Netpbm image
+    /// If the data is not a valid Netpbm image, returns an error
+    pub fn from_netpbm_bytes(bytes: &[u8]) -> Result<Image, LalgrsError> {
+        let mut reader = NetpbmReader { bytes, position: 0 };
+        let format = match reader.token()? {
+            "P2" => NetpbmFormat::PlainGray,
+            "P3" => NetpbmFormat::PlainColor,
+            "P5" => NetpbmFormat::BinaryGray,
+            "P6" => NetpbmFormat::BinaryColor,
+            other => {
+                return Err(LalgrsError::InvalidImage(format!(
+                    "unsupported format {other:?}"
+                )))
+            }
+        };
+        let width = reader.number()?;
+        let height = reader.number()?;
+        let max_value = reader.number()?;
+        if max_value == 0 || max_value > u16::MAX as usize {
+            return Err(LalgrsError::InvalidImage(format!(
+                "maximum value {max_value} is not in 1..=65535"
+            )));
+        }
+
+        let channel_count = format.channels();
+        let sample_count = width
+            .checked_mul(height)
+            .and_then(|n| n.checked_mul(channel_count))
+            .ok_or_else(|| {
+                LalgrsError::InvalidImage(format!("size {width}x{height} is too large"))
+            })?;
+        let samples: Vec<usize> = if format.is_binary() {
+            // A single whitespace character separates the header from the raster
+            let start = reader.position + 1;
+            let bytes_per_sample = if max_value < 256 { 1 } else { 2 };
+            let end = sample_count
+                .checked_mul(bytes_per_sample)
+                .and_then(|n| n.checked_add(start));
+            let raster = end
+                .and_then(|end| bytes.get(start..end))
+                .ok_or_else(|| LalgrsError::InvalidImage("raster is too short".to_string()))?;
+            raster
+                .chunks(bytes_per_sample)
+                .map(|chunk| chunk.iter().fold(0, |acc, &b| acc * 256 + b as usize))
+                .collect()
+        } else {
+            (0..sample_count)
+                .map(|_| reader.number())
+                .collect::<Result<_, _>>()?
+        };
+        if let Some(sample) = samples.iter().find(|&&s| s > max_value) {
+            return Err(LalgrsError::InvalidImage(format!(
+                "sample {sample} is greater than the maximum value {max_value}"
+            )));
+        }
+
+        // Samples are stored row by row, with the channels of each pixel next to each other
+        let scale = max_value as f64;
+        let channels = (0..channel_count)
+            .map(|c| {
+                LalgrsMatrix::from_fn(height, width, |i, j| {
+                    samples[(i * width + j) * channel_count + c] as f64 / scale
+                })
+            })
+            .collect();
+        Ok(Image {
+            channels,
+            max_value: max_value as u16,
+        })
+    }
+
+    /// ## Loads a P2, P3, P5 or P6 Netpbm image from a file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Image, LalgrsError> {
+        Image::from_netpbm_bytes(&fs::read(path)?)
+    }
+
+    /// ## Encodes the image in the given Netpbm format
+    /// Intensities are clamped to `[0, 1]` and rounded to the nearest integer sample.
+    /// If the format does not match the number of channels of the image, returns an error
+    pub fn to_netpbm_bytes(&self, format: NetpbmFormat) -> Result<Vec<u8>, LalgrsError> {
+        if format.channels() != self.channels.len() {
+            return Err(LalgrsError::InvalidImage(format!(
+                "{} needs {} channels, the image has {}",
+                format.magic_number(),
+                format.channels(),
+                self.channels.len()
+            )));
+        }
+
+        let (width, height) = (self.width(), self.height());
+        let max_value = self.max_value.max(1);
+        let mut bytes = format!(
+            "{}\n{} {}\n{}\n",
+            format.magic_number(),
+            width,
+            height,
+            max_value
+        )
+        .into_bytes();
+
+        let samples = (0..height).flat_map(|i| {
+            (0..width).flat_map(move |j| {
+                self.channels.iter().map(move |channel| {
+                    (channel[(i, j)].clamp(0.0, 1.0) * max_value as f64).round() as u16
+                })
+            })
+        });
+        if format.is_binary() {
+            for sample in samples {
+                if max_value < 256 {
+                    bytes.push(sample as u8);
+                } else {
+                    bytes.extend_from_slice(&sample.to_be_bytes());
+                }
+            }
+        } else {
+            // Plain formats should not have lines longer than 70 characters: write one pixel row per line,
+            // wrapping long rows
+            let per_line = (70 / (max_value.to_string().len() + 1)).max(1);
+            let row_length = width * self.channels.len();
+            let samples: Vec<u16> = samples.collect();
+            for row in samples.chunks(row_length.max(1)) {
+                for line in row.chunks(per_line) {
+                    let text: Vec<String> = line.iter().map(|s| s.to_string()).collect();
+                    bytes.extend_from_slice(text.join(" ").as_bytes());
+                    bytes.push(b'\n');
+                }
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// ## Saves the image to a file in the given Netpbm format
+    pub fn save<P: AsRef<Path>>(&self, path: P, format: NetpbmFormat) -> Result<(), LalgrsError> {
+        fs::write(path, self.to_netpbm_bytes(format)?)?;
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Image Operations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Gaussian kernel with standard deviation `sigma`, normalised to sum to one
+/// The kernel is a single row with `2 * ceil(3 * sigma) + 1` elements; transpose it for a column kernel.
+/// If `sigma` is not positive, returns an error
+pub fn gaussian_kernel(sigma: f64) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    if !(sigma > 0.0 && sigma.is_finite()) {
+        return Err(LalgrsError::InvalidParameter {
+            name: "sigma",
+            value: sigma,
+        });
+    }
+    let radius = (3.0 * sigma).ceil() as usize;
+    let weights: Vec<f64> = (0..2 * radius + 1)
+        .map(|k| {
+            let x = k as f64 - radius as f64;
+            (-x * x / (2.0 * sigma * sigma)).exp()
+        })
+        .collect();
+    let total: f64 = weights.iter().sum();
+    LalgrsMatrix::from_row_slice(1, weights.len(), &weights).map(|kernel| kernel * (1.0 / total))
+}
+
+/// ## Horizontal and vertical gradient kernels of an edge operator
+fn gradient_kernels(operator: EdgeOperator) -> (LalgrsMatrix<f64>, LalgrsMatrix<f64>) {
+    let side = match operator {
+        EdgeOperator::Sobel => 2.0,
+        EdgeOperator::Prewitt => 1.0,
+    };
+    let horizontal = LalgrsMatrix::from_fn(3, 3, |i, j| {
+        (j as f64 - 1.0) * if i == 1 { side } else { 1.0 }
+    });
+    let vertical = horizontal.transpose();
+    (horizontal, vertical)
+}
+
+/// ## Value of a channel at a fractional position, interpolated between the four closest pixels
+/// Positions outside of the channel read as zero
+fn bilinear_sample(channel: &LalgrsMatrix<f64>, row: f64, column: f64) -> f64 {
+    let (row0, column0) = (row.floor(), column.floor());
+    let (dr, dc) = (row - row0, column - column0);
+    let pixel = |i: f64, j: f64| -> f64 {
+        if i < 0.0 || j < 0.0 {
+            return 0.0;
+        }
+        channel.get(i as usize, j as usize).copied().unwrap_or(0.0)
+    };
+    pixel(row0, column0) * (1.0 - dr) * (1.0 - dc)
+        + pixel(row0, column0 + 1.0) * (1.0 - dr) * dc
+        + pixel(row0 + 1.0, column0) * dr * (1.0 - dc)
+        + pixel(row0 + 1.0, column0 + 1.0) * dr * dc
+}
+
+impl Image {
+    /// ## Converts a color image to grayscale
+    /// Each pixel becomes the weighted sum of its channels, e.g. with `luma_weights()`.
+    /// If the number of weights does not match the number of channels, returns an error
+    pub fn to_grayscale(&self, weights: &LalgrsVector<f64>) -> Result<Image, LalgrsError> {
+        if weights.size() != self.channels.len() {
+            return Err(LalgrsError::MismatchedVectorDimensions {
+                vector1: weights.size(),
+                vector2: self.channels.len(),
+            });
+        }
+        let gray = self.channels.iter().zip(weights.values.iter()).try_fold(
+            LalgrsMatrix::zeros(self.height(), self.width()),
+            |acc, (c, w)| acc + c.clone() * *w,
+        )?;
+        Ok(Image {
+            channels: vec![gray],
+            max_value: self.max_value,
+        })
+    }
+
+    /// ## Convolves every channel with `kernel`, clamping at the borders
+    pub fn convolve(&self, kernel: &LalgrsMatrix<f64>) -> Result<Image, LalgrsError> {
+        self.map_channels(|c| c.convolve(kernel, Border::Clamp))
+    }
+
+    /// ## Gaussian blur with standard deviation `sigma`, in pixels
+    /// The blur is applied as two one dimensional convolutions.
+    /// If `sigma` is not positive, returns an error
+    pub fn gaussian_blur(&self, sigma: f64) -> Result<Image, LalgrsError> {
+        let row_kernel = gaussian_kernel(sigma)?;
+        let column_kernel = row_kernel.transpose();
+        self.map_channels(|c| {
+            c.convolve(&row_kernel, Border::Clamp)?
+                .convolve(&column_kernel, Border::Clamp)
+        })
+    }
+
+    /// ## Magnitude of the intensity gradient of every channel
+    /// Strong responses mark edges. The result is not clamped to `[0, 1]`
+    pub fn detect_edges(&self, operator: EdgeOperator) -> Result<Image, LalgrsError> {
+        let (horizontal, vertical) = gradient_kernels(operator);
+        self.map_channels(|c| {
+            let gx = c.convolve(&horizontal, Border::Clamp)?;
+            let gy = c.convolve(&vertical, Border::Clamp)?;
+            Ok(LalgrsMatrix::from_fn(c.rows(), c.columns(), |i, j| {
+                gx[(i, j)].hypot(gy[(i, j)])
+            }))
+        })
+    }
+
+    /// ## Applies the linear map `transform` around the centre of the image, with bilinear resampling
+    /// `transform` acts on `(x, y)` pixel coordinates, with x growing to the right and y growing down.
+    /// The result has the given size; pixels that come from outside of the image are black.
+    /// If `transform` is not invertible, returns an error
+    pub fn affine(
+        &self,
+        transform: &SMatrix2<f64>,
+        width: usize,
+        height: usize,
+    ) -> Result<Image, LalgrsError> {
+        let [[a, b], [c, d]] = [
+            [transform[(0, 0)], transform[(0, 1)]],
+            [transform[(1, 0)], transform[(1, 1)]],
+        ];
+        let determinant = a * d - b * c;
+        // Relative to the size of the entries, so that strong uniform scales stay invertible
+        let scale = a.abs().max(b.abs()).max(c.abs()).max(d.abs());
+        if determinant.abs() <= f64::EPSILON * scale * scale {
+            return Err(LalgrsError::SingularMatrix);
+        }
+        let inverse = SMatrix2::from_rows([[d, -b], [-c, a]]) * (1.0 / determinant);
+        if !(0..2).all(|i| (0..2).all(|j| inverse[(i, j)].is_finite())) {
+            return Err(LalgrsError::SingularMatrix);
+        }
+
+        // Map the centre of the output onto the centre of the input
+        let source_center = SVector::new([
+            (self.width() as f64 - 1.0) / 2.0,
+            (self.height() as f64 - 1.0) / 2.0,
+        ]);
+        let target_center = SVector::new([(width as f64 - 1.0) / 2.0, (height as f64 - 1.0) / 2.0]);
+        self.map_channels(|channel| {
+            Ok(LalgrsMatrix::from_fn(height, width, |i, j| {
+                let target = SVector::new([j as f64, i as f64]) - target_center;
+                let source = inverse * target + source_center;
+                bilinear_sample(channel, source[1], source[0])
+            }))
+        })
+    }
+
+    /// ## Rotates the image by `angle` radians, clockwise on screen, keeping its size
+    pub fn rotate(&self, angle: f64) -> Result<Image, LalgrsError> {
+        // With y growing down, a counterclockwise rotation of the coordinates looks clockwise
+        self.affine(&Rotation2::new(angle).matrix(), self.width(), self.height())
+    }
+
+    /// ## Scales the image by the given horizontal and vertical factors
+    /// The size of the result is the size of the image times the factors, rounded.
+    /// If one of the factors is not positive, returns an error
+    pub fn scale(&self, factor_x: f64, factor_y: f64) -> Result<Image, LalgrsError> {
+        for (name, value) in [("factor_x", factor_x), ("factor_y", factor_y)] {
+            if !(value > 0.0 && value.is_finite()) {
+                return Err(LalgrsError::InvalidParameter { name, value });
+            }
+        }
+        let width = (self.width() as f64 * factor_x).round() as usize;
+        let height = (self.height() as f64 * factor_y).round() as usize;
+        // Scale the pixel grids rather than the coordinates, so corner pixels map onto corner pixels
+        let sx = if self.width() > 1 && width > 1 {
+            (width as f64 - 1.0) / (self.width() as f64 - 1.0)
+        } else {
+            factor_x
+        };
+        let sy = if self.height() > 1 && height > 1 {
+            (height as f64 - 1.0) / (self.height() as f64 - 1.0)
+        } else {
+            factor_y
+        };
+        self.affine(&SMatrix2::from_rows([[sx, 0.0], [0.0, sy]]), width, height)
+    }
+}
@@ -1,13 +1,65 @@
+// Fallible operations report failures as `LalgrsError`, so that a bad input can not take down a long running caller.
+// The only intentional panics are out of bounds indexing, and overflow in arithmetic operators: the `Rational`
+// operators, and the matrix and vector operators, which combine elements with the operators of their type,
+// behave like the primitive integers. Algorithms that may run on `Rational` use the `checked_` methods of `Field`
+// instead and report overflow as an error, as do `LalgrsMatrix::checked_add`, `checked_sub`, `checked_mul`
+// and `checked_mul_vector`
+#![cfg_attr(
+    not(test),
+    deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)
+)]
+
 use std::{
-    collections::VecDeque,
-    fmt::Debug,
-    ops::{self, Add, Mul, Neg},
+    collections::{vec_deque, VecDeque},
+    fmt::{self, Display},
+    ops::{self, Add, Index, IndexMut, Mul, Neg},
 };
 
 use thiserror::Error;
 
+pub mod approx;
+pub mod blocks;
+pub mod complex;
+pub mod compression;
+pub mod convolution;
+pub mod decomposition;
+pub mod eigensolvers;
+pub mod elementwise;
+pub mod elimination;
+pub mod functions;
+pub mod geometry;
+pub mod graph;
+pub mod image;
+pub mod life;
+pub mod markov;
+pub mod matrix_equations;
+pub mod norms;
+pub mod polynomial;
+pub mod random;
+pub mod rational;
+pub mod scalar;
+pub mod schur;
+pub mod static_matrix;
+pub mod statistics;
+pub mod view;
+
+pub use approx::Ulps;
+pub use complex::Complex;
+pub use decomposition::{Cholesky, Ldlt, Lu, Svd};
+pub use eigensolvers::{EigenPair, LinearOperator};
+pub use markov::MarkovChain;
+pub use polynomial::Polynomial;
+pub use random::Rng;
+pub use rational::Rational;
+pub use scalar::{Field, One, Zero};
+pub use schur::{ComplexSchur, Hessenberg, Schur};
+pub use static_matrix::{
+    SMatrix, SMatrix2, SMatrix3, SMatrix4, SVector, SVector2, SVector3, SVector4,
+};
+pub use view::{AsMatrixView, MatrixView, MatrixViewMut};
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-/// # Base struct definitions
+// # Base struct definitions
 ///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, PartialEq)]
@@ -26,58 +78,380 @@ impl<T: Add<T, Output = T>> LalgrsVector<T> {
     }
 }
 
-impl<T: Add<T, Output = T> + Clone> Iterator for LalgrsVector<T> {
-    type Item = T;
+/// ## Shape of a matrix, as number of rows and number of columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape {
+    pub rows: usize,
+    pub columns: usize,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.values.pop_front()
+impl Shape {
+    pub fn new(rows: usize, columns: usize) -> Shape {
+        Shape { rows, columns }
+    }
+    /// ## Number of elements of a matrix with this shape
+    pub fn size(&self) -> usize {
+        self.rows * self.columns
+    }
+    /// ## Number of elements, or `None` if it does not fit in a `usize`
+    pub fn checked_size(&self) -> Option<usize> {
+        self.rows.checked_mul(self.columns)
+    }
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+    /// ## A shape is empty if it has no rows or no columns
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0 || self.columns == 0
+    }
+    /// ## Shape of the transposed matrix
+    pub fn transposed(&self) -> Shape {
+        Shape::new(self.columns, self.rows)
     }
 }
 
+impl From<(usize, usize)> for Shape {
+    fn from(value: (usize, usize)) -> Self {
+        Shape::new(value.0, value.1)
+    }
+}
+
+impl From<Shape> for (usize, usize) {
+    fn from(value: Shape) -> Self {
+        (value.rows, value.columns)
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.rows, self.columns)
+    }
+}
+
+/// ## Dense matrix
+/// Elements are stored column by column in a single buffer.
+/// Element `(row, column)` can be read with `matrix[(row, column)]` or, without panicking, with `matrix.get(row, column)`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct LalgrsMatrix<T: Add<T, Output = T>> {
-    columns: Vec<LalgrsVector<T>>,
+    data: Vec<T>,
+    shape: Shape,
+}
+
+impl<T: Add<T, Output = T>> LalgrsMatrix<T> {
+    /// ## Number of rows
+    pub fn rows(&self) -> usize {
+        self.shape.rows
+    }
+    /// ## Number of columns
+    pub fn columns(&self) -> usize {
+        self.shape.columns
+    }
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+    /// ## A matrix is empty if it has no rows or no columns
+    pub fn is_empty(&self) -> bool {
+        self.shape.is_empty()
+    }
+
+    /// ## Returns a reference to the element at the given row and column
+    /// Returns `None` if the position is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row >= self.shape.rows || column >= self.shape.columns {
+            return None;
+        }
+        self.data.get(column * self.shape.rows + row)
+    }
+
+    /// ## Returns a mutable reference to the element at the given row and column
+    /// Returns `None` if the position is out of bounds
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if row >= self.shape.rows || column >= self.shape.columns {
+            return None;
+        }
+        self.data.get_mut(column * self.shape.rows + row)
+    }
+
+    /// ## Elements of the matrix, column by column
+    pub fn as_column_major_slice(&self) -> &[T] {
+        &self.data
+    }
 }
 
 impl<T: Add<T, Output = T> + Clone> LalgrsMatrix<T> {
+    /// ## Creates a matrix from a list of columns
+    /// Each inner vector is a column of the matrix, so `new(vec![vec![1, 2], vec![3, 4]])` is
+    /// ```text
+    /// | 1 3 |
+    /// | 2 4 |
+    /// ```
+    /// Use `LalgrsMatrix::from_rows` to build a matrix row by row.
+    /// If the columns have different lengths, returns an error
     pub fn new(columns: Vec<Vec<T>>) -> Result<LalgrsMatrix<T>, LalgrsError> {
-        let are_all_vectors_the_same_size = columns.iter().all(|v| v.len() == columns[0].len());
-        if !are_all_vectors_the_same_size {
+        let rows = columns.first().map_or(0, |c| c.len());
+        if columns.iter().any(|c| c.len() != rows) {
             return Err(LalgrsError::InvalidMatrixDimensions);
         }
 
         Ok(LalgrsMatrix {
-            columns: columns
-                .iter()
-                .map(|v| LalgrsVector::new(v.to_vec()))
-                .collect(),
+            shape: Shape::new(rows, columns.len()),
+            data: columns.into_iter().flatten().collect(),
         })
     }
-    pub fn rows(&self) -> usize {
-        return self.columns.len();
+}
+
+/// ## Indexing by `(row, column)`
+/// Panics if the position is out of bounds. Use `LalgrsMatrix::get` for a non panicking alternative
+impl<T: Add<T, Output = T>> Index<(usize, usize)> for LalgrsMatrix<T> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        assert!(
+            row < self.shape.rows && column < self.shape.columns,
+            "index ({row}, {column}) out of bounds for matrix of shape {}",
+            self.shape
+        );
+        &self.data[column * self.shape.rows + row]
     }
-    pub fn columns(&self) -> usize {
-        if self.columns.is_empty() {
-            return 0;
-        }
+}
 
-        return self.columns[0].size();
+impl<T: Add<T, Output = T>> IndexMut<(usize, usize)> for LalgrsMatrix<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            row < self.shape.rows && column < self.shape.columns,
+            "index ({row}, {column}) out of bounds for matrix of shape {}",
+            self.shape
+        );
+        &mut self.data[column * self.shape.rows + row]
     }
 }
 
+/// ## Creates a matrix from a list of column vectors
+/// If the vectors have different lengths, returns an error
 impl<T: Add<T, Output = T>> TryFrom<Vec<LalgrsVector<T>>> for LalgrsMatrix<T> {
     type Error = LalgrsError;
     fn try_from(value: Vec<LalgrsVector<T>>) -> Result<Self, Self::Error> {
-        let are_all_vectors_the_same_size = value.iter().all(|v| v.size() == value[0].size());
-        if !are_all_vectors_the_same_size {
+        let rows = value.first().map_or(0, |v| v.size());
+        if value.iter().any(|v| v.size() != rows) {
             return Err(LalgrsError::InvalidMatrixDimensions);
         }
-        Ok(LalgrsMatrix { columns: value })
+        Ok(LalgrsMatrix {
+            shape: Shape::new(rows, value.len()),
+            data: value.into_iter().flat_map(|v| v.values).collect(),
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Matrix constructors
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Add<T, Output = T> + Clone> LalgrsMatrix<T> {
+    /// ## Creates a matrix from a list of columns
+    /// Each inner vector is a column of the matrix. Equivalent to `LalgrsMatrix::new`.
+    /// If the columns have different lengths, returns an error
+    pub fn from_columns(columns: Vec<Vec<T>>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        LalgrsMatrix::new(columns)
+    }
+
+    /// ## Creates a matrix from a list of rows
+    /// Each inner vector is a row of the matrix.
+    /// If the rows have different lengths, returns an error
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        let row_length = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != row_length) {
+            return Err(LalgrsError::InvalidMatrixDimensions);
+        }
+
+        LalgrsMatrix::new(
+            (0..row_length)
+                .map(|j| rows.iter().map(|r| r[j].clone()).collect())
+                .collect(),
+        )
+    }
+
+    /// ## Creates a matrix from a slice of elements stored row by row
+    /// If `rows * columns` overflows or is not the length of the slice, returns an error
+    pub fn from_row_slice(
+        rows: usize,
+        columns: usize,
+        values: &[T],
+    ) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        let expected = Shape::new(rows, columns)
+            .checked_size()
+            .ok_or(LalgrsError::InvalidMatrixDimensions)?;
+        if expected != values.len() {
+            return Err(LalgrsError::InvalidElementCount {
+                expected,
+                found: values.len(),
+            });
+        }
+
+        Ok(LalgrsMatrix::from_fn(rows, columns, |i, j| {
+            values[i * columns + j].clone()
+        }))
+    }
+
+    /// ## Creates a matrix by calling `f(row, column)` for every element
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(
+        rows: usize,
+        columns: usize,
+        mut f: F,
+    ) -> LalgrsMatrix<T> {
+        LalgrsMatrix {
+            data: (0..columns)
+                .flat_map(|j| (0..rows).map(move |i| (i, j)))
+                .map(|(i, j)| f(i, j))
+                .collect(),
+            shape: Shape::new(rows, columns),
+        }
+    }
+
+    /// ## Creates a matrix with every element set to `value`
+    pub fn from_element(rows: usize, columns: usize, value: T) -> LalgrsMatrix<T> {
+        LalgrsMatrix::from_fn(rows, columns, |_, _| value.clone())
+    }
+
+    /// ## Returns the transpose of the matrix
+    pub fn transpose(&self) -> LalgrsMatrix<T> {
+        LalgrsMatrix::from_fn(self.shape.columns, self.shape.rows, |i, j| {
+            self[(j, i)].clone()
+        })
+    }
+}
+
+impl<T: Add<T, Output = T> + Clone + Zero> LalgrsMatrix<T> {
+    /// ## Creates a matrix with every element set to zero
+    pub fn zeros(rows: usize, columns: usize) -> LalgrsMatrix<T> {
+        LalgrsMatrix::from_element(rows, columns, T::zero())
+    }
+
+    /// ## Creates a square matrix with the elements of `diagonal` on its main diagonal and zeros elsewhere
+    pub fn from_diagonal(diagonal: &LalgrsVector<T>) -> LalgrsMatrix<T> {
+        let n = diagonal.size();
+        LalgrsMatrix::from_fn(n, n, |i, j| {
+            if i == j {
+                diagonal.values[i].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+impl<T: Add<T, Output = T> + Clone + One> LalgrsMatrix<T> {
+    /// ## Creates a matrix with every element set to one
+    pub fn ones(rows: usize, columns: usize) -> LalgrsMatrix<T> {
+        LalgrsMatrix::from_element(rows, columns, T::one())
+    }
+}
+
+impl<T: Add<T, Output = T> + Clone + Zero + One> LalgrsMatrix<T> {
+    /// ## Creates the `n` by `n` identity matrix
+    pub fn identity(n: usize) -> LalgrsMatrix<T> {
+        LalgrsMatrix::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Vector iteration
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Add<T, Output = T>> LalgrsVector<T> {
+    /// ## Iterator over references to the elements
+    pub fn iter(&self) -> vec_deque::Iter<'_, T> {
+        self.values.iter()
+    }
+
+    /// ## Iterator over mutable references to the elements
+    pub fn iter_mut(&mut self) -> vec_deque::IterMut<'_, T> {
+        self.values.iter_mut()
+    }
+
+    /// ## New vector with `f` applied to every element
+    pub fn map<U: Add<U, Output = U>, F: FnMut(&T) -> U>(&self, f: F) -> LalgrsVector<U> {
+        self.iter().map(f).collect()
+    }
+
+    /// ## New vector with `f` applied to every pair of elements at the same position
+    /// If the vectors have different sizes, returns an error
+    pub fn zip_map<U, V, F>(
+        &self,
+        other: &LalgrsVector<U>,
+        mut f: F,
+    ) -> Result<LalgrsVector<V>, LalgrsError>
+    where
+        U: Add<U, Output = U>,
+        V: Add<V, Output = V>,
+        F: FnMut(&T, &U) -> V,
+    {
+        if self.size() != other.size() {
+            return Err(LalgrsError::MismatchedVectorDimensions {
+                vector1: self.size(),
+                vector2: other.size(),
+            });
+        }
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .map(|(a, b)| f(a, b))
+            .collect())
+    }
+
+    /// ## Combines the elements from first to last, starting with `init`
+    pub fn fold<A, F: FnMut(A, &T) -> A>(&self, init: A, f: F) -> A {
+        self.iter().fold(init, f)
+    }
+}
+
+impl<T: Add<T, Output = T>> IntoIterator for LalgrsVector<T> {
+    type Item = T;
+    type IntoIter = vec_deque::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, T: Add<T, Output = T>> IntoIterator for &'a LalgrsVector<T> {
+    type Item = &'a T;
+    type IntoIter = vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+impl<'a, T: Add<T, Output = T>> IntoIterator for &'a mut LalgrsVector<T> {
+    type Item = &'a mut T;
+    type IntoIter = vec_deque::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter_mut()
+    }
+}
+
+impl<T: Add<T, Output = T>> FromIterator<T> for LalgrsVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        LalgrsVector {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Add<T, Output = T>> Extend<T> for LalgrsVector<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.values.extend(iter);
+    }
+}
+
+impl<'a, T: Add<T, Output = T> + Copy + 'a> Extend<&'a T> for LalgrsVector<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.values.extend(iter);
     }
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-/// # Vector Operations
+// # Vector Operations
 ///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// ## Addition between two vectors.
@@ -102,13 +476,13 @@ impl<T: Add<T, Output = T> + Clone> ops::Add<LalgrsVector<T>> for LalgrsVector<T
             });
         };
 
-        return Ok(LalgrsVector::new(
+        Ok(LalgrsVector::new(
             self.values
                 .iter()
                 .zip(rhs.values)
                 .map(|e| -> T { e.0.to_owned() + e.1.to_owned() })
                 .collect(),
-        ));
+        ))
     }
 }
 
@@ -116,14 +490,7 @@ impl<T: Add<T, Output = T> + Clone> ops::Add<LalgrsVector<T>> for LalgrsVector<T
 impl<T: Add<T, Output = T> + Neg<Output = T> + Clone> ops::Neg for LalgrsVector<T> {
     type Output = LalgrsVector<T>;
     fn neg(self) -> Self::Output {
-        return LalgrsVector::new(
-            self.values
-                .iter()
-                .map(|f| -> T {
-                    return -f.to_owned();
-                })
-                .collect(),
-        );
+        LalgrsVector::new(self.values.iter().map(|f| -> T { -f.to_owned() }).collect())
     }
 }
 
@@ -134,7 +501,7 @@ impl<T: Add<T, Output = T> + Neg<Output = T> + Clone> ops::Sub<LalgrsVector<T>>
 {
     type Output = Result<LalgrsVector<T>, LalgrsError>;
     fn sub(self, rhs: LalgrsVector<T>) -> Self::Output {
-        return self + -rhs;
+        self + -rhs
     }
 }
 
@@ -153,79 +520,44 @@ impl<T: Add<T, Output = T> + Mul<T, Output = T> + Clone> ops::Mul<T> for LalgrsV
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-/// # Matrix Operations
+// # Matrix Operations
 ///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// ## Multiplication between a matrix and a vector
-/// If the number of columns in the matrix does not match the number of elements in the vector, returns an error
-/// Otherwise returns a new vector which is the result of the multiplication
-impl<T: Add<T, Output = T> + Clone + Mul<T, Output = T>> ops::Mul<LalgrsVector<T>>
+/// If the number of columns in the matrix does not match the number of elements in the vector, returns an error.
+/// A matrix without columns gives a vector of zeros.
+/// Otherwise returns a new vector which is the result of the multiplication.
+/// Overflowing elements panic like the operators of `T`; `LalgrsMatrix::checked_mul_vector` reports it as an error
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Add<T, Output = T> + Clone + Mul<T, Output = T> + Zero> ops::Mul<LalgrsVector<T>>
     for LalgrsMatrix<T>
 {
     type Output = Result<LalgrsVector<T>, LalgrsError>;
     fn mul(self, rhs: LalgrsVector<T>) -> Self::Output {
-        if self.columns() != rhs.size() {
-            return Err(LalgrsError::MismatchedVectorAndMatrixDimensions {
-                vector_size: rhs.size(),
-                matrix_columns: self.columns(),
-            });
-        }
-
-        let result = self
-            .columns
-            .iter()
-            // Zip each LalgrsVector with the corresponding vector element
-            .zip(rhs.clone().values)
-            // Multiply each LalgrsVector with the corresponding vector element
-            .map(|f| -> LalgrsVector<T> { f.0.to_owned() * f.1.to_owned() })
-            // Sum all LalgrsVectors
-            .reduce(|res, element| -> LalgrsVector<T> { (res + element).unwrap() });
-
-        return Ok(result.unwrap_or(LalgrsVector::new(vec![])));
+        view::mul_vector(self.view(), &rhs)
     }
 }
 
 /// ## Addition between two matrices
-/// If the number of columns in the first matrix does not match the number of columns in the second matrix, returns an error
-/// Otherwise returns a new matrix which is the result of the addition
+/// If one of the two matrices is `0x0`, returns the other one.
+/// If the two matrices have different shapes, returns an error.
+/// Otherwise returns a new matrix which is the result of the addition.
+/// Overflowing elements panic like the operators of `T`; `LalgrsMatrix::checked_add` reports it as an error
 impl<T: Add<T, Output = T> + Clone> ops::Add<LalgrsMatrix<T>> for LalgrsMatrix<T> {
     type Output = Result<LalgrsMatrix<T>, LalgrsError>;
     fn add(self, rhs: LalgrsMatrix<T>) -> Self::Output {
-        if self.columns() == 0 {
-            return Ok(rhs);
-        }
-
-        if rhs.columns() == 0 {
-            return Ok(self);
-        }
-
-        if self.columns() != rhs.columns() {
-            return Err(LalgrsError::MismatchedMatrixDimensions {
-                first_matrix_columns: self.columns(),
-                second_matrix_rows: rhs.columns(),
-            });
-        }
-        return Ok(LalgrsMatrix {
-            columns: self
-                .columns
-                .iter()
-                .zip(rhs.columns.iter())
-                .map(|f| -> LalgrsVector<T> { (f.0.to_owned() + f.1.to_owned()).unwrap() })
-                .collect(),
-        });
+        view::add(self.view(), rhs.view())
     }
 }
 
-/// ## Negation of a matrix. Negates each column
+/// ## Negation of a matrix. Negates each element
 impl<T: Add<T, Output = T> + Neg<Output = T> + Clone> ops::Neg for LalgrsMatrix<T> {
     type Output = LalgrsMatrix<T>;
     fn neg(self) -> Self::Output {
-        let cols: Vec<LalgrsVector<T>> = self
-            .columns
-            .iter()
-            .map(|f| -> LalgrsVector<T> { -f.to_owned() })
-            .collect();
-        return LalgrsMatrix::try_from(cols).unwrap();
+        LalgrsMatrix {
+            shape: self.shape,
+            data: self.data.into_iter().map(|v| -v).collect(),
+        }
     }
 }
 
@@ -236,7 +568,7 @@ impl<T: Add<T, Output = T> + Neg<Output = T> + Clone> ops::Sub<LalgrsMatrix<T>>
 {
     type Output = Result<LalgrsMatrix<T>, LalgrsError>;
     fn sub(self, rhs: LalgrsMatrix<T>) -> Self::Output {
-        return self + -rhs;
+        self + -rhs
     }
 }
 
@@ -245,63 +577,66 @@ impl<T: Add<T, Output = T> + Neg<Output = T> + Clone> ops::Sub<LalgrsMatrix<T>>
 impl<T: Add<T, Output = T> + Mul<T, Output = T> + Clone> ops::Mul<T> for LalgrsMatrix<T> {
     type Output = LalgrsMatrix<T>;
     fn mul(self, rhs: T) -> Self::Output {
-        let cols: Vec<LalgrsVector<T>> = self
-            .columns
-            .iter()
-            .map(|f| -> LalgrsVector<T> { f.to_owned() * rhs.clone() })
-            .collect();
-        LalgrsMatrix::try_from(cols).unwrap()
+        LalgrsMatrix {
+            shape: self.shape,
+            data: self.data.into_iter().map(|v| v * rhs.clone()).collect(),
+        }
     }
 }
 
 /// ## Multiplication between two matrices
-/// If the number of columns in the first matrix does not match the number of rows in the second matrix, returns an error
-/// Otherwise returns a new matrix which is the result of the multiplication
-impl<T: Add<T, Output = T> + Clone + Mul<T, Output = T> + Debug> ops::Mul<LalgrsMatrix<T>>
+/// If one of the two matrices is `0x0`, returns the `0x0` matrix.
+/// If the number of columns in the first matrix does not match the number of rows in the second matrix, returns an error.
+/// An `m x 0` and a `0 x n` matrix give the `m x n` zero matrix.
+/// Otherwise returns a new matrix which is the result of the multiplication.
+/// Overflowing elements panic like the operators of `T`; `LalgrsMatrix::checked_mul` reports it as an error
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Add<T, Output = T> + Clone + Mul<T, Output = T> + Zero> ops::Mul<LalgrsMatrix<T>>
     for LalgrsMatrix<T>
 {
     type Output = Result<LalgrsMatrix<T>, LalgrsError>;
     fn mul(self, rhs: LalgrsMatrix<T>) -> Self::Output {
-        if self.columns() == 0 || rhs.columns() == 0 {
-            return Ok(LalgrsMatrix::new(vec![]).unwrap());
-        }
+        view::mul(self.view(), rhs.view())
+    }
+}
 
-        if self.columns() != rhs.rows() {
-            return Err(LalgrsError::MismatchedMatrixDimensions {
-                first_matrix_columns: self.columns(),
-                second_matrix_rows: rhs.rows(),
-            });
-        }
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Checked matrix arithmetic
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-        let result_matrix: Vec<LalgrsVector<T>> = rhs
-            .columns
-            .iter()
-            // For each column of the rhs operand
-            .map(|col| -> LalgrsVector<T> {
-                return col
-                    .to_owned()
-                    .into_iter()
-                    // Iterate over the elements of the column
-                    .zip(self.columns.iter())
-                    // Zip the elements of the column with the columns of the lhs operand (each element of the column will be coupled with a column of the lhs operand)
-                    .map(|tuple| -> LalgrsVector<T> {
-                        // Multiply each element of the column with the corresponding column of the lhs operand
-                        return tuple.1.to_owned() * tuple.0;
-                    })
-                    .reduce(|acc, element| -> LalgrsVector<T> {
-                        // Sum all LalgrsVectors. This produces a new LalgrsVector which is the result of multiplying a column of the rhs operand with a row of the lhs operand
-                        (acc + element).unwrap()
-                    })
-                    .unwrap();
-            })
-            .collect();
-
-        return Ok(LalgrsMatrix::try_from(result_matrix)?);
+impl<T: Field> LalgrsMatrix<T> {
+    /// ## Sum of two matrices, as the `+` operator
+    /// If the two matrices have different shapes, or an element overflows, returns an error
+    pub fn checked_add(&self, rhs: &LalgrsMatrix<T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        view::checked_elementwise(self.view(), rhs.view(), |a, b| a.checked_add(b))
+    }
+
+    /// ## Difference of two matrices, as the `-` operator
+    /// If the two matrices have different shapes, or an element overflows, returns an error
+    pub fn checked_sub(&self, rhs: &LalgrsMatrix<T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        view::checked_elementwise(self.view(), rhs.view(), |a, b| a.checked_sub(b))
+    }
+
+    /// ## Product of two matrices, as the `*` operator
+    /// If the number of columns of `self` does not match the number of rows of `rhs`, or an element overflows,
+    /// returns an error
+    pub fn checked_mul(&self, rhs: &LalgrsMatrix<T>) -> Result<LalgrsMatrix<T>, LalgrsError> {
+        view::checked_mul(self.view(), rhs.view())
+    }
+
+    /// ## Product of the matrix and a vector, as the `*` operator
+    /// If the number of columns does not match the number of elements in the vector, or an element overflows,
+    /// returns an error
+    pub fn checked_mul_vector(
+        &self,
+        rhs: &LalgrsVector<T>,
+    ) -> Result<LalgrsVector<T>, LalgrsError> {
+        view::checked_mul_vector(self.view(), rhs)
     }
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-/// # Errors
+// # Errors
 ///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, Error, PartialEq)]
@@ -309,18 +644,86 @@ pub enum LalgrsError {
     #[error("Mismatched vector dimensions. Found {vector1} and {vector2}")]
     MismatchedVectorDimensions { vector1: usize, vector2: usize },
 
-    #[error("Mismatched vector and matrix dimensions. Vector has {vector_size} elements and matrix has {matrix_columns} columns")]
+    #[error("Mismatched vector and matrix dimensions. Vector has {vector_size} elements and matrix has shape {matrix_shape}")]
     MismatchedVectorAndMatrixDimensions {
         vector_size: usize,
-        matrix_columns: usize,
+        matrix_shape: Shape,
     },
 
-    #[error("Mismatched matrix sizes. Left hand side matrix columns :{first_matrix_columns},  Right hand side matrix rows:{second_matrix_rows}")]
-    MismatchedMatrixDimensions {
-        first_matrix_columns: usize,
-        second_matrix_rows: usize,
-    },
+    #[error("Mismatched matrix dimensions. Left hand side matrix has shape {lhs}, right hand side matrix has shape {rhs}")]
+    MismatchedMatrixDimensions { lhs: Shape, rhs: Shape },
 
-    #[error("Could not create matrix. All columns must have the same length")]
+    #[error("Could not create matrix. All rows or columns must have the same length")]
     InvalidMatrixDimensions,
+
+    #[error("Could not create matrix. Expected {expected} elements, found {found}")]
+    InvalidElementCount { expected: usize, found: usize },
+
+    #[error("Submatrix of shape {shape} starting at ({row}, {column}) does not fit inside a matrix of shape {matrix_shape}")]
+    SubmatrixOutOfBounds {
+        row: usize,
+        column: usize,
+        shape: Shape,
+        matrix_shape: Shape,
+    },
+
+    #[error("Could not create rotation. The rotation axis has zero length")]
+    ZeroLengthAxis,
+
+    #[error("Could not normalise quaternion. The quaternion has zero length")]
+    ZeroLengthQuaternion,
+
+    #[error("Invalid projection parameters. Near plane: {near}, far plane: {far}")]
+    InvalidProjection { near: f64, far: f64 },
+
+    #[error("The transformed point lies at infinity")]
+    PointAtInfinity,
+
+    #[error("The operation requires a non empty matrix")]
+    EmptyMatrix,
+
+    #[error("The matrix is singular")]
+    SingularMatrix,
+
+    #[error("The operation requires a square matrix, found shape {shape}")]
+    NonSquareMatrix { shape: Shape },
+
+    #[error("The matrix is not positive definite")]
+    NotPositiveDefinite,
+
+    #[error("The matrix is ill conditioned, with an estimated condition number of {estimate}")]
+    IllConditioned { estimate: f64 },
+
+    #[error("The iteration did not converge after {iterations} iterations")]
+    NoConvergence { iterations: usize },
+
+    #[error("The Riccati equation has no stabilising solution")]
+    NoStabilisingSolution,
+
+    #[error("Arithmetic overflow or division by zero")]
+    ArithmeticOverflow,
+
+    #[error("Invalid value {value} for parameter {name}")]
+    InvalidParameter { name: &'static str, value: f64 },
+
+    #[error("Invalid image: {0}")]
+    InvalidImage(String),
+
+    #[error("Invalid rule: {0}")]
+    InvalidRule(String),
+
+    #[error("Invalid pattern: {0}")]
+    InvalidPattern(String),
+
+    #[error("Invalid rational: {0}")]
+    InvalidRational(String),
+
+    #[error("I/O error: {0}")]
+    Io(String),
+}
+
+impl From<std::io::Error> for LalgrsError {
+    fn from(value: std::io::Error) -> Self {
+        LalgrsError::Io(value.to_string())
+    }
 }
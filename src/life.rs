@@ -0,0 +1,505 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{convolution::Border, LalgrsError, LalgrsMatrix, Shape};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Rules
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Life-like cellular automaton rule
+/// A dead cell is born if its number of live neighbours is in the birth set,
+/// and a live cell survives if its number of live neighbours is in the survival set.
+/// Rules are written in B/S notation, e.g. `B3/S23` for Conway's Game of Life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rule {
+    birth: [bool; 9],
+    survival: [bool; 9],
+}
+
+impl Rule {
+    /// ## Rule with the given birth and survival neighbour counts
+    /// If a count is larger than 8, returns an error
+    pub fn new(birth: &[u8], survival: &[u8]) -> Result<Rule, LalgrsError> {
+        let mut rule = Rule {
+            birth: [false; 9],
+            survival: [false; 9],
+        };
+        for (counts, set) in [(birth, &mut rule.birth), (survival, &mut rule.survival)] {
+            for count in counts {
+                *set.get_mut(*count as usize).ok_or_else(|| {
+                    LalgrsError::InvalidRule(format!("{count} is not a neighbour count"))
+                })? = true;
+            }
+        }
+        Ok(rule)
+    }
+
+    /// ## Conway's Game of Life, `B3/S23`
+    pub fn conway() -> Rule {
+        Rule {
+            birth: [false, false, false, true, false, false, false, false, false],
+            survival: [false, false, true, true, false, false, false, false, false],
+        }
+    }
+
+    /// ## HighLife, `B36/S23`
+    pub fn high_life() -> Rule {
+        Rule {
+            birth: [false, false, false, true, false, false, true, false, false],
+            ..Rule::conway()
+        }
+    }
+
+    /// ## State of a cell in the next generation
+    pub fn next_state(&self, alive: bool, neighbours: u8) -> bool {
+        let set = if alive { &self.survival } else { &self.birth };
+        set.get(neighbours as usize).copied().unwrap_or(false)
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::conway()
+    }
+}
+
+/// ## Parses a rule in B/S notation, e.g. `B36/S23`
+/// Letters are case insensitive and the two parts can be given in either order
+impl FromStr for Rule {
+    type Err = LalgrsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || LalgrsError::InvalidRule(s.to_string());
+        let (mut birth, mut survival) = (None, None);
+        for part in s.trim().split('/') {
+            let mut chars = part.chars();
+            let target = match chars.next().map(|c| c.to_ascii_uppercase()) {
+                Some('B') => &mut birth,
+                Some('S') => &mut survival,
+                _ => return Err(invalid()),
+            };
+            if target.is_some() {
+                return Err(invalid());
+            }
+            let counts = chars
+                .map(|c| c.to_digit(10).map(|d| d as u8).ok_or_else(invalid))
+                .collect::<Result<Vec<u8>, _>>()?;
+            *target = Some(counts);
+        }
+        match (birth, survival) {
+            (Some(birth), Some(survival)) => Rule::new(&birth, &survival),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |set: &[bool; 9]| -> String {
+            (0..9).filter(|n| set[*n]).map(|n| n.to_string()).collect()
+        };
+        write!(f, "B{}/S{}", counts(&self.birth), counts(&self.survival))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Game of Life
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## What happens at the edges of the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Cells outside of the grid are always dead
+    Bounded,
+    /// The grid wraps around, so cells on opposite edges are neighbours
+    Toroidal,
+}
+
+/// ## Grid of a life-like cellular automaton
+/// Live cells are 1 and dead cells are 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Life {
+    grid: LalgrsMatrix<u8>,
+    rule: Rule,
+    topology: Topology,
+    generation: u64,
+}
+
+impl Life {
+    /// ## Starts from the given grid, where every non zero cell is alive
+    pub fn new(grid: &LalgrsMatrix<u8>, rule: Rule, topology: Topology) -> Life {
+        Life {
+            grid: LalgrsMatrix::from_fn(grid.rows(), grid.columns(), |i, j| {
+                (grid[(i, j)] != 0) as u8
+            }),
+            rule,
+            topology,
+            generation: 0,
+        }
+    }
+
+    /// ## Places `pattern` in the middle of an empty grid of the given shape
+    /// The rule of the pattern is used if it has one, otherwise Conway's rule.
+    /// If the pattern does not fit in the grid, returns an error
+    pub fn from_pattern(
+        pattern: &Pattern,
+        shape: Shape,
+        topology: Topology,
+    ) -> Result<Life, LalgrsError> {
+        let cells = pattern.cells.shape();
+        let (row, column) = (
+            shape.rows.saturating_sub(cells.rows) / 2,
+            shape.columns.saturating_sub(cells.columns) / 2,
+        );
+        let mut grid = LalgrsMatrix::from_element(shape.rows, shape.columns, 0);
+        grid.submatrix_mut(row, column, cells)?
+            .copy_from(&pattern.cells.view())?;
+        Ok(Life::new(&grid, pattern.rule.unwrap_or_default(), topology))
+    }
+
+    pub fn grid(&self) -> &LalgrsMatrix<u8> {
+        &self.grid
+    }
+
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// ## Number of generations computed since the start
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// ## Number of live cells
+    pub fn population(&self) -> usize {
+        self.grid
+            .as_column_major_slice()
+            .iter()
+            .filter(|c| **c != 0)
+            .count()
+    }
+
+    /// ## Number of live neighbours of every cell
+    /// Computed as the convolution of the grid with a 3x3 kernel of ones with a zero in the middle
+    pub fn neighbour_counts(&self) -> LalgrsMatrix<u8> {
+        let kernel = LalgrsMatrix::from_fn(3, 3, |i, j| (i != 1 || j != 1) as u8);
+        let border = match self.topology {
+            Topology::Bounded => Border::Zero,
+            Topology::Toroidal => Border::Wrap,
+        };
+        // The kernel is never empty, so the convolution cannot fail
+        self.grid.convolve(&kernel, border).unwrap_or_else(|_| {
+            LalgrsMatrix::from_element(self.grid.rows(), self.grid.columns(), 0)
+        })
+    }
+
+    /// ## Computes the next generation
+    pub fn step(&mut self) {
+        let counts = self.neighbour_counts();
+        self.grid = LalgrsMatrix::from_fn(self.grid.rows(), self.grid.columns(), |i, j| {
+            self.rule.next_state(self.grid[(i, j)] != 0, counts[(i, j)]) as u8
+        });
+        self.generation += 1;
+    }
+
+    /// ## Computes the next `generations` generations
+    pub fn advance(&mut self, generations: u64) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// ## The whole grid as a pattern with the rule of the game
+    pub fn to_pattern(&self) -> Pattern {
+        Pattern {
+            name: None,
+            cells: self.grid.clone(),
+            rule: Some(self.rule),
+        }
+    }
+}
+
+/// ## Draws live cells as `O` and dead cells as `.`, one line per row
+impl Display for Life {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.grid.rows() {
+            let line: String = (0..self.grid.columns())
+                .map(|j| if self.grid[(i, j)] != 0 { 'O' } else { '.' })
+                .collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Pattern files
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Pattern read from or written to a RLE or plaintext `.cells` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    pub name: Option<String>,
+    /// Live cells are 1 and dead cells are 0
+    pub cells: LalgrsMatrix<u8>,
+    /// Only RLE files store a rule
+    pub rule: Option<Rule>,
+}
+
+/// Maximum length of the lines written in RLE files
+const RLE_LINE_LENGTH: usize = 70;
+
+/// Largest number of cells an RLE header may declare, so that a malformed file can not exhaust memory
+const MAX_PATTERN_CELLS: usize = 1 << 26;
+
+/// ## Grid of the given shape with the given live cells
+fn cells_from_rows(rows: &[Vec<bool>], columns: usize) -> LalgrsMatrix<u8> {
+    LalgrsMatrix::from_fn(rows.len(), columns, |i, j| {
+        rows[i].get(j).copied().unwrap_or(false) as u8
+    })
+}
+
+fn is_rle(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("rle"))
+}
+
+impl Pattern {
+    /// ## Loads a pattern from a file
+    /// Files with the `.rle` extension are run length encoded, any other file is read as plaintext `.cells`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Pattern, LalgrsError> {
+        let text = fs::read_to_string(&path)?;
+        if is_rle(path.as_ref()) {
+            Pattern::from_rle(&text)
+        } else {
+            Pattern::from_cells(&text)
+        }
+    }
+
+    /// ## Saves the pattern to a file, in the format given by its extension as in `Pattern::load`
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), LalgrsError> {
+        let text = if is_rle(path.as_ref()) {
+            self.to_rle()
+        } else {
+            self.to_cells()
+        };
+        fs::write(path, text)?;
+        Ok(())
+    }
+
+    /// ## Parses a plaintext `.cells` pattern
+    /// Lines starting with `!` are comments, and `!Name:` gives the name of the pattern.
+    /// `O` or `*` marks a live cell and `.` a dead cell; short lines are padded with dead cells.
+    /// If a line contains any other character, returns an error
+    pub fn from_cells(text: &str) -> Result<Pattern, LalgrsError> {
+        let mut name = None;
+        let mut rows = Vec::new();
+        for line in text.lines() {
+            let line = line.trim_end();
+            if let Some(comment) = line.strip_prefix('!') {
+                if let Some(n) = comment.strip_prefix("Name:") {
+                    name = Some(n.trim().to_string());
+                }
+                continue;
+            }
+            let row = line
+                .chars()
+                .map(|c| match c {
+                    'O' | '*' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(LalgrsError::InvalidPattern(format!(
+                        "unexpected character {c:?} in .cells pattern"
+                    ))),
+                })
+                .collect::<Result<Vec<bool>, _>>()?;
+            rows.push(row);
+        }
+        let columns = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        Ok(Pattern {
+            name,
+            cells: cells_from_rows(&rows, columns),
+            rule: None,
+        })
+    }
+
+    /// ## Writes the pattern in the plaintext `.cells` format
+    pub fn to_cells(&self) -> String {
+        let mut text = String::new();
+        if let Some(name) = &self.name {
+            text.push_str(&format!("!Name: {name}\n"));
+        }
+        for i in 0..self.cells.rows() {
+            text.extend((0..self.cells.columns()).map(|j| {
+                if self.cells[(i, j)] != 0 {
+                    'O'
+                } else {
+                    '.'
+                }
+            }));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// ## Parses a run length encoded pattern
+    /// Lines starting with `#` are comments, and `#N` gives the name of the pattern.
+    /// The header `x = <columns>, y = <rows>` can be followed by `, rule = <B/S rule>`.
+    /// If the header is missing or malformed, declares more than 2²⁶ cells, or the cells do not fit in the declared size, returns an error
+    pub fn from_rle(text: &str) -> Result<Pattern, LalgrsError> {
+        let invalid = |message: &str| LalgrsError::InvalidPattern(message.to_string());
+        let mut name = None;
+        let mut lines = text.lines().map(str::trim).filter(|l| !l.is_empty());
+
+        let header = loop {
+            match lines.next() {
+                Some(line) if line.starts_with('#') => {
+                    if let Some(n) = line.strip_prefix("#N") {
+                        name = Some(n.trim().to_string());
+                    }
+                }
+                Some(line) => break line,
+                None => return Err(invalid("missing RLE header")),
+            }
+        };
+        let (mut columns, mut rows, mut rule) = (None, None, None);
+        for field in header.split(',') {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| invalid("malformed RLE header"))?;
+            let (key, value) = (key.trim(), value.trim());
+            match key {
+                "x" => columns = value.parse::<usize>().ok(),
+                "y" => rows = value.parse::<usize>().ok(),
+                "rule" => rule = Some(value.parse::<Rule>()?),
+                _ => return Err(invalid("unknown field in RLE header")),
+            }
+        }
+        let (Some(columns), Some(rows)) = (columns, rows) else {
+            return Err(invalid("the RLE header needs x and y"));
+        };
+        if rows
+            .checked_mul(columns)
+            .is_none_or(|cells| cells > MAX_PATTERN_CELLS)
+        {
+            return Err(invalid("the declared size is too large"));
+        }
+
+        let mut cells: Vec<Vec<bool>> = vec![Vec::new()];
+        let mut count: Option<usize> = None;
+        'body: for line in lines {
+            if line.starts_with('#') {
+                continue;
+            }
+            for c in line.chars() {
+                match c {
+                    '0'..='9' => {
+                        let digit = c as usize - '0' as usize;
+                        count = count
+                            .unwrap_or(0)
+                            .checked_mul(10)
+                            .and_then(|n| n.checked_add(digit));
+                        if count.is_none() {
+                            return Err(invalid("run count is too large"));
+                        }
+                        continue;
+                    }
+                    'b' | '.' | 'o' | 'A'..='X' => {
+                        let run = count.unwrap_or(1);
+                        let row = cells.last_mut().ok_or_else(|| invalid("empty pattern"))?;
+                        if run > columns.saturating_sub(row.len()) {
+                            return Err(invalid("row longer than the declared width"));
+                        }
+                        row.extend(std::iter::repeat_n(c != 'b' && c != '.', run));
+                    }
+                    '$' => {
+                        let run = count.unwrap_or(1);
+                        if run > rows.saturating_sub(cells.len()) {
+                            return Err(invalid("more rows than the declared height"));
+                        }
+                        cells.extend((0..run).map(|_| Vec::new()));
+                    }
+                    '!' => break 'body,
+                    c if c.is_whitespace() => {}
+                    c => {
+                        return Err(LalgrsError::InvalidPattern(format!(
+                            "unexpected character {c:?} in RLE pattern"
+                        )))
+                    }
+                }
+                count = None;
+            }
+        }
+
+        cells.resize(rows, Vec::new());
+        Ok(Pattern {
+            name,
+            cells: cells_from_rows(&cells, columns),
+            rule,
+        })
+    }
+
+    /// ## Writes the pattern in the run length encoded format
+    /// Lines are at most 70 characters long
+    pub fn to_rle(&self) -> String {
+        let mut text = String::new();
+        if let Some(name) = &self.name {
+            text.push_str(&format!("#N {name}\n"));
+        }
+        text.push_str(&format!(
+            "x = {}, y = {}, rule = {}\n",
+            self.cells.columns(),
+            self.cells.rows(),
+            self.rule.unwrap_or_default()
+        ));
+
+        // Runs of (count, tag), without trailing dead cells and with empty rows merged into `$` runs
+        let mut runs: Vec<(usize, char)> = Vec::new();
+        let push = |runs: &mut Vec<(usize, char)>, count: usize, tag: char| match runs.last_mut() {
+            Some((n, t)) if *t == tag => *n += count,
+            _ => runs.push((count, tag)),
+        };
+        for i in 0..self.cells.rows() {
+            if i > 0 {
+                push(&mut runs, 1, '$');
+            }
+            let alive: Vec<bool> = (0..self.cells.columns())
+                .map(|j| self.cells[(i, j)] != 0)
+                .collect();
+            let length = alive.iter().rposition(|a| *a).map_or(0, |p| p + 1);
+            for a in &alive[..length] {
+                push(&mut runs, 1, if *a { 'o' } else { 'b' });
+            }
+        }
+        while runs.last().is_some_and(|(_, t)| *t == '$') {
+            runs.pop();
+        }
+        runs.push((1, '!'));
+
+        let mut line = String::new();
+        for (count, tag) in runs {
+            let run = if count > 1 {
+                format!("{count}{tag}")
+            } else {
+                tag.to_string()
+            };
+            if line.len() + run.len() > RLE_LINE_LENGTH {
+                text.push_str(&line);
+                text.push('\n');
+                line.clear();
+            }
+            line.push_str(&run);
+        }
+        text.push_str(&line);
+        text.push('\n');
+        text
+    }
+}
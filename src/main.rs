@@ -1,3 +1,147 @@
-fn main() {
-    println!("Ciao");
+use std::{env, process::ExitCode, thread, time::Duration};
+
+use lalgrs::{
+    compression::compress_image,
+    image::{Image, NetpbmFormat},
+    life::{Life, Pattern, Rule, Topology},
+    LalgrsMatrix, Rng, Shape,
+};
+
+const USAGE: &str = "Usage:
+    lalgrs compress <input> <output> <rank>    Keep the <rank> largest singular values of a PGM or PPM image
+    lalgrs life [<pattern>] [options]          Print the generations of a life-like cellular automaton
+
+Options of life:
+    --rule <B/S>            Rule in B/S notation, e.g. B36/S23. Default: the rule of the pattern, or B3/S23
+    --size <rows>x<columns> Size of the grid. Default: 24x60
+    --generations <n>       Number of generations to print after the first one. Default: 50
+    --bounded               Cells outside of the grid are dead, instead of wrapping around
+    --delay <ms>            Wait between generations and redraw them in place
+    --seed <n>              Seed of the random grid used when no pattern is given. Default: 0
+";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("compress") => compress(&args[1..]),
+        Some("life") => life(&args[1..]),
+        Some("help" | "--help" | "-h") => {
+            print!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// ## `compress <input> <output> <rank>`
+/// Compresses the input image, writes the reconstruction to the output file and prints the compression report
+fn compress(args: &[String]) -> Result<(), String> {
+    let [input, output, rank] = args else {
+        return Err(USAGE.to_string());
+    };
+    let rank: usize = rank.parse().map_err(|_| format!("Invalid rank: {rank}"))?;
+
+    let image = Image::load(input).map_err(|e| e.to_string())?;
+    let compressed = compress_image(&image, rank).map_err(|e| e.to_string())?;
+    let format = if image.is_color() {
+        NetpbmFormat::BinaryColor
+    } else {
+        NetpbmFormat::BinaryGray
+    };
+    compressed.save(output, format).map_err(|e| e.to_string())?;
+    let report = compressed.report(&image).map_err(|e| e.to_string())?;
+    println!("{report}");
+    Ok(())
+}
+
+/// ## `life [<pattern>] [options]`
+/// Runs the pattern, or a random grid, and prints every generation
+fn life(args: &[String]) -> Result<(), String> {
+    let mut pattern_path = None;
+    let mut rule = None;
+    let mut shape = Shape::new(24, 60);
+    let mut generations: u64 = 50;
+    let mut topology = Topology::Toroidal;
+    let mut delay = None;
+    let mut seed = 0;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--rule" => rule = Some(value()?.parse::<Rule>().map_err(|e| e.to_string())?),
+            "--size" => {
+                let size = value()?;
+                shape = size
+                    .split_once('x')
+                    .and_then(|(r, c)| Some(Shape::new(r.parse().ok()?, c.parse().ok()?)))
+                    .ok_or_else(|| format!("Invalid size: {size}"))?;
+            }
+            "--generations" => {
+                let n = value()?;
+                generations = n.parse().map_err(|_| format!("Invalid generations: {n}"))?;
+            }
+            "--bounded" => topology = Topology::Bounded,
+            "--delay" => {
+                let ms = value()?;
+                delay = Some(Duration::from_millis(
+                    ms.parse().map_err(|_| format!("Invalid delay: {ms}"))?,
+                ));
+            }
+            "--seed" => {
+                let n = value()?;
+                seed = n.parse().map_err(|_| format!("Invalid seed: {n}"))?;
+            }
+            path if !path.starts_with("--") && pattern_path.is_none() => pattern_path = Some(path),
+            other => return Err(format!("Unexpected argument: {other}\n\n{USAGE}")),
+        }
+    }
+
+    let mut game = match pattern_path {
+        Some(path) => {
+            let pattern = Pattern::load(path).map_err(|e| e.to_string())?;
+            Life::from_pattern(&pattern, shape, topology).map_err(|e| e.to_string())?
+        }
+        None => {
+            let mut rng = Rng::seed_from_u64(seed);
+            let grid = LalgrsMatrix::from_fn(shape.rows, shape.columns, |_, _| {
+                (rng.next_f64() < 0.3) as u8
+            });
+            Life::new(&grid, Rule::default(), topology)
+        }
+    };
+    if let Some(rule) = rule {
+        game = Life::new(game.grid(), rule, topology);
+    }
+
+    for generation in 0..=generations {
+        if generation > 0 {
+            game.step();
+        }
+        if delay.is_some() {
+            // Move the cursor to the top left corner and clear the terminal
+            print!("\x1b[H\x1b[2J");
+        }
+        println!(
+            "Generation {}, population {}, rule {}",
+            game.generation(),
+            game.population(),
+            game.rule()
+        );
+        println!("{game}");
+        if let Some(delay) = delay {
+            thread::sleep(delay);
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,227 @@
+use crate::{decomposition::check_square, random::Rng, LalgrsError, LalgrsMatrix, LalgrsVector};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Markov chains
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Largest accepted difference between the sum of a row of the transition matrix and one
+const ROW_SUM_TOLERANCE: f64 = 1e-10;
+
+/// ## Discrete time Markov chain on the states `0..states`
+/// Element `(i, j)` of the row stochastic transition matrix is the probability of moving from state `i` to state `j` in one step.
+/// Distributions over the states are row vectors, so one step maps `π` to `π * P`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkovChain {
+    transition: LalgrsMatrix<f64>,
+}
+
+impl MarkovChain {
+    /// ## Markov chain with the given transition matrix
+    /// If the matrix is empty or not square, has negative or non finite elements,
+    /// or has a row that does not sum to one, returns an error
+    pub fn new(transition: LalgrsMatrix<f64>) -> Result<MarkovChain, LalgrsError> {
+        check_square(&transition)?;
+        let n = transition.rows();
+        for i in 0..n {
+            let row: Vec<f64> = (0..n).map(|j| transition[(i, j)]).collect();
+            if let Some(p) = row.iter().find(|p| **p < 0.0 || !p.is_finite()) {
+                return Err(LalgrsError::InvalidParameter {
+                    name: "probability",
+                    value: *p,
+                });
+            }
+            let sum: f64 = row.iter().sum();
+            if (sum - 1.0).abs() > ROW_SUM_TOLERANCE {
+                return Err(LalgrsError::InvalidParameter {
+                    name: "row sum",
+                    value: sum,
+                });
+            }
+        }
+        Ok(MarkovChain { transition })
+    }
+
+    pub fn transition_matrix(&self) -> &LalgrsMatrix<f64> {
+        &self.transition
+    }
+
+    /// ## Number of states
+    pub fn states(&self) -> usize {
+        self.transition.rows()
+    }
+
+    /// ## Returns an error if `state` is not a state of the chain
+    fn check_state(&self, state: usize) -> Result<(), LalgrsError> {
+        if state >= self.states() {
+            return Err(LalgrsError::InvalidParameter {
+                name: "state",
+                value: state as f64,
+            });
+        }
+        Ok(())
+    }
+
+    /// ## Transition matrix of `steps` steps, `P^steps`
+    /// If `steps` does not fit in an `i32`, returns an error
+    pub fn n_step(&self, steps: usize) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let steps = i32::try_from(steps).map_err(|_| LalgrsError::InvalidParameter {
+            name: "steps",
+            value: steps as f64,
+        })?;
+        self.transition.powi(steps)
+    }
+
+    /// ## Distribution after `steps` steps from the `initial` distribution, `π * P^steps`
+    /// If `initial` does not have one element per state, or `steps` does not fit in an `i32`, returns an error
+    pub fn distribution_after(
+        &self,
+        initial: &LalgrsVector<f64>,
+        steps: usize,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.n_step(steps)?.transpose() * initial.clone()
+    }
+
+    /// ## Stationary distribution `π`, with `π * P = π` and elements summing to one
+    /// Solves the linear system `(Pᵀ - I) * πᵀ = 0`, with one equation replaced by the normalisation.
+    /// The distribution is unique when the chain has a single closed communicating class.
+    /// If there are several stationary distributions, returns an error
+    pub fn stationary_distribution(&self) -> Result<LalgrsVector<f64>, LalgrsError> {
+        let n = self.states();
+        let system = LalgrsMatrix::from_fn(n, n, |i, j| {
+            if i == n - 1 {
+                1.0
+            } else if i == j {
+                self.transition[(j, i)] - 1.0
+            } else {
+                self.transition[(j, i)]
+            }
+        });
+        let mut rhs = vec![0.0; n];
+        rhs[n - 1] = 1.0;
+        // Rounding can leave tiny negative probabilities
+        Ok(system.solve(&LalgrsVector::new(rhs))?.map(|p| p.max(0.0)))
+    }
+
+    /// ## Absorbing states, the states that can not be left
+    pub fn absorbing_states(&self) -> Vec<usize> {
+        (0..self.states())
+            .filter(|i| self.transition[(*i, *i)] == 1.0)
+            .collect()
+    }
+
+    /// ## States that are not absorbing, in increasing order
+    /// They index the rows and columns of `MarkovChain::fundamental_matrix`
+    pub fn transient_states(&self) -> Vec<usize> {
+        (0..self.states())
+            .filter(|i| self.transition[(*i, *i)] != 1.0)
+            .collect()
+    }
+
+    /// ## Fundamental matrix `N = (I - Q)⁻¹` of an absorbing chain
+    /// `Q` is the transition matrix restricted to the transient states, in the order of `MarkovChain::transient_states`.
+    /// Element `(i, j)` is the expected number of visits to transient state `j` when starting from transient state `i`.
+    /// If every state is absorbing, or some transient state can not reach an absorbing state,
+    /// which includes chains without absorbing states, returns an error
+    pub fn fundamental_matrix(&self) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let transient = self.transient_states();
+        if transient.is_empty() {
+            return Err(LalgrsError::EmptyMatrix);
+        }
+        let k = transient.len();
+        LalgrsMatrix::from_fn(k, k, |i, j| {
+            let identity = if i == j { 1.0 } else { 0.0 };
+            identity - self.transition[(transient[i], transient[j])]
+        })
+        .inverse()
+    }
+
+    /// ## Probabilities `B = N * R` of ending in each absorbing state
+    /// Rows follow `MarkovChain::transient_states` and columns `MarkovChain::absorbing_states`.
+    /// If every state is absorbing, or some transient state can not reach an absorbing state, returns an error
+    pub fn absorption_probabilities(&self) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let fundamental = self.fundamental_matrix()?;
+        let (transient, absorbing) = (self.transient_states(), self.absorbing_states());
+        let r = LalgrsMatrix::from_fn(transient.len(), absorbing.len(), |i, j| {
+            self.transition[(transient[i], absorbing[j])]
+        });
+        fundamental * r
+    }
+
+    /// ## Expected number of steps before absorption from each transient state, the row sums of `N`
+    /// Elements follow `MarkovChain::transient_states`.
+    /// If every state is absorbing, or some transient state can not reach an absorbing state, returns an error
+    pub fn expected_steps_to_absorption(&self) -> Result<LalgrsVector<f64>, LalgrsError> {
+        let fundamental = self.fundamental_matrix()?;
+        fundamental * LalgrsVector::new(vec![1.0; self.transient_states().len()])
+    }
+
+    /// ## Expected number of steps to reach any of the `targets` from every state
+    /// Solves `h_i = 1 + Σ_j P_ij * h_j` for the other states, with `h = 0` on the targets.
+    /// If `targets` is empty or has a state outside of the chain, or some state can not reach the targets, returns an error
+    pub fn expected_hitting_times(
+        &self,
+        targets: &[usize],
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        for target in targets {
+            self.check_state(*target)?;
+        }
+        if targets.is_empty() {
+            return Err(LalgrsError::InvalidParameter {
+                name: "targets",
+                value: 0.0,
+            });
+        }
+        let others: Vec<usize> = (0..self.states())
+            .filter(|i| !targets.contains(i))
+            .collect();
+        let mut times = vec![0.0; self.states()];
+        if others.is_empty() {
+            return Ok(LalgrsVector::new(times));
+        }
+        let k = others.len();
+        let system = LalgrsMatrix::from_fn(k, k, |i, j| {
+            let identity = if i == j { 1.0 } else { 0.0 };
+            identity - self.transition[(others[i], others[j])]
+        });
+        let solution = system.solve(&LalgrsVector::new(vec![1.0; k]))?;
+        for (state, time) in others.iter().zip(solution.iter()) {
+            times[*state] = *time;
+        }
+        Ok(LalgrsVector::new(times))
+    }
+
+    /// ## Random path of `steps` steps from `start`, including the start
+    /// The same seed always gives the same path.
+    /// If `start` is not a state of the chain, returns an error
+    pub fn simulate(
+        &self,
+        start: usize,
+        steps: usize,
+        rng: &mut Rng,
+    ) -> Result<Vec<usize>, LalgrsError> {
+        self.check_state(start)?;
+        let n = self.states();
+        let mut path = Vec::with_capacity(steps + 1);
+        path.push(start);
+        let mut state = start;
+        for _ in 0..steps {
+            let sample = rng.next_f64();
+            let mut cumulative = 0.0;
+            // Rounding can leave the row sum just below the sample, so the last possible state catches the rest
+            let mut next = (0..n)
+                .rev()
+                .find(|j| self.transition[(state, *j)] > 0.0)
+                .unwrap_or(state);
+            for j in 0..n {
+                cumulative += self.transition[(state, j)];
+                if sample < cumulative {
+                    next = j;
+                    break;
+                }
+            }
+            state = next;
+            path.push(state);
+        }
+        Ok(path)
+    }
+}
@@ -0,0 +1,385 @@
+use crate::{
+    decomposition::check_square, schur::Schur, LalgrsError, LalgrsMatrix, LalgrsVector, Shape,
+};
+
+/// Maximum number of doubling steps for the discrete algebraic Riccati equation
+const MAX_DOUBLING_STEPS: usize = 100;
+
+/// Relative change between doubling steps below which the iteration has converged
+const DOUBLING_TOLERANCE: f64 = 1e-12;
+
+/// ## Returns an error if the matrix does not have the given shape
+fn check_shape(matrix: &LalgrsMatrix<f64>, rows: usize, columns: usize) -> Result<(), LalgrsError> {
+    if matrix.shape() != Shape::new(rows, columns) {
+        return Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(rows, columns),
+            rhs: matrix.shape(),
+        });
+    }
+    Ok(())
+}
+
+/// ## Matrix product of references, for matrices whose shapes are known to match
+fn product(
+    lhs: &LalgrsMatrix<f64>,
+    rhs: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    lhs.clone() * rhs.clone()
+}
+
+/// ## Symmetric part `(X + Xᵀ) / 2`, which removes the asymmetry left by rounding in symmetric solutions
+fn symmetric_part(x: &LalgrsMatrix<f64>) -> LalgrsMatrix<f64> {
+    LalgrsMatrix::from_fn(x.rows(), x.columns(), |i, j| (x[(i, j)] + x[(j, i)]) / 2.0)
+}
+
+/// ## Diagonal block of rows and columns `start..start + size`
+fn diagonal_block(matrix: &LalgrsMatrix<f64>, start: usize, size: usize) -> LalgrsMatrix<f64> {
+    LalgrsMatrix::from_fn(size, size, |i, j| matrix[(start + i, start + j)])
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Small Kronecker systems
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Solves `system * vec(X) = vec(C)`, where `vec` stacks the columns of a matrix
+fn solve_vectorised(
+    system: LalgrsMatrix<f64>,
+    c: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    let rows = c.rows();
+    let rhs: Vec<f64> = (0..c.rows() * c.columns())
+        .map(|k| c[(k % rows, k / rows)])
+        .collect();
+    let x: Vec<f64> = system
+        .solve(&LalgrsVector::new(rhs))?
+        .iter()
+        .copied()
+        .collect();
+    Ok(LalgrsMatrix::from_fn(rows, c.columns(), |i, j| {
+        x[i + rows * j]
+    }))
+}
+
+/// ## Solves `A * X + X * B = C` for blocks of at most a few rows, as `(I ⊗ A + Bᵀ ⊗ I) * vec(X) = vec(C)`
+/// If `A` and `-B` share an eigenvalue, returns an error
+pub(crate) fn solve_small_sylvester(
+    a: &LalgrsMatrix<f64>,
+    b: &LalgrsMatrix<f64>,
+    c: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    let (m, n) = (a.rows(), b.rows());
+    let system = (LalgrsMatrix::identity(n).kronecker(a)
+        + b.transpose().kronecker(&LalgrsMatrix::identity(m)))?;
+    solve_vectorised(system, c)
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Sylvester and Lyapunov equations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Solves `R * Y + Y * S = F` for the quasi-triangular factors of two real Schur forms
+/// Blocks of `Y` are found one at a time, down the block columns and up the block rows, as in LAPACK's `trsyl`.
+fn solve_quasi_triangular_sylvester(
+    r: &Schur,
+    s: &Schur,
+    f: LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    let (r_blocks, s_blocks) = (r.blocks(), s.blocks());
+    let m = r.t.rows();
+    let mut y = f;
+    for &(l, q) in &s_blocks {
+        for &(k, p) in r_blocks.iter().rev() {
+            // F_kl - Σ_{i>k} R_ki * Y_il - Σ_{j<l} Y_kj * S_jl, with F_kl still in place of Y_kl
+            let rhs = LalgrsMatrix::from_fn(p, q, |i, j| {
+                let below: f64 = (k + p..m).map(|t| r.t[(k + i, t)] * y[(t, l + j)]).sum();
+                let left: f64 = (0..l).map(|t| y[(k + i, t)] * s.t[(t, l + j)]).sum();
+                y[(k + i, l + j)] - below - left
+            });
+            let block = solve_small_sylvester(
+                &diagonal_block(&r.t, k, p),
+                &diagonal_block(&s.t, l, q),
+                &rhs,
+            )?;
+            for i in 0..p {
+                for j in 0..q {
+                    y[(k + i, l + j)] = block[(i, j)];
+                }
+            }
+        }
+    }
+    Ok(y)
+}
+
+/// ## Solves the Sylvester equation `A * X + X * B = C` with the Bartels-Stewart algorithm
+/// `A` is `m x m`, `B` is `n x n` and `C` is `m x n`. With the real Schur forms `A = U * R * Uᵀ` and `B = V * S * Vᵀ`,
+/// the quasi-triangular equation `R * Y + Y * S = Uᵀ * C * V` is solved by substitution, and `X = U * Y * Vᵀ`.
+/// The solution is unique when `A` and `-B` have no common eigenvalue.
+/// If `A` or `B` is empty or not square, `C` does not have the shape of `X`, or `A` and `-B` share an eigenvalue, returns an error
+pub fn solve_sylvester(
+    a: &LalgrsMatrix<f64>,
+    b: &LalgrsMatrix<f64>,
+    c: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    check_square(a)?;
+    check_square(b)?;
+    check_shape(c, a.rows(), b.rows())?;
+    let (r, s) = (a.schur()?, b.schur()?);
+    let f = product(&product(&r.q.transpose(), c)?, &s.q)?;
+    let y = solve_quasi_triangular_sylvester(&r, &s, f)?;
+    product(&product(&r.q, &y)?, &s.q.transpose())
+}
+
+/// ## Solves the continuous Lyapunov equation `A * X + X * Aᵀ + Q = 0`
+/// The solution is unique when no two eigenvalues of `A` sum to zero.
+/// When `A` is stable and `Q` is positive semidefinite, `X` is the positive semidefinite Gramian `∫ e^(At) * Q * e^(Aᵀt) dt`.
+/// A symmetric `Q` gives an exactly symmetric `X`.
+/// If `A` is empty or not square, `Q` does not have the shape of `A`, or the solution is not unique, returns an error
+pub fn solve_continuous_lyapunov(
+    a: &LalgrsMatrix<f64>,
+    q: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    check_square(a)?;
+    check_shape(q, a.rows(), a.rows())?;
+    let x = solve_sylvester(a, &a.transpose(), &-q.clone())?;
+    Ok(if *q == q.transpose() {
+        symmetric_part(&x)
+    } else {
+        x
+    })
+}
+
+/// ## Solves the discrete Lyapunov, or Stein, equation `A * X * Aᵀ - X + Q = 0`
+/// With the real Schur form `A = U * R * Uᵀ`, the blocks of `Y = Uᵀ * X * U` are found by substitution
+/// from the bottom right, keeping the partial products `Y * Rᵀ` so that the whole solve costs `O(n³)`.
+/// The solution is unique when no product of two eigenvalues of `A` is one.
+/// When the eigenvalues of `A` are inside the unit circle and `Q` is positive semidefinite, `X = Σ Aᵏ * Q * (Aᵀ)ᵏ`.
+/// A symmetric `Q` gives an exactly symmetric `X`.
+/// If `A` is empty or not square, `Q` does not have the shape of `A`, or the solution is not unique, returns an error
+pub fn solve_discrete_lyapunov(
+    a: &LalgrsMatrix<f64>,
+    q: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    check_square(a)?;
+    let n = a.rows();
+    check_shape(q, n, n)?;
+    let schur = a.schur()?;
+    let r = &schur.t;
+    let blocks = schur.blocks();
+    let mut y = product(&product(&schur.q.transpose(), q)?, &schur.q)?;
+
+    // Block (k, l) of R * Y * Rᵀ is Σ_{i≥k} R_ki * Z_i, with Z = Σ_{j≥l} Y_:j * R_ljᵀ
+    for &(l, width) in blocks.iter().rev() {
+        let mut z = LalgrsMatrix::from_fn(n, width, |i, c| {
+            (l + width..n)
+                .map(|t| y[(i, t)] * r[(l + c, t)])
+                .sum::<f64>()
+        });
+        let r_ll = diagonal_block(r, l, width);
+        for &(k, height) in blocks.iter().rev() {
+            // R_kk * Y_kl * R_llᵀ - Y_kl = -F_kl - Σ_{i≥k} R_ki * Z_i, where Z_k does not include Y_kl yet
+            let rhs = LalgrsMatrix::from_fn(height, width, |i, c| {
+                let known: f64 = (k..n).map(|t| r[(k + i, t)] * z[(t, c)]).sum();
+                -y[(k + i, l + c)] - known
+            });
+            let system = (r_ll.kronecker(&diagonal_block(r, k, height))
+                + -LalgrsMatrix::identity(height * width))?;
+            let block = solve_vectorised(system, &rhs)?;
+            for i in 0..height {
+                for c in 0..width {
+                    y[(k + i, l + c)] = block[(i, c)];
+                    z[(k + i, c)] += (0..width)
+                        .map(|t| block[(i, t)] * r[(l + c, l + t)])
+                        .sum::<f64>();
+                }
+            }
+        }
+    }
+    let x = product(&product(&schur.q, &y)?, &schur.q.transpose())?;
+    Ok(if *q == q.transpose() {
+        symmetric_part(&x)
+    } else {
+        x
+    })
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Algebraic Riccati equations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Checks the shapes of the Riccati coefficients and returns `G = B * R⁻¹ * Bᵀ`
+/// If `A` is empty or not square, `B`, `Q` or `R` has the wrong shape, or `R` is not positive definite, returns an error
+fn riccati_gain_term(
+    a: &LalgrsMatrix<f64>,
+    b: &LalgrsMatrix<f64>,
+    q: &LalgrsMatrix<f64>,
+    r: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    check_square(a)?;
+    let (n, m) = (a.rows(), b.columns());
+    check_shape(b, n, m)?;
+    check_shape(q, n, n)?;
+    check_shape(r, m, m)?;
+    product(b, &r.cholesky()?.solve_matrix(&b.transpose())?)
+}
+
+/// ## Stabilising solution of the continuous algebraic Riccati equation `Aᵀ * X + X * A - X * B * R⁻¹ * Bᵀ * X + Q = 0`
+/// `A` is `n x n`, `B` is `n x m`, `Q` is symmetric `n x n` and `R` is symmetric positive definite `m x m`.
+/// The stable invariant subspace `[U₁; U₂]` of the Hamiltonian matrix `[[A, -B * R⁻¹ * Bᵀ], [-Q, -Aᵀ]]`
+/// is found with a reordered real Schur form, and `X = U₂ * U₁⁻¹`.
+/// The LQR gain is then `K = R⁻¹ * Bᵀ * X`, and `A - B * K` is stable.
+/// If the shapes do not match, `R` is not positive definite, or there is no stabilising solution, returns an error
+pub fn solve_continuous_riccati(
+    a: &LalgrsMatrix<f64>,
+    b: &LalgrsMatrix<f64>,
+    q: &LalgrsMatrix<f64>,
+    r: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    let g = riccati_gain_term(a, b, q, r)?;
+    let n = a.rows();
+    let hamiltonian = LalgrsMatrix::from_fn(2 * n, 2 * n, |i, j| match (i < n, j < n) {
+        (true, true) => a[(i, j)],
+        (true, false) => -g[(i, j - n)],
+        (false, true) => -q[(i - n, j)],
+        (false, false) => -a[(j - n, i - n)],
+    });
+    let mut schur = hamiltonian.schur()?;
+    // Eigenvalues on the imaginary axis leave fewer than n strictly stable ones
+    if schur.reorder(|z| z.re < 0.0)? != n {
+        return Err(LalgrsError::NoStabilisingSolution);
+    }
+    // A nearly singular U₁, from a mode that B can not reach, gives a huge X that does not stabilise A - G * X
+    let x = stabilising_solution(&schur.q, n)?;
+    let closed_loop = (a.clone() + -product(&g, &x)?)?;
+    if !closed_loop.eigenvalues()?.iter().all(|z| z.re < 0.0) {
+        return Err(LalgrsError::NoStabilisingSolution);
+    }
+    Ok(x)
+}
+
+/// ## `X = U₂ * U₁⁻¹` from the first `n` columns `[U₁; U₂]` of `Q`, made symmetric
+/// If `U₁` is singular, returns an error
+fn stabilising_solution(q: &LalgrsMatrix<f64>, n: usize) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    // X * U₁ = U₂, so U₁ᵀ * Xᵀ = U₂ᵀ
+    let u1_t = LalgrsMatrix::from_fn(n, n, |i, j| q[(j, i)]);
+    let u2_t = LalgrsMatrix::from_fn(n, n, |i, j| q[(n + j, i)]);
+    let x_t = u1_t
+        .lu()?
+        .solve_matrix(&u2_t)
+        .map_err(|error| match error {
+            LalgrsError::SingularMatrix => LalgrsError::NoStabilisingSolution,
+            error => error,
+        })?;
+    Ok(symmetric_part(&x_t))
+}
+
+/// ## Stabilising solution of the discrete algebraic Riccati equation
+/// `Aᵀ * X * A - X - Aᵀ * X * B * (R + Bᵀ * X * B)⁻¹ * Bᵀ * X * A + Q = 0`
+/// `A` is `n x n`, `B` is `n x m`, `Q` is symmetric `n x n` and `R` is symmetric positive definite `m x m`.
+/// Computed with the structure preserving doubling algorithm of Chu, Fan and Lin, which converges quadratically
+/// and, unlike the eigenvectors of the symplectic matrix, does not need `A` to be invertible.
+/// The doubling iteration only finds the stabilising solution when `(A, Q)` is detectable,
+/// so its result is checked, and an invertible `A` falls back to the reordered Schur form of the symplectic matrix.
+/// The LQR gain is then `K = (R + Bᵀ * X * B)⁻¹ * Bᵀ * X * A`, and the eigenvalues of `A - B * K` are inside the unit circle.
+/// If the shapes do not match, `R` is not positive definite, or no stabilising solution is found, returns an error
+pub fn solve_discrete_riccati(
+    a: &LalgrsMatrix<f64>,
+    b: &LalgrsMatrix<f64>,
+    q: &LalgrsMatrix<f64>,
+    r: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    let g = riccati_gain_term(a, b, q, r)?;
+    let doubling = doubling_iteration(a, &g, q);
+    if let Ok(x) = &doubling {
+        if is_discrete_stabilising(a, b, r, x)? {
+            return Ok(x.clone());
+        }
+    }
+    let Ok(a_inverse) = a.inverse() else {
+        return Err(match doubling {
+            Err(error @ LalgrsError::NoConvergence { .. }) => error,
+            _ => LalgrsError::NoStabilisingSolution,
+        });
+    };
+    let x = symplectic_riccati(&a_inverse, a, &g, q)?;
+    if !is_discrete_stabilising(a, b, r, &x)? {
+        return Err(LalgrsError::NoStabilisingSolution);
+    }
+    Ok(x)
+}
+
+/// ## Limit of the doubling iteration, started from `A₀ = A`, `G₀ = B * R⁻¹ * Bᵀ` and `H₀ = Q`
+/// If an iterate is singular or not finite, or the iteration does not converge, returns an error
+fn doubling_iteration(
+    a: &LalgrsMatrix<f64>,
+    g: &LalgrsMatrix<f64>,
+    q: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    let (mut a, mut g, mut h) = (a.clone(), g.clone(), q.clone());
+    let identity = LalgrsMatrix::identity(a.rows());
+    for _ in 0..MAX_DOUBLING_STEPS {
+        // With W = I + G * H: A ← A * W⁻¹ * A, G ← G + A * W⁻¹ * G * Aᵀ and H ← H + Aᵀ * H * W⁻¹ * A
+        let w = (identity.clone() + product(&g, &h)?)?.lu()?;
+        let (w_a, w_g) = (w.solve_matrix(&a)?, w.solve_matrix(&g)?);
+        let next_h = (h.clone() + product(&product(&a.transpose(), &h)?, &w_a)?)?;
+        g = (g.clone() + product(&product(&a, &w_g)?, &a.transpose())?)?;
+        a = product(&a, &w_a)?;
+        let change = (next_h.clone() + -h)?.frobenius_norm();
+        h = next_h;
+        if !change.is_finite() {
+            return Err(LalgrsError::NoStabilisingSolution);
+        }
+        if change <= DOUBLING_TOLERANCE * h.frobenius_norm() {
+            return Ok(symmetric_part(&h));
+        }
+    }
+    Err(LalgrsError::NoConvergence {
+        iterations: MAX_DOUBLING_STEPS,
+    })
+}
+
+/// ## Whether the eigenvalues of `A - B * K`, with `K = (R + Bᵀ * X * B)⁻¹ * Bᵀ * X * A`, are inside the unit circle
+fn is_discrete_stabilising(
+    a: &LalgrsMatrix<f64>,
+    b: &LalgrsMatrix<f64>,
+    r: &LalgrsMatrix<f64>,
+    x: &LalgrsMatrix<f64>,
+) -> Result<bool, LalgrsError> {
+    let b_t_x = product(&b.transpose(), x)?;
+    let gain = match (r.clone() + product(&b_t_x, b)?)?
+        .lu()?
+        .solve_matrix(&product(&b_t_x, a)?)
+    {
+        Ok(gain) => gain,
+        Err(LalgrsError::SingularMatrix) => return Ok(false),
+        Err(error) => return Err(error),
+    };
+    let closed_loop = (a.clone() + -product(b, &gain)?)?;
+    Ok(closed_loop.eigenvalues()?.iter().all(|z| z.abs() < 1.0))
+}
+
+/// ## Stabilising solution from the invariant subspace of the symplectic matrix
+/// `[[A + G * A⁻ᵀ * Q, -G * A⁻ᵀ], [-A⁻ᵀ * Q, A⁻ᵀ]]` for the eigenvalues inside the unit circle, with `X = U₂ * U₁⁻¹`.
+/// If eigenvalues on the unit circle leave fewer than `n` inside it, or `U₁` is singular, returns an error
+fn symplectic_riccati(
+    a_inverse: &LalgrsMatrix<f64>,
+    a: &LalgrsMatrix<f64>,
+    g: &LalgrsMatrix<f64>,
+    q: &LalgrsMatrix<f64>,
+) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+    let n = a.rows();
+    let a_inverse_t = a_inverse.transpose();
+    let g_a_inverse_t = product(g, &a_inverse_t)?;
+    let a_inverse_t_q = product(&a_inverse_t, q)?;
+    let top_left = (a.clone() + product(&g_a_inverse_t, q)?)?;
+    let symplectic = LalgrsMatrix::from_fn(2 * n, 2 * n, |i, j| match (i < n, j < n) {
+        (true, true) => top_left[(i, j)],
+        (true, false) => -g_a_inverse_t[(i, j - n)],
+        (false, true) => -a_inverse_t_q[(i - n, j)],
+        (false, false) => a_inverse_t[(i - n, j - n)],
+    });
+    let mut schur = symplectic.schur()?;
+    if schur.reorder(|z| z.abs() < 1.0)? != n {
+        return Err(LalgrsError::NoStabilisingSolution);
+    }
+    stabilising_solution(&schur.q, n)
+}
@@ -0,0 +1,88 @@
+use crate::{scalar::Field, LalgrsError, LalgrsMatrix};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Matrix norms
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Field> LalgrsMatrix<T> {
+    /// ## Frobenius norm, the square root of the sum of the squared magnitudes of the elements
+    /// The norm of an empty matrix is zero
+    pub fn frobenius_norm(&self) -> f64 {
+        // Scaled by the largest magnitude to avoid overflow and underflow in the squares
+        let scale = self
+            .as_column_major_slice()
+            .iter()
+            .map(|v| v.magnitude())
+            .fold(0.0, f64::max);
+        if scale == 0.0 || !scale.is_finite() {
+            return scale;
+        }
+        let sum: f64 = self
+            .as_column_major_slice()
+            .iter()
+            .map(|v| (v.magnitude() / scale).powi(2))
+            .sum();
+        scale * sum.sqrt()
+    }
+
+    /// ## 1-norm, the largest sum of magnitudes over the columns
+    /// The norm of an empty matrix is zero
+    pub fn one_norm(&self) -> f64 {
+        self.as_column_major_slice()
+            .chunks(self.rows().max(1))
+            .map(|column| column.iter().map(|v| v.magnitude()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    /// ## ∞-norm, the largest sum of magnitudes over the rows
+    /// The norm of an empty matrix is zero
+    pub fn infinity_norm(&self) -> f64 {
+        (0..self.rows())
+            .map(|i| {
+                (0..self.columns())
+                    .map(|j| self[(i, j)].magnitude())
+                    .sum::<f64>()
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Spectral norm, the largest singular value
+    /// The norm of an empty matrix is zero.
+    /// If the singular value decomposition does not converge, returns an error
+    pub fn spectral_norm(&self) -> Result<f64, LalgrsError> {
+        if self.is_empty() {
+            return Ok(0.0);
+        }
+        Ok(self
+            .svd()?
+            .singular_values
+            .values
+            .front()
+            .copied()
+            .unwrap_or(0.0))
+    }
+
+    /// ## Condition number in the spectral norm, the ratio of the largest to the smallest singular value
+    /// Rank deficient matrices have an infinite condition number.
+    /// Computed exactly with a singular value decomposition: `Lu::condition_estimate` is much cheaper
+    /// when an LU decomposition is available.
+    /// If the matrix is empty, or the singular value decomposition does not converge, returns an error
+    pub fn condition_number(&self) -> Result<f64, LalgrsError> {
+        let singular_values = self.svd()?.singular_values.values;
+        let largest = singular_values.front().copied().unwrap_or(0.0);
+        let smallest = singular_values.back().copied().unwrap_or(0.0);
+        if smallest == 0.0 {
+            return Ok(f64::INFINITY);
+        }
+        Ok(largest / smallest)
+    }
+
+    /// ## Estimate of the condition number in the 1-norm, computed with an LU decomposition
+    /// See `Lu::condition_estimate`.
+    /// If the matrix is empty or not square, returns an error
+    pub fn condition_estimate(&self) -> Result<f64, LalgrsError> {
+        Ok(self.lu()?.condition_estimate())
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use crate::{complex::Complex, scalar::Field, LalgrsError, LalgrsMatrix, LalgrsVector};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Polynomials
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Polynomial `c₀ + c₁x + c₂x² + ...` with coefficients in a field
+/// Coefficients are stored from the constant term up, without trailing zeros,
+/// so the zero polynomial has no coefficients and equal polynomials compare equal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<T: Field> {
+    coefficients: Vec<T>,
+}
+
+impl<T: Field> Polynomial<T> {
+    /// ## Polynomial with the given coefficients, from the constant term up
+    /// `Polynomial::new(vec![1.0, 0.0, 2.0])` is `1 + 2x²`
+    pub fn new(coefficients: Vec<T>) -> Polynomial<T> {
+        let mut coefficients = coefficients;
+        while coefficients.last().is_some_and(|c| *c == T::zero()) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    pub fn zero() -> Polynomial<T> {
+        Polynomial {
+            coefficients: Vec::new(),
+        }
+    }
+
+    /// ## Monic polynomial `(x - r₁)(x - r₂)...` with the given roots
+    pub fn from_roots(roots: &[T]) -> Polynomial<T> {
+        roots.iter().fold(Polynomial::new(vec![T::one()]), |p, r| {
+            p * Polynomial::new(vec![-r.clone(), T::one()])
+        })
+    }
+
+    /// ## Coefficients from the constant term up, empty for the zero polynomial
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// ## Highest power with a non zero coefficient, or `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// ## Coefficient of the highest power, zero for the zero polynomial
+    pub fn leading_coefficient(&self) -> T {
+        self.coefficients.last().cloned().unwrap_or_else(T::zero)
+    }
+
+    /// ## Value at `x`, computed with Horner's method
+    pub fn evaluate(&self, x: &T) -> T {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// ## Value at every element of `x`
+    pub fn evaluate_vector(&self, x: &LalgrsVector<T>) -> LalgrsVector<T> {
+        x.map(|x| self.evaluate(x))
+    }
+
+    /// ## Derivative
+    pub fn derivative(&self) -> Polynomial<T> {
+        let mut power = T::zero();
+        Polynomial::new(
+            self.coefficients
+                .iter()
+                .skip(1)
+                .map(|c| {
+                    power = power.clone() + T::one();
+                    power.clone() * c.clone()
+                })
+                .collect(),
+        )
+    }
+
+    /// ## Quotient and remainder of polynomial long division
+    /// The remainder has a lower degree than the divisor.
+    /// If the divisor is the zero polynomial, or an exact coefficient overflows, returns an error
+    pub fn div_rem(
+        &self,
+        divisor: &Polynomial<T>,
+    ) -> Result<(Polynomial<T>, Polynomial<T>), LalgrsError> {
+        let Some(divisor_degree) = divisor.degree() else {
+            return Err(LalgrsError::InvalidParameter {
+                name: "divisor",
+                value: 0.0,
+            });
+        };
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() <= divisor_degree {
+            return Ok((Polynomial::zero(), self.clone()));
+        }
+        let leading = divisor.leading_coefficient();
+        let mut quotient = vec![T::zero(); remainder.len() - divisor_degree];
+        for k in (0..quotient.len()).rev() {
+            // The top coefficient of the remainder is cancelled exactly, so it is dropped rather than computed
+            let top = remainder.pop().unwrap_or_else(T::zero);
+            let factor = top
+                .checked_div(&leading)
+                .ok_or(LalgrsError::ArithmeticOverflow)?;
+            for (j, d) in divisor.coefficients[..divisor_degree].iter().enumerate() {
+                remainder[k + j] = factor
+                    .checked_mul(d)
+                    .and_then(|product| remainder[k + j].checked_sub(&product))
+                    .ok_or(LalgrsError::ArithmeticOverflow)?;
+            }
+            quotient[k] = factor;
+        }
+        Ok((Polynomial::new(quotient), Polynomial::new(remainder)))
+    }
+}
+
+impl<T: Field> Add for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Polynomial<T>) -> Self::Output {
+        let (mut long, short) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self.coefficients, rhs.coefficients)
+        } else {
+            (rhs.coefficients, self.coefficients)
+        };
+        for (a, b) in long.iter_mut().zip(short) {
+            *a = a.clone() + b;
+        }
+        Polynomial::new(long)
+    }
+}
+
+impl<T: Field> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Self::Output {
+        Polynomial {
+            coefficients: self.coefficients.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<T: Field> Sub for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Polynomial<T>) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: Field> Mul for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: Polynomial<T>) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut product = vec![T::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Polynomial::new(product)
+    }
+}
+
+/// ## Formats from the highest power down, as `2x^3 - x + 0.5`
+impl<T: Field> Display for Polynomial<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (power, c) in self.coefficients.iter().enumerate().rev() {
+            if *c == T::zero() {
+                continue;
+            }
+            let negative = *c < T::zero();
+            let magnitude = if negative { -c.clone() } else { c.clone() };
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+            if power == 0 || magnitude != T::one() {
+                write!(f, "{magnitude}")?;
+            }
+            match power {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{power}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Field> LalgrsMatrix<T> {
+    /// ## Vandermonde matrix with element `(i, j)` equal to `x_i^j`
+    /// Its product with the coefficients of a polynomial, from the constant term up, gives the values at the points `x`
+    pub fn vandermonde(x: &LalgrsVector<T>, columns: usize) -> LalgrsMatrix<T> {
+        let points: Vec<&T> = x.iter().collect();
+        let mut vandermonde = LalgrsMatrix::from_element(points.len(), columns, T::one());
+        for j in 1..columns {
+            for (i, x) in points.iter().enumerate() {
+                vandermonde[(i, j)] = vandermonde[(i, j - 1)].clone() * (*x).clone();
+            }
+        }
+        vandermonde
+    }
+}
+
+impl Polynomial<f64> {
+    /// ## Value at a complex `z`, computed with Horner's method
+    pub fn evaluate_complex(&self, z: Complex) -> Complex {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Complex::default(), |acc, c| acc * z + Complex::from(*c))
+    }
+
+    /// ## Least squares fit of a polynomial of the given degree to the points `(x_i, y_i)`
+    /// Minimises `Σ (p(x_i) - y_i)²` with the pseudo-inverse of the Vandermonde matrix,
+    /// after scaling its columns to unit norm to improve its conditioning.
+    /// If `x` and `y` have different sizes, or there are fewer than `degree + 1` points, returns an error
+    pub fn fit(
+        x: &LalgrsVector<f64>,
+        y: &LalgrsVector<f64>,
+        degree: usize,
+    ) -> Result<Polynomial<f64>, LalgrsError> {
+        if x.size() != y.size() {
+            return Err(LalgrsError::MismatchedVectorDimensions {
+                vector1: x.size(),
+                vector2: y.size(),
+            });
+        }
+        if x.size() <= degree {
+            return Err(LalgrsError::InvalidParameter {
+                name: "degree",
+                value: degree as f64,
+            });
+        }
+        let mut vandermonde = LalgrsMatrix::vandermonde(x, degree + 1);
+        let scales: Vec<f64> = (0..=degree)
+            .map(|j| {
+                let norm = (0..x.size())
+                    .map(|i| vandermonde[(i, j)].powi(2))
+                    .sum::<f64>()
+                    .sqrt();
+                if norm > 0.0 {
+                    norm
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+        for (j, scale) in scales.iter().enumerate() {
+            for i in 0..x.size() {
+                vandermonde[(i, j)] /= scale;
+            }
+        }
+        let coefficients = (vandermonde.pseudo_inverse(None)? * y.clone())?;
+        Ok(Polynomial::new(
+            coefficients
+                .iter()
+                .zip(&scales)
+                .map(|(c, scale)| c / scale)
+                .collect(),
+        ))
+    }
+
+    /// ## Complex roots, repeated according to their multiplicity
+    /// Computed as the eigenvalues of the companion matrix, as NumPy's `roots` does.
+    /// Zero roots, from vanishing low order coefficients, are exact. Constants, including zero, have no roots.
+    /// If the eigenvalue iteration does not converge, returns an error
+    pub fn roots(&self) -> Result<Vec<Complex>, LalgrsError> {
+        let zeros = self.coefficients.iter().take_while(|c| **c == 0.0).count();
+        let mut roots = vec![Complex::default(); zeros];
+        let coefficients = &self.coefficients[zeros.min(self.coefficients.len())..];
+        let n = coefficients.len().saturating_sub(1);
+        if n == 0 {
+            return Ok(roots);
+        }
+        // x^n = -(c₀ + c₁x + ... + c_{n-1}x^{n-1}) / c_n, with ones below the diagonal
+        let leading = coefficients[n];
+        let companion = LalgrsMatrix::from_fn(n, n, |i, j| {
+            if j == n - 1 {
+                -coefficients[i] / leading
+            } else if i == j + 1 {
+                1.0
+            } else {
+                0.0
+            }
+        });
+        roots.extend(companion.eigenvalues()?);
+        Ok(roots)
+    }
+}
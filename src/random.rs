@@ -0,0 +1,142 @@
+use crate::LalgrsMatrix;
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Pseudo random number generator
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Seeded pseudo random number generator
+/// Implements xoshiro256**, with the state expanded from a single `u64` seed through splitmix64.
+/// The same seed always produces the same sequence, on every platform.
+/// Not suitable for cryptographic use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rng {
+    state: [u64; 4],
+    spare_normal: Option<f64>,
+}
+
+impl Rng {
+    pub fn seed_from_u64(seed: u64) -> Rng {
+        let mut splitmix_state = seed;
+        let mut splitmix = || {
+            splitmix_state = splitmix_state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = splitmix_state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        Rng {
+            state: [splitmix(), splitmix(), splitmix(), splitmix()],
+            spare_normal: None,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    /// ## Uniformly distributed value in `[0, 1)`
+    pub fn next_f64(&mut self) -> f64 {
+        // The 53 most significant bits fill the mantissa exactly
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// ## Uniformly distributed value in `[low, high)`
+    pub fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.next_f64()
+    }
+
+    /// ## Standard normally distributed value
+    /// Uses the Box-Muller transform, keeping the second generated value for the next call
+    pub fn normal(&mut self) -> f64 {
+        if let Some(value) = self.spare_normal.take() {
+            return value;
+        }
+
+        // 1 - next_f64() lies in (0, 1], so the logarithm is always finite
+        let radius = (-2.0 * (1.0 - self.next_f64()).ln()).sqrt();
+        let angle = 2.0 * std::f64::consts::PI * self.next_f64();
+        self.spare_normal = Some(radius * angle.sin());
+        radius * angle.cos()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Random matrices
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl LalgrsMatrix<f64> {
+    /// ## Matrix with elements uniformly distributed in `[low, high)`
+    pub fn random_uniform(
+        rows: usize,
+        columns: usize,
+        low: f64,
+        high: f64,
+        rng: &mut Rng,
+    ) -> LalgrsMatrix<f64> {
+        LalgrsMatrix::from_fn(rows, columns, |_, _| rng.uniform(low, high))
+    }
+
+    /// ## Matrix with normally distributed elements
+    pub fn random_normal(
+        rows: usize,
+        columns: usize,
+        mean: f64,
+        std_dev: f64,
+        rng: &mut Rng,
+    ) -> LalgrsMatrix<f64> {
+        LalgrsMatrix::from_fn(rows, columns, |_, _| mean + std_dev * rng.normal())
+    }
+
+    /// ## Random `n` by `n` orthogonal matrix
+    /// Orthonormalises the columns of a standard normal matrix with modified Gram-Schmidt,
+    /// which gives a matrix distributed uniformly (Haar) over the orthogonal group
+    pub fn random_orthogonal(n: usize, rng: &mut Rng) -> LalgrsMatrix<f64> {
+        let mut columns: Vec<Vec<f64>> = (0..n)
+            .map(|_| (0..n).map(|_| rng.normal()).collect())
+            .collect();
+
+        for j in 0..n {
+            let (previous, current) = columns.split_at_mut(j);
+            for basis in previous.iter() {
+                let projection: f64 = basis
+                    .iter()
+                    .zip(current[0].iter())
+                    .map(|(b, c)| b * c)
+                    .sum();
+                for (c, b) in current[0].iter_mut().zip(basis.iter()) {
+                    *c -= projection * b;
+                }
+            }
+            let norm = columns[j].iter().map(|x| x * x).sum::<f64>().sqrt();
+            for value in columns[j].iter_mut() {
+                *value /= norm;
+            }
+        }
+
+        LalgrsMatrix::from_fn(n, n, |i, j| columns[j][i])
+    }
+
+    /// ## Random `n` by `n` symmetric positive definite matrix
+    /// Computes `A * A^T + n * I` for a standard normal matrix `A`
+    pub fn random_spd(n: usize, rng: &mut Rng) -> LalgrsMatrix<f64> {
+        let a = LalgrsMatrix::random_normal(n, n, 0.0, 1.0, rng);
+        LalgrsMatrix::from_fn(n, n, |i, j| {
+            let product: f64 = (0..n).map(|k| a[(i, k)] * a[(j, k)]).sum();
+            if i == j {
+                product + n as f64
+            } else {
+                product
+            }
+        })
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    scalar::{Field, One, Zero},
+    LalgrsError,
+};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Rational numbers
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Exact fraction of two `i64`
+/// Always stored in lowest terms with a positive denominator, so equal fractions compare equal.
+/// Like the primitive integers, the arithmetic operators panic on overflow and on division by zero;
+/// use the `checked_` methods to handle these cases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+impl Rational {
+    /// ## Fraction `numerator / denominator`, reduced to lowest terms
+    /// If the denominator is zero, or the reduced fraction does not fit in `i64`, returns an error
+    pub fn new(numerator: i64, denominator: i64) -> Result<Rational, LalgrsError> {
+        if denominator == 0 {
+            return Err(LalgrsError::InvalidParameter {
+                name: "denominator",
+                value: 0.0,
+            });
+        }
+        Rational::reduce(numerator as i128, denominator as i128)
+            .ok_or(LalgrsError::ArithmeticOverflow)
+    }
+
+    /// ## Reduces a fraction of wide integers
+    /// Returns `None` if the denominator is zero or the reduced fraction does not fit in `i64`
+    fn reduce(numerator: i128, denominator: i128) -> Option<Rational> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Some(Rational {
+            numerator: (numerator / divisor).try_into().ok()?,
+            denominator: (denominator / divisor).try_into().ok()?,
+        })
+    }
+
+    pub fn from_integer(value: i64) -> Rational {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// ## Denominator, always positive
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn abs(&self) -> Rational {
+        Rational {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator,
+        }
+    }
+
+    /// ## Multiplicative inverse
+    /// Returns `None` for zero
+    pub fn recip(&self) -> Option<Rational> {
+        Rational::reduce(self.denominator as i128, self.numerator as i128)
+    }
+
+    /// ## Closest `f64` to the fraction
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// ## Sum, or `None` on overflow
+    pub fn checked_add(&self, rhs: &Rational) -> Option<Rational> {
+        let (a, b) = (self.numerator as i128, self.denominator as i128);
+        let (c, d) = (rhs.numerator as i128, rhs.denominator as i128);
+        Rational::reduce(a * d + c * b, b * d)
+    }
+
+    /// ## Difference, or `None` on overflow
+    pub fn checked_sub(&self, rhs: &Rational) -> Option<Rational> {
+        let (a, b) = (self.numerator as i128, self.denominator as i128);
+        let (c, d) = (rhs.numerator as i128, rhs.denominator as i128);
+        Rational::reduce(a * d - c * b, b * d)
+    }
+
+    /// ## Product, or `None` on overflow
+    pub fn checked_mul(&self, rhs: &Rational) -> Option<Rational> {
+        Rational::reduce(
+            self.numerator as i128 * rhs.numerator as i128,
+            self.denominator as i128 * rhs.denominator as i128,
+        )
+    }
+
+    /// ## Quotient, or `None` on overflow or division by zero
+    pub fn checked_div(&self, rhs: &Rational) -> Option<Rational> {
+        self.checked_mul(&rhs.recip()?)
+    }
+
+    /// ## Negation, or `None` on overflow
+    pub fn checked_neg(&self) -> Option<Rational> {
+        Some(Rational {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::from_integer(value)
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Self {
+        Rational::from_integer(value as i64)
+    }
+}
+
+/// ## Parses `"3/4"`, `"-2/6"` or an integer such as `"5"`
+impl FromStr for Rational {
+    type Err = LalgrsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || LalgrsError::InvalidRational(s.to_string());
+        let (numerator, denominator) = match s.trim().split_once('/') {
+            Some((n, d)) => (n.trim(), d.trim()),
+            None => (s.trim(), "1"),
+        };
+        Rational::new(
+            numerator.parse().map_err(|_| invalid())?,
+            denominator.parse().map_err(|_| invalid())?,
+        )
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross multiplying keeps the order
+        (self.numerator as i128 * other.denominator as i128)
+            .cmp(&(other.numerator as i128 * self.denominator as i128))
+    }
+}
+
+impl Zero for Rational {
+    fn zero() -> Self {
+        Rational::from_integer(0)
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Rational::from_integer(1)
+    }
+}
+
+impl Field for Rational {
+    const EXACT: bool = true;
+
+    fn magnitude(&self) -> f64 {
+        self.to_f64().abs()
+    }
+
+    fn tolerance(_scale: f64, _size: usize) -> f64 {
+        0.0
+    }
+
+    fn to_latex(&self) -> String {
+        match (self.is_integer(), self.numerator < 0) {
+            (true, _) => self.numerator.to_string(),
+            (false, false) => format!("\\frac{{{}}}{{{}}}", self.numerator, self.denominator),
+            (false, true) => format!(
+                "-\\frac{{{}}}{{{}}}",
+                self.numerator.unsigned_abs(),
+                self.denominator
+            ),
+        }
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Rational::checked_add(self, rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Rational::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Rational::checked_mul(self, rhs)
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        Rational::checked_div(self, rhs)
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        Rational::checked_neg(self)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Rational Operations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Reports an overflow in an arithmetic operator
+/// The operators can not return an error, so like the primitive integers they panic;
+/// this is the one panic the crate allows outside of indexing, see the lint attributes in `lib.rs`
+#[allow(clippy::panic)]
+fn overflow(message: &str) -> ! {
+    panic!("{message}")
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    /// ## Panics on overflow, like the primitive integers
+    fn neg(self) -> Self::Output {
+        self.checked_neg()
+            .unwrap_or_else(|| overflow("attempt to negate with overflow"))
+    }
+}
+
+macro_rules! impl_rational_operator {
+    ($trait:ident, $method:ident, $checked:ident, $message:literal) => {
+        impl $trait for Rational {
+            type Output = Rational;
+
+            /// ## Panics on overflow, like the primitive integers
+            fn $method(self, rhs: Rational) -> Self::Output {
+                self.$checked(&rhs).unwrap_or_else(|| overflow($message))
+            }
+        }
+    };
+}
+
+impl_rational_operator!(Add, add, checked_add, "attempt to add with overflow");
+impl_rational_operator!(Sub, sub, checked_sub, "attempt to subtract with overflow");
+impl_rational_operator!(Mul, mul, checked_mul, "attempt to multiply with overflow");
+impl_rational_operator!(
+    Div,
+    div,
+    checked_div,
+    "attempt to divide by zero or with overflow"
+);
@@ -0,0 +1,117 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Numeric traits
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Additive identity
+/// Implemented for all primitive numeric types. Used by constructors such as `LalgrsMatrix::zeros`
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+/// ## Multiplicative identity
+/// Implemented for all primitive numeric types. Used by constructors such as `LalgrsMatrix::identity`
+pub trait One {
+    fn one() -> Self;
+}
+
+macro_rules! impl_zero_one {
+    ($zero:literal, $one:literal, $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_one!(0, 1, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_zero_one!(0.0, 1.0, f32, f64);
+
+/// ## Scalars with exact or approximate division, as needed by Gaussian elimination
+/// Implemented for `f32`, `f64` and `Rational`
+pub trait Field:
+    Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + Div<Self, Output = Self>
+    + Neg<Output = Self>
+    + Clone
+    + PartialOrd
+    + Display
+    + Zero
+    + One
+{
+    /// Exact fields pick the first non zero pivot, approximate ones the largest pivot in magnitude
+    const EXACT: bool;
+
+    /// Magnitude used to compare pivots
+    fn magnitude(&self) -> f64;
+
+    /// ## Magnitude below which values are treated as zero
+    /// `scale` is the largest magnitude in the matrix and `size` its largest dimension
+    fn tolerance(scale: f64, size: usize) -> f64;
+
+    /// ## The value formatted as LaTeX
+    fn to_latex(&self) -> String {
+        self.to_string()
+    }
+
+    /// ## Sum, or `None` if it can not be represented
+    /// Floating point operations always succeed, overflowing to infinity
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() + rhs.clone())
+    }
+
+    /// ## Difference, or `None` if it can not be represented
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() - rhs.clone())
+    }
+
+    /// ## Product, or `None` if it can not be represented
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() * rhs.clone())
+    }
+
+    /// ## Quotient, or `None` if it can not be represented
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() / rhs.clone())
+    }
+
+    /// ## Negation, or `None` if it can not be represented
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self.clone())
+    }
+}
+
+macro_rules! impl_float_field {
+    ($($t:ty),*) => {
+        $(
+            impl Field for $t {
+                const EXACT: bool = false;
+
+                fn magnitude(&self) -> f64 {
+                    self.abs() as f64
+                }
+
+                fn tolerance(scale: f64, size: usize) -> f64 {
+                    scale * size as f64 * <$t>::EPSILON as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_float_field!(f32, f64);
@@ -0,0 +1,429 @@
+use crate::{
+    complex::Complex,
+    decomposition::{check_square, complete_basis, francis_qr, reduce_to_hessenberg},
+    matrix_equations::solve_small_sylvester,
+    LalgrsError, LalgrsMatrix,
+};
+
+/// Multiple of `ε * ‖T‖` allowed for the part of a swapped block that should vanish
+const SWAP_TOLERANCE: f64 = 20.0;
+
+/// ## Product `Q * M * Qᵀ`
+fn similarity(q: &LalgrsMatrix<f64>, m: &LalgrsMatrix<f64>) -> LalgrsMatrix<f64> {
+    let n = q.rows();
+    let qm = LalgrsMatrix::from_fn(n, n, |i, j| {
+        (0..n).map(|k| q[(i, k)] * m[(k, j)]).sum::<f64>()
+    });
+    LalgrsMatrix::from_fn(n, n, |i, j| (0..n).map(|k| qm[(i, k)] * q[(j, k)]).sum())
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Hessenberg decomposition
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Hessenberg decomposition `A = Q * H * Qᵀ`
+/// `q` is orthogonal and `h` is upper Hessenberg, with zeros below its first subdiagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hessenberg {
+    pub q: LalgrsMatrix<f64>,
+    pub h: LalgrsMatrix<f64>,
+}
+
+impl Hessenberg {
+    /// ## Product `Q * H * Qᵀ`, equal to the decomposed matrix up to rounding
+    pub fn reconstruct(&self) -> LalgrsMatrix<f64> {
+        similarity(&self.q, &self.h)
+    }
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Hessenberg decomposition, computed with Householder reflections
+    /// If the matrix is empty or not square, returns an error
+    pub fn hessenberg(&self) -> Result<Hessenberg, LalgrsError> {
+        check_square(self)?;
+        let mut h = self.clone();
+        let mut q = LalgrsMatrix::identity(self.rows());
+        reduce_to_hessenberg(&mut h, Some(&mut q));
+        Ok(Hessenberg { q, h })
+    }
+
+    /// ## Real Schur decomposition, computed with Francis double shift QR steps on the Hessenberg form
+    /// If the matrix is empty or not square, or the iteration does not converge, returns an error
+    pub fn schur(&self) -> Result<Schur, LalgrsError> {
+        let Hessenberg { q, h: t } = self.hessenberg()?;
+        let mut schur = Schur { q, t };
+        let eigenvalues = francis_qr(&mut schur.t, Some(&mut schur.q))?;
+        let n = self.rows();
+        // Clear the rounding left below the quasi-triangular structure
+        for (j, eigenvalue) in eigenvalues.iter().enumerate() {
+            for i in j + 2..n {
+                schur.t[(i, j)] = 0.0;
+            }
+            if j + 1 < n && eigenvalue.im <= 0.0 {
+                schur.t[(j + 1, j)] = 0.0;
+            }
+        }
+        let mut k = 0;
+        while k < n {
+            if schur.block_size(k) == 2 {
+                schur.standardize(k);
+            }
+            k += schur.block_size(k);
+        }
+        Ok(schur)
+    }
+
+    /// ## Complex Schur decomposition, from the real one with a unitary rotation per 2x2 block
+    /// If the matrix is empty or not square, or the iteration does not converge, returns an error
+    pub fn complex_schur(&self) -> Result<ComplexSchur, LalgrsError> {
+        Ok(self.schur()?.to_complex())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Real Schur decomposition
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Real Schur decomposition `A = Q * T * Qᵀ`
+/// `q` is orthogonal and `t` is upper quasi-triangular. Real eigenvalues are on its diagonal,
+/// and each complex conjugate pair is the pair of eigenvalues of a 2x2 diagonal block in the standard form `[[a, b], [c, a]]`, with `b * c < 0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schur {
+    pub q: LalgrsMatrix<f64>,
+    pub t: LalgrsMatrix<f64>,
+}
+
+impl Schur {
+    /// ## Product `Q * T * Qᵀ`, equal to the decomposed matrix up to rounding
+    pub fn reconstruct(&self) -> LalgrsMatrix<f64> {
+        similarity(&self.q, &self.t)
+    }
+
+    /// ## Size of the diagonal block of `T` starting at row `k`, 1 or 2
+    fn block_size(&self, k: usize) -> usize {
+        if k + 1 < self.t.rows() && self.t[(k + 1, k)] != 0.0 {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// ## Start and size of every diagonal block of `T`, from the top left
+    pub(crate) fn blocks(&self) -> Vec<(usize, usize)> {
+        let mut blocks = Vec::new();
+        let mut k = 0;
+        while k < self.t.rows() {
+            blocks.push((k, self.block_size(k)));
+            k += self.block_size(k);
+        }
+        blocks
+    }
+
+    /// ## Eigenvalues in the order of the diagonal of `T`, complex pairs with the positive imaginary part first
+    pub fn eigenvalues(&self) -> Vec<Complex> {
+        let n = self.t.rows();
+        let mut eigenvalues = Vec::with_capacity(n);
+        let mut k = 0;
+        while k < n {
+            if self.block_size(k) == 1 {
+                eigenvalues.push(Complex::from(self.t[(k, k)]));
+                k += 1;
+                continue;
+            }
+            let (a, b) = (self.t[(k, k)], self.t[(k, k + 1)]);
+            let (c, d) = (self.t[(k + 1, k)], self.t[(k + 1, k + 1)]);
+            let mean = (a + d) / 2.0;
+            let discriminant = ((a - d) / 2.0).powi(2) + b * c;
+            if discriminant < 0.0 {
+                let im = (-discriminant).sqrt();
+                eigenvalues.push(Complex::new(mean, im));
+                eigenvalues.push(Complex::new(mean, -im));
+            } else {
+                eigenvalues.push(Complex::from(mean + discriminant.sqrt()));
+                eigenvalues.push(Complex::from(mean - discriminant.sqrt()));
+            }
+            k += 2;
+        }
+        eigenvalues
+    }
+
+    /// ## Applies the orthogonal similarity `W` to the rows and columns `start..start + W.rows()`
+    /// `T` becomes `Wᵀ * T * W` on that window and `Q` becomes `Q * W`, so `Q * T * Qᵀ` is unchanged.
+    /// The window must start at a block boundary, so that `T` is zero to its left.
+    fn transform(&mut self, start: usize, w: &LalgrsMatrix<f64>) {
+        let (n, m) = (self.t.rows(), w.rows());
+        let end = start + m;
+        for j in start..n {
+            let column: Vec<f64> = (start..end).map(|i| self.t[(i, j)]).collect();
+            for r in 0..m {
+                self.t[(start + r, j)] =
+                    column.iter().enumerate().map(|(s, x)| w[(s, r)] * x).sum();
+            }
+        }
+        let multiply_columns = |matrix: &mut LalgrsMatrix<f64>, rows: usize| {
+            for i in 0..rows {
+                let row: Vec<f64> = (start..end).map(|j| matrix[(i, j)]).collect();
+                for c in 0..m {
+                    matrix[(i, start + c)] =
+                        row.iter().enumerate().map(|(s, x)| x * w[(s, c)]).sum();
+                }
+            }
+        };
+        multiply_columns(&mut self.t, end);
+        multiply_columns(&mut self.q, n);
+    }
+
+    /// ## Rotates the 2x2 block at row `k` to the standard form, with equal diagonal elements
+    /// With `θ` the rotation angle, the diagonal difference becomes `(a - d) * cos 2θ + (b + c) * sin 2θ`.
+    fn standardize(&mut self, k: usize) {
+        let (a, b) = (self.t[(k, k)], self.t[(k, k + 1)]);
+        let (c, d) = (self.t[(k + 1, k)], self.t[(k + 1, k + 1)]);
+        let angle = (d - a).atan2(b + c) / 2.0;
+        let (sn, cs) = angle.sin_cos();
+        self.transform(
+            k,
+            &LalgrsMatrix::from_fn(2, 2, |i, j| match (i, j) {
+                (0, 1) => -sn,
+                (1, 0) => sn,
+                _ => cs,
+            }),
+        );
+        let mean = (self.t[(k, k)] + self.t[(k + 1, k + 1)]) / 2.0;
+        self.t[(k, k)] = mean;
+        self.t[(k + 1, k + 1)] = mean;
+    }
+
+    /// ## Swaps the adjacent diagonal blocks of sizes `first` and `second` starting at row `start`
+    /// Solves `T₁₁ * X - X * T₂₂ = T₁₂`, so that the columns of `[-X; I]` span the invariant subspace of `T₂₂`,
+    /// and moves an orthonormal basis of them to the front, as in Bai and Demmel's direct swapping.
+    /// If the swap would not leave `T` quasi-triangular to working precision, returns an error and leaves it unchanged
+    fn swap(&mut self, start: usize, first: usize, second: usize) -> Result<(), LalgrsError> {
+        let m = first + second;
+        let t = &self.t;
+        let block = |row: usize, column: usize, rows: usize, columns: usize| {
+            LalgrsMatrix::from_fn(rows, columns, |i, j| {
+                t[(start + row + i, start + column + j)]
+            })
+        };
+        let x = solve_small_sylvester(
+            &block(0, 0, first, first),
+            &-block(first, first, second, second),
+            &block(0, first, first, second),
+        )?;
+
+        let mut basis: Vec<Vec<f64>> = Vec::with_capacity(m);
+        for k in 0..second {
+            let mut vector: Vec<f64> = (0..m)
+                .map(|i| match i {
+                    i if i < first => -x[(i, k)],
+                    i if i - first == k => 1.0,
+                    _ => 0.0,
+                })
+                .collect();
+            // Two passes of Gram-Schmidt keep the basis orthogonal to working precision
+            for _ in 0..2 {
+                for b in &basis {
+                    let projection: f64 = b.iter().zip(&vector).map(|(x, y)| x * y).sum();
+                    vector
+                        .iter_mut()
+                        .zip(b)
+                        .for_each(|(v, x)| *v -= projection * x);
+                }
+            }
+            let norm = vector.iter().map(|v| v * v).sum::<f64>().sqrt();
+            basis.push(vector.iter().map(|v| v / norm).collect());
+        }
+        let complement = complete_basis(basis.clone(), m);
+        basis.extend(complement);
+        let w = LalgrsMatrix::from_fn(m, m, |i, j| basis[j][i]);
+
+        let window = LalgrsMatrix::from_fn(m, m, |i, j| t[(start + i, start + j)]);
+        let window_norm = window.frobenius_norm();
+        let swapped = LalgrsMatrix::from_fn(m, m, |i, j| {
+            (0..m)
+                .map(|k| w[(k, i)] * (0..m).map(|l| window[(k, l)] * w[(l, j)]).sum::<f64>())
+                .sum::<f64>()
+        });
+        let residual = (second..m)
+            .flat_map(|i| (0..second).map(move |j| (i, j)))
+            .map(|(i, j)| swapped[(i, j)].powi(2))
+            .sum::<f64>()
+            .sqrt();
+        if residual > SWAP_TOLERANCE * f64::EPSILON * window_norm {
+            return Err(LalgrsError::IllConditioned {
+                estimate: x.frobenius_norm(),
+            });
+        }
+
+        self.transform(start, &w);
+        for i in second..m {
+            for j in 0..second {
+                self.t[(start + i, start + j)] = 0.0;
+            }
+        }
+        for (offset, size) in [(0, second), (second, first)] {
+            if size == 2 {
+                self.standardize(start + offset);
+            }
+        }
+        Ok(())
+    }
+
+    /// ## Moves the selected eigenvalues to the top left of `T`, keeping `Q * T * Qᵀ` unchanged
+    /// `select` is called on every eigenvalue, and a complex pair is moved when either of its eigenvalues is selected.
+    /// The selected and the other eigenvalues keep their relative order.
+    /// Returns the number `k` of selected eigenvalues: the first `k` columns of `Q` span their invariant subspace.
+    /// If swapping two blocks is too ill conditioned, which happens when their eigenvalues are very close, returns an error
+    pub fn reorder<F: FnMut(Complex) -> bool>(
+        &mut self,
+        mut select: F,
+    ) -> Result<usize, LalgrsError> {
+        let n = self.t.rows();
+        let eigenvalues = self.eigenvalues();
+        let mut selected = 0;
+        let mut k = 0;
+        while k < n {
+            let size = self.block_size(k);
+            if eigenvalues[k..k + size].iter().any(|e| select(*e)) {
+                let mut here = k;
+                while here > selected {
+                    let previous = if here >= 2 && self.t[(here - 1, here - 2)] != 0.0 {
+                        2
+                    } else {
+                        1
+                    };
+                    self.swap(here - previous, previous, size)?;
+                    here -= previous;
+                }
+                selected += size;
+            }
+            k += size;
+        }
+        Ok(selected)
+    }
+
+    /// ## Complex Schur decomposition with the same `Q * T * Qᵀ` and eigenvalue order
+    /// Each 2x2 block is triangularised by the unitary rotation whose first column is an eigenvector of the block,
+    /// as MATLAB's `rsf2csf` does.
+    pub fn to_complex(&self) -> ComplexSchur {
+        let mut q = self.q.map(|x| Complex::from(*x));
+        let mut t = self.t.map(|x| Complex::from(*x));
+        let n = self.t.rows();
+        let eigenvalues = self.eigenvalues();
+        let mut k = 0;
+        while k < n {
+            if self.block_size(k) == 1 {
+                k += 1;
+                continue;
+            }
+            let m = k + 1;
+            let mu = eigenvalues[k] - t[(m, m)];
+            let r = mu.abs().hypot(t[(m, k)].re);
+            let (c, s) = (mu / Complex::from(r), Complex::from(t[(m, k)].re / r));
+            // Rows k and m by G = [[c̄, s], [-s, c]], then columns by Gᴴ
+            for j in k..n {
+                let (x, y) = (t[(k, j)], t[(m, j)]);
+                t[(k, j)] = c.conj() * x + s * y;
+                t[(m, j)] = c * y - s * x;
+            }
+            let rotate_columns = |matrix: &mut LalgrsMatrix<Complex>, rows: usize| {
+                for i in 0..rows {
+                    let (x, y) = (matrix[(i, k)], matrix[(i, m)]);
+                    matrix[(i, k)] = c * x + s * y;
+                    matrix[(i, m)] = c.conj() * y - s * x;
+                }
+            };
+            rotate_columns(&mut t, m + 1);
+            rotate_columns(&mut q, n);
+            t[(m, k)] = Complex::default();
+            k += 2;
+        }
+        ComplexSchur { q, t }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Complex Schur decomposition
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Complex Schur decomposition `A = Q * T * Qᴴ`
+/// `q` is unitary and `t` is upper triangular, with the eigenvalues on its diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplexSchur {
+    pub q: LalgrsMatrix<Complex>,
+    pub t: LalgrsMatrix<Complex>,
+}
+
+impl ComplexSchur {
+    /// ## Product `Q * T * Qᴴ`, equal to the decomposed matrix up to rounding
+    pub fn reconstruct(&self) -> LalgrsMatrix<Complex> {
+        let n = self.q.rows();
+        let qt = LalgrsMatrix::from_fn(n, n, |i, j| {
+            (0..=j).fold(Complex::default(), |sum, k| {
+                sum + self.q[(i, k)] * self.t[(k, j)]
+            })
+        });
+        LalgrsMatrix::from_fn(n, n, |i, j| {
+            (0..n).fold(Complex::default(), |sum, k| {
+                sum + qt[(i, k)] * self.q[(j, k)].conj()
+            })
+        })
+    }
+
+    /// ## Eigenvalues, the diagonal of `T`
+    pub fn eigenvalues(&self) -> Vec<Complex> {
+        (0..self.t.rows()).map(|k| self.t[(k, k)]).collect()
+    }
+
+    /// ## Swaps the diagonal elements `k` and `k + 1` of `T` with a complex Givens rotation, as LAPACK's `trexc` does
+    fn swap(&mut self, k: usize) {
+        let n = self.t.rows();
+        let (t11, t22) = (self.t[(k, k)], self.t[(k + 1, k + 1)]);
+        // Rotation [[cs, sn], [-sn̄, cs]] that zeroes the second element of (t₁₂, t₂₂ - t₁₁)
+        let (f, g) = (self.t[(k, k + 1)], t22 - t11);
+        let (cs, sn) = if g == Complex::default() {
+            (1.0, Complex::default())
+        } else if f == Complex::default() {
+            (0.0, g.conj() / Complex::from(g.abs()))
+        } else {
+            let norm = f.abs().hypot(g.abs());
+            (
+                f.abs() / norm,
+                f / Complex::from(f.abs()) * g.conj() / Complex::from(norm),
+            )
+        };
+        let cs = Complex::from(cs);
+        for j in k + 2..n {
+            let (x, y) = (self.t[(k, j)], self.t[(k + 1, j)]);
+            self.t[(k, j)] = cs * x + sn * y;
+            self.t[(k + 1, j)] = cs * y - sn.conj() * x;
+        }
+        let rotate_columns = |matrix: &mut LalgrsMatrix<Complex>, rows: usize| {
+            for i in 0..rows {
+                let (x, y) = (matrix[(i, k)], matrix[(i, k + 1)]);
+                matrix[(i, k)] = cs * x + sn.conj() * y;
+                matrix[(i, k + 1)] = cs * y - sn * x;
+            }
+        };
+        rotate_columns(&mut self.t, k);
+        rotate_columns(&mut self.q, n);
+        self.t[(k, k)] = t22;
+        self.t[(k + 1, k + 1)] = t11;
+    }
+
+    /// ## Moves the selected eigenvalues to the top left of `T`, keeping `Q * T * Qᴴ` unchanged
+    /// The selected and the other eigenvalues keep their relative order.
+    /// Returns the number `k` of selected eigenvalues: the first `k` columns of `Q` span their invariant subspace.
+    pub fn reorder<F: FnMut(Complex) -> bool>(&mut self, mut select: F) -> usize {
+        let mut selected = 0;
+        for k in 0..self.t.rows() {
+            if select(self.t[(k, k)]) {
+                for here in (selected..k).rev() {
+                    self.swap(here);
+                }
+                selected += 1;
+            }
+        }
+        selected
+    }
+}
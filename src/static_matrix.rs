@@ -0,0 +1,396 @@
+use std::ops::{self, Add, Index, IndexMut, Mul, Neg};
+
+use crate::{LalgrsError, LalgrsMatrix, LalgrsVector, MatrixView, One, Shape, Zero};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Base struct definitions
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Stack allocated matrix with `R` rows and `C` columns
+/// Dimensions are part of the type, so mismatched operations are rejected at compile time:
+/// ```compile_fail
+/// use lalgrs::SMatrix;
+/// let a = SMatrix::<f64, 3, 2>::zeros();
+/// let b = SMatrix::<f64, 4, 4>::zeros();
+/// let c = a * b;
+/// ```
+/// Elements are stored column by column, like in `LalgrsMatrix`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SMatrix<T, const R: usize, const C: usize> {
+    columns: [[T; R]; C],
+}
+
+/// ## Stack allocated vector with `N` elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SVector<T, const N: usize> {
+    pub values: [T; N],
+}
+
+pub type SMatrix2<T> = SMatrix<T, 2, 2>;
+pub type SMatrix3<T> = SMatrix<T, 3, 3>;
+pub type SMatrix4<T> = SMatrix<T, 4, 4>;
+pub type SVector2<T> = SVector<T, 2>;
+pub type SVector3<T> = SVector<T, 3>;
+pub type SVector4<T> = SVector<T, 4>;
+
+impl<T, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// ## Creates a matrix from an array of columns
+    pub fn from_columns(columns: [[T; R]; C]) -> SMatrix<T, R, C> {
+        SMatrix { columns }
+    }
+
+    /// ## Creates a matrix by calling `f(row, column)` for every element
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> SMatrix<T, R, C> {
+        SMatrix {
+            columns: std::array::from_fn(|j| std::array::from_fn(|i| f(i, j))),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        R
+    }
+    pub fn columns(&self) -> usize {
+        C
+    }
+    pub fn shape(&self) -> Shape {
+        Shape::new(R, C)
+    }
+
+    /// ## Returns a reference to the element at the given row and column
+    /// Returns `None` if the position is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.columns.get(column)?.get(row)
+    }
+
+    /// ## Returns a mutable reference to the element at the given row and column
+    /// Returns `None` if the position is out of bounds
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.columns.get_mut(column)?.get_mut(row)
+    }
+
+    /// ## Read only view of the matrix, to use it with the dynamically sized operations
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView::column_major(self.columns.as_flattened(), self.shape())
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// ## Creates a matrix from an array of rows
+    pub fn from_rows(rows: [[T; C]; R]) -> SMatrix<T, R, C> {
+        SMatrix::from_fn(|i, j| rows[i][j].clone())
+    }
+
+    /// ## Returns the transpose of the matrix
+    pub fn transpose(&self) -> SMatrix<T, C, R> {
+        SMatrix::from_fn(|i, j| self.columns[i][j].clone())
+    }
+
+    /// ## Copy of the given column
+    /// Returns `None` if the column is out of bounds
+    pub fn column(&self, column: usize) -> Option<SVector<T, R>> {
+        self.columns.get(column).cloned().map(SVector::new)
+    }
+
+    /// ## Copy of the given row
+    /// Returns `None` if the row is out of bounds
+    pub fn row(&self, row: usize) -> Option<SVector<T, C>> {
+        if row >= R {
+            return None;
+        }
+        Some(SVector::new(std::array::from_fn(|j| {
+            self.columns[j][row].clone()
+        })))
+    }
+}
+
+impl<T: Zero, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// ## Creates a matrix with every element set to zero
+    pub fn zeros() -> SMatrix<T, R, C> {
+        SMatrix::from_fn(|_, _| T::zero())
+    }
+}
+
+impl<T: Zero + One, const N: usize> SMatrix<T, N, N> {
+    /// ## Creates the identity matrix
+    pub fn identity() -> SMatrix<T, N, N> {
+        SMatrix::from_fn(|i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: Zero + Clone, const N: usize> SMatrix<T, N, N> {
+    /// ## Creates a matrix with the elements of `diagonal` on its main diagonal and zeros elsewhere
+    pub fn from_diagonal(diagonal: &SVector<T, N>) -> SMatrix<T, N, N> {
+        SMatrix::from_fn(|i, j| {
+            if i == j {
+                diagonal.values[i].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+/// ## Indexing by `(row, column)`
+/// Panics if the position is out of bounds. Use `SMatrix::get` for a non panicking alternative
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for SMatrix<T, R, C> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.columns[column][row]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.columns[column][row]
+    }
+}
+
+impl<T, const N: usize> SVector<T, N> {
+    pub fn new(values: [T; N]) -> SVector<T, N> {
+        SVector { values }
+    }
+
+    /// ## Creates a vector by calling `f(index)` for every element
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> SVector<T, N> {
+        SVector::new(std::array::from_fn(f))
+    }
+
+    pub fn size(&self) -> usize {
+        N
+    }
+}
+
+impl<T: Zero, const N: usize> SVector<T, N> {
+    /// ## Creates a vector with every element set to zero
+    pub fn zeros() -> SVector<T, N> {
+        SVector::from_fn(|_| T::zero())
+    }
+}
+
+impl<T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero, const N: usize> SVector<T, N> {
+    /// ## Dot product between two vectors
+    pub fn dot(&self, rhs: &SVector<T, N>) -> T {
+        self.values
+            .iter()
+            .zip(rhs.values.iter())
+            .fold(T::zero(), |acc, (a, b)| acc + a.clone() * b.clone())
+    }
+}
+
+impl<T: Add<T, Output = T> + Mul<T, Output = T> + Neg<Output = T> + Clone> SVector<T, 3> {
+    /// ## Cross product between two 3 dimensional vectors
+    pub fn cross(&self, rhs: &SVector<T, 3>) -> SVector<T, 3> {
+        let [a1, a2, a3] = self.values.clone();
+        let [b1, b2, b3] = rhs.values.clone();
+        SVector::new([
+            a2.clone() * b3.clone() + -(a3.clone() * b2.clone()),
+            a3 * b1.clone() + -(a1.clone() * b3),
+            a1 * b2 + -(a2 * b1),
+        ])
+    }
+}
+
+impl<const N: usize> SVector<f64, N> {
+    /// ## Euclidean norm of the vector
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for SVector<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SVector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.values[index]
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Conversions
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Add<T, Output = T> + Clone, const R: usize, const C: usize> From<SMatrix<T, R, C>>
+    for LalgrsMatrix<T>
+{
+    fn from(value: SMatrix<T, R, C>) -> Self {
+        value.view().to_matrix()
+    }
+}
+
+/// ## Converts a dynamically sized matrix into a static one
+/// If the shape of the matrix is not `R` by `C`, returns an error
+impl<T: Add<T, Output = T> + Clone, const R: usize, const C: usize> TryFrom<&LalgrsMatrix<T>>
+    for SMatrix<T, R, C>
+{
+    type Error = LalgrsError;
+    fn try_from(value: &LalgrsMatrix<T>) -> Result<Self, Self::Error> {
+        SMatrix::try_from(value.view())
+    }
+}
+
+impl<T: Add<T, Output = T> + Clone, const R: usize, const C: usize> TryFrom<LalgrsMatrix<T>>
+    for SMatrix<T, R, C>
+{
+    type Error = LalgrsError;
+    fn try_from(value: LalgrsMatrix<T>) -> Result<Self, Self::Error> {
+        SMatrix::try_from(value.view())
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> TryFrom<MatrixView<'_, T>> for SMatrix<T, R, C> {
+    type Error = LalgrsError;
+    fn try_from(value: MatrixView<'_, T>) -> Result<Self, Self::Error> {
+        if value.shape() != Shape::new(R, C) {
+            return Err(LalgrsError::MismatchedMatrixDimensions {
+                lhs: value.shape(),
+                rhs: Shape::new(R, C),
+            });
+        }
+        Ok(SMatrix::from_fn(|i, j| value[(i, j)].clone()))
+    }
+}
+
+impl<T: Add<T, Output = T>, const N: usize> From<SVector<T, N>> for LalgrsVector<T> {
+    fn from(value: SVector<T, N>) -> Self {
+        LalgrsVector::new(value.values.into())
+    }
+}
+
+/// ## Converts a dynamically sized vector into a static one
+/// If the vector does not have `N` elements, returns an error
+impl<T: Add<T, Output = T> + Clone, const N: usize> TryFrom<&LalgrsVector<T>> for SVector<T, N> {
+    type Error = LalgrsError;
+    fn try_from(value: &LalgrsVector<T>) -> Result<Self, Self::Error> {
+        if value.size() != N {
+            return Err(LalgrsError::MismatchedVectorDimensions {
+                vector1: value.size(),
+                vector2: N,
+            });
+        }
+        Ok(SVector::from_fn(|i| value.values[i].clone()))
+    }
+}
+
+impl<T: Add<T, Output = T> + Clone, const N: usize> TryFrom<LalgrsVector<T>> for SVector<T, N> {
+    type Error = LalgrsError;
+    fn try_from(value: LalgrsVector<T>) -> Result<Self, Self::Error> {
+        SVector::try_from(&value)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Static Matrix Operations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Addition between two matrices of the same shape
+impl<T: Add<T, Output = T> + Clone, const R: usize, const C: usize> ops::Add<SMatrix<T, R, C>>
+    for SMatrix<T, R, C>
+{
+    type Output = SMatrix<T, R, C>;
+    fn add(self, rhs: SMatrix<T, R, C>) -> Self::Output {
+        SMatrix::from_fn(|i, j| self[(i, j)].clone() + rhs[(i, j)].clone())
+    }
+}
+
+/// ## Negation of a matrix. Negates each element
+impl<T: Neg<Output = T> + Clone, const R: usize, const C: usize> ops::Neg for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+    fn neg(self) -> Self::Output {
+        SMatrix::from_fn(|i, j| -self[(i, j)].clone())
+    }
+}
+
+/// ## Subtraction between two matrices of the same shape
+/// Performs addition with the negative of the second operand
+impl<T: Add<T, Output = T> + Neg<Output = T> + Clone, const R: usize, const C: usize>
+    ops::Sub<SMatrix<T, R, C>> for SMatrix<T, R, C>
+{
+    type Output = SMatrix<T, R, C>;
+    fn sub(self, rhs: SMatrix<T, R, C>) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// ## Multiplication between a matrix and a scalar
+impl<T: Mul<T, Output = T> + Clone, const R: usize, const C: usize> ops::Mul<T>
+    for SMatrix<T, R, C>
+{
+    type Output = SMatrix<T, R, C>;
+    fn mul(self, rhs: T) -> Self::Output {
+        SMatrix::from_fn(|i, j| self[(i, j)].clone() * rhs.clone())
+    }
+}
+
+/// ## Multiplication between an `R` by `C` and a `C` by `K` matrix
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<
+        T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero,
+        const R: usize,
+        const C: usize,
+        const K: usize,
+    > ops::Mul<SMatrix<T, C, K>> for SMatrix<T, R, C>
+{
+    type Output = SMatrix<T, R, K>;
+    fn mul(self, rhs: SMatrix<T, C, K>) -> Self::Output {
+        SMatrix::from_fn(|i, j| {
+            (0..C).fold(T::zero(), |acc, k| {
+                acc + self[(i, k)].clone() * rhs[(k, j)].clone()
+            })
+        })
+    }
+}
+
+/// ## Multiplication between an `R` by `C` matrix and a vector with `C` elements
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero, const R: usize, const C: usize>
+    ops::Mul<SVector<T, C>> for SMatrix<T, R, C>
+{
+    type Output = SVector<T, R>;
+    fn mul(self, rhs: SVector<T, C>) -> Self::Output {
+        SVector::from_fn(|i| {
+            (0..C).fold(T::zero(), |acc, k| {
+                acc + self[(i, k)].clone() * rhs[k].clone()
+            })
+        })
+    }
+}
+
+/// ## Addition between two vectors of the same size
+impl<T: Add<T, Output = T> + Clone, const N: usize> ops::Add<SVector<T, N>> for SVector<T, N> {
+    type Output = SVector<T, N>;
+    fn add(self, rhs: SVector<T, N>) -> Self::Output {
+        SVector::from_fn(|i| self[i].clone() + rhs[i].clone())
+    }
+}
+
+/// ## Negation of a vector. Negates each element
+impl<T: Neg<Output = T> + Clone, const N: usize> ops::Neg for SVector<T, N> {
+    type Output = SVector<T, N>;
+    fn neg(self) -> Self::Output {
+        SVector::from_fn(|i| -self[i].clone())
+    }
+}
+
+/// ## Subtraction between two vectors of the same size
+/// Performs addition with the negative of the second operand
+impl<T: Add<T, Output = T> + Neg<Output = T> + Clone, const N: usize> ops::Sub<SVector<T, N>>
+    for SVector<T, N>
+{
+    type Output = SVector<T, N>;
+    fn sub(self, rhs: SVector<T, N>) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// ## Multiplication between a vector and a scalar
+impl<T: Mul<T, Output = T> + Clone, const N: usize> ops::Mul<T> for SVector<T, N> {
+    type Output = SVector<T, N>;
+    fn mul(self, rhs: T) -> Self::Output {
+        SVector::from_fn(|i| self[i].clone() * rhs.clone())
+    }
+}
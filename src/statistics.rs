@@ -0,0 +1,268 @@
+use crate::{LalgrsError, LalgrsMatrix, LalgrsVector};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Statistics
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Direction of a reduction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// One result per column, reducing over the rows
+    Columns,
+    /// One result per row, reducing over the columns
+    Rows,
+}
+
+/// ## Checks that `weights` has one non negative weight per element of a lane, with a positive sum
+fn check_weights(weights: &LalgrsVector<f64>, length: usize) -> Result<(), LalgrsError> {
+    if weights.size() != length {
+        return Err(LalgrsError::MismatchedVectorDimensions {
+            vector1: weights.size(),
+            vector2: length,
+        });
+    }
+    if let Some(weight) = weights.iter().find(|w| **w < 0.0 || !w.is_finite()) {
+        return Err(LalgrsError::InvalidParameter {
+            name: "weights",
+            value: *weight,
+        });
+    }
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return Err(LalgrsError::InvalidParameter {
+            name: "weights",
+            value: total,
+        });
+    }
+    Ok(())
+}
+
+/// ## Weighted mean of `values`
+fn weighted_mean(values: &[f64], weights: &[f64]) -> f64 {
+    let total: f64 = weights.iter().sum();
+    values.iter().zip(weights).map(|(v, w)| v * w).sum::<f64>() / total
+}
+
+/// ## Normalisation of a weighted sum of squared deviations
+/// `Σw - ddof * Σw² / Σw`, which is `n - ddof` for unit weights
+fn degrees_of_freedom(weights: &[f64], ddof: usize) -> Result<f64, LalgrsError> {
+    let total: f64 = weights.iter().sum();
+    let squares: f64 = weights.iter().map(|w| w * w).sum();
+    let dof = total - ddof as f64 * squares / total;
+    if dof <= 0.0 {
+        return Err(LalgrsError::InvalidParameter {
+            name: "ddof",
+            value: ddof as f64,
+        });
+    }
+    Ok(dof)
+}
+
+/// ## Weighted covariance of two samples with the same weights
+fn weighted_covariance(x: &[f64], y: &[f64], weights: &[f64], dof: f64) -> f64 {
+    let (mean_x, mean_y) = (weighted_mean(x, weights), weighted_mean(y, weights));
+    x.iter()
+        .zip(y)
+        .zip(weights)
+        .map(|((a, b), w)| w * (a - mean_x) * (b - mean_y))
+        .sum::<f64>()
+        / dof
+}
+
+/// ## Quantile of sorted values, interpolating linearly between the two closest ranks
+fn sorted_quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (below, above) = (position.floor() as usize, position.ceil() as usize);
+    let fraction = position - below as f64;
+    sorted[below] + (sorted[above] - sorted[below]) * fraction
+}
+
+impl LalgrsMatrix<f64> {
+    /// ## Columns or rows of the matrix, as the samples reduced along `axis`
+    fn lanes(&self, axis: Axis) -> Result<Vec<Vec<f64>>, LalgrsError> {
+        if self.is_empty() {
+            return Err(LalgrsError::EmptyMatrix);
+        }
+        Ok(match axis {
+            Axis::Columns => self
+                .as_column_major_slice()
+                .chunks(self.rows())
+                .map(|c| c.to_vec())
+                .collect(),
+            Axis::Rows => (0..self.rows())
+                .map(|i| (0..self.columns()).map(|j| self[(i, j)]).collect())
+                .collect(),
+        })
+    }
+
+    /// ## Applies `reduce` to every lane along `axis`, with unit weights if none are given
+    fn reduce<F: Fn(&[f64], &[f64]) -> Result<f64, LalgrsError>>(
+        &self,
+        axis: Axis,
+        weights: Option<&LalgrsVector<f64>>,
+        reduce: F,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        let lanes = self.lanes(axis)?;
+        let length = lanes.first().map_or(0, |l| l.len());
+        let weights: Vec<f64> = match weights {
+            Some(weights) => {
+                check_weights(weights, length)?;
+                weights.iter().copied().collect()
+            }
+            None => vec![1.0; length],
+        };
+        lanes.iter().map(|lane| reduce(lane, &weights)).collect()
+    }
+
+    /// ## Arithmetic mean of every column or every row
+    /// If the matrix is empty, returns an error
+    pub fn mean(&self, axis: Axis) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.reduce(axis, None, |lane, weights| Ok(weighted_mean(lane, weights)))
+    }
+
+    /// ## Weighted mean of every column or every row
+    /// `weights` has one weight per element of a column or row.
+    /// If the matrix is empty, or the weights do not match, are negative or sum to zero, returns an error
+    pub fn weighted_mean(
+        &self,
+        axis: Axis,
+        weights: &LalgrsVector<f64>,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.reduce(axis, Some(weights), |lane, weights| {
+            Ok(weighted_mean(lane, weights))
+        })
+    }
+
+    /// ## Variance of every column or every row, with `ddof` delta degrees of freedom
+    /// The sum of squared deviations is divided by `n - ddof`:
+    /// `ddof = 0` gives the population variance and `ddof = 1` the unbiased sample variance.
+    /// If the matrix is empty, or `ddof` is not smaller than `n`, returns an error
+    pub fn variance(&self, axis: Axis, ddof: usize) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.reduce(axis, None, |lane, weights| {
+            Ok(weighted_covariance(
+                lane,
+                lane,
+                weights,
+                degrees_of_freedom(weights, ddof)?,
+            ))
+        })
+    }
+
+    /// ## Weighted variance of every column or every row, with `ddof` delta degrees of freedom
+    /// The weighted sum of squared deviations is divided by `Σw - ddof * Σw² / Σw`, as NumPy does.
+    /// The weights measure reliability: scaling them all by a constant does not change the result,
+    /// and unit weights give `LalgrsMatrix::variance`.
+    /// If the matrix is empty, the weights do not match, are negative or sum to zero,
+    /// or `ddof` leaves no degrees of freedom, returns an error
+    pub fn weighted_variance(
+        &self,
+        axis: Axis,
+        weights: &LalgrsVector<f64>,
+        ddof: usize,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.reduce(axis, Some(weights), |lane, weights| {
+            Ok(weighted_covariance(
+                lane,
+                lane,
+                weights,
+                degrees_of_freedom(weights, ddof)?,
+            ))
+        })
+    }
+
+    /// ## Standard deviation of every column or every row, the square root of `LalgrsMatrix::variance`
+    /// If the matrix is empty, or `ddof` is not smaller than `n`, returns an error
+    pub fn std(&self, axis: Axis, ddof: usize) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(self.variance(axis, ddof)?.map(|v| v.sqrt()))
+    }
+
+    /// ## Weighted standard deviation of every column or every row, the square root of `LalgrsMatrix::weighted_variance`
+    /// If the matrix is empty, the weights are invalid, or `ddof` leaves no degrees of freedom, returns an error
+    pub fn weighted_std(
+        &self,
+        axis: Axis,
+        weights: &LalgrsVector<f64>,
+        ddof: usize,
+    ) -> Result<LalgrsVector<f64>, LalgrsError> {
+        Ok(self
+            .weighted_variance(axis, weights, ddof)?
+            .map(|v| v.sqrt()))
+    }
+
+    /// ## Median of every column or every row
+    /// Lanes with an even number of elements give the mean of the two middle elements.
+    /// If the matrix is empty, returns an error
+    pub fn median(&self, axis: Axis) -> Result<LalgrsVector<f64>, LalgrsError> {
+        self.quantile(axis, 0.5)
+    }
+
+    /// ## Quantile `q` of every column or every row, interpolating linearly between the closest elements
+    /// `q = 0` is the minimum, `q = 0.5` the median and `q = 1` the maximum. NaN elements sort after every number.
+    /// If the matrix is empty, or `q` is not in `[0, 1]`, returns an error
+    pub fn quantile(&self, axis: Axis, q: f64) -> Result<LalgrsVector<f64>, LalgrsError> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(LalgrsError::InvalidParameter {
+                name: "q",
+                value: q,
+            });
+        }
+        self.reduce(axis, None, |lane, _| {
+            let mut sorted = lane.to_vec();
+            sorted.sort_by(f64::total_cmp);
+            Ok(sorted_quantile(&sorted, q))
+        })
+    }
+
+    /// ## Covariance matrix of the variables in the columns, with one observation per row
+    /// Element `(i, j)` is the covariance of columns `i` and `j`, normalised by `n - ddof` as in `LalgrsMatrix::variance`.
+    /// If the matrix is empty, or `ddof` is not smaller than the number of rows, returns an error
+    pub fn covariance_matrix(&self, ddof: usize) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let weights = LalgrsVector::new(vec![1.0; self.rows()]);
+        self.weighted_covariance_matrix(&weights, ddof)
+    }
+
+    /// ## Weighted covariance matrix of the variables in the columns, with one weighted observation per row
+    /// Normalised as in `LalgrsMatrix::weighted_variance`.
+    /// If the matrix is empty, the weights do not have one element per row, are negative or sum to zero,
+    /// or `ddof` leaves no degrees of freedom, returns an error
+    pub fn weighted_covariance_matrix(
+        &self,
+        weights: &LalgrsVector<f64>,
+        ddof: usize,
+    ) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let columns = self.lanes(Axis::Columns)?;
+        check_weights(weights, self.rows())?;
+        let weights: Vec<f64> = weights.iter().copied().collect();
+        let dof = degrees_of_freedom(&weights, ddof)?;
+
+        let n = columns.len();
+        let mut covariance = LalgrsMatrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..=i {
+                let value = weighted_covariance(&columns[i], &columns[j], &weights, dof);
+                covariance[(i, j)] = value;
+                covariance[(j, i)] = value;
+            }
+        }
+        Ok(covariance)
+    }
+
+    /// ## Pearson correlation matrix of the variables in the columns, with one observation per row
+    /// The diagonal is one. Correlations involving a constant column are NaN.
+    /// If the matrix is empty or has a single row, returns an error
+    pub fn correlation_matrix(&self) -> Result<LalgrsMatrix<f64>, LalgrsError> {
+        let covariance = self.covariance_matrix(1)?;
+        let n = covariance.rows();
+        Ok(LalgrsMatrix::from_fn(n, n, |i, j| {
+            if i == j {
+                return if covariance[(i, i)] > 0.0 {
+                    1.0
+                } else {
+                    f64::NAN
+                };
+            }
+            // Rounding can push the ratio slightly outside of [-1, 1]
+            (covariance[(i, j)] / (covariance[(i, i)] * covariance[(j, j)]).sqrt()).clamp(-1.0, 1.0)
+        }))
+    }
+}
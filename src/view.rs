@@ -0,0 +1,956 @@
+use std::{
+    marker::PhantomData,
+    ops::{self, Add, Index, IndexMut, Mul, Neg},
+};
+
+use crate::{
+    scalar::{Field, Zero},
+    LalgrsError, LalgrsMatrix, LalgrsVector, Shape,
+};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # View struct definitions
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Borrowed, read only view into the elements of a matrix
+/// A view references a submatrix, a row, a column or a strided range of a `LalgrsMatrix` without copying it.
+/// Element `(row, column)` of the view is found at `row * row_stride + column * column_stride` in the borrowed buffer.
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    // Raw pointer instead of a slice, because a view made from a split `MatrixViewMut`
+    // must not borrow the interleaved elements that belong to the other half.
+    // Invariant: every `(row, column)` inside `shape` maps to a valid element that is not mutated while the view is alive.
+    data: *const T,
+    shape: Shape,
+    row_stride: usize,
+    column_stride: usize,
+    marker: PhantomData<&'a T>,
+}
+
+// Implemented by hand, because deriving would require `T: Copy`
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+// A `MatrixView` behaves like a `&[T]`
+unsafe impl<T: Sync> Send for MatrixView<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixView<'_, T> {}
+
+/// ## Borrowed, mutable view into the elements of a matrix
+/// Mutable views can be split into disjoint views with `split_at_row` and `split_at_column`,
+/// so block algorithms can work on several submatrices of the same matrix at once.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    // Raw pointer instead of a slice, because the rows of a view are interleaved in memory
+    // and splitting a view by rows can not be expressed with `split_at_mut`.
+    // Invariant: every `(row, column)` inside `shape` maps to a distinct, valid element.
+    data: *mut T,
+    shape: Shape,
+    row_stride: usize,
+    column_stride: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+// A `MatrixViewMut` behaves like a `&mut [T]`
+unsafe impl<T: Send> Send for MatrixViewMut<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixViewMut<'_, T> {}
+
+/// ## Computes the position of a strided range inside a parent view
+/// Returns the offset of the first element and the new strides,
+/// or an error if the range does not fit inside `parent` or its offsets overflow
+fn strided_range(
+    parent: Shape,
+    (row_stride, column_stride): (usize, usize),
+    (row, column): (usize, usize),
+    shape: Shape,
+    (row_step, column_step): (usize, usize),
+) -> Result<(usize, usize, usize), LalgrsError> {
+    let out_of_bounds = LalgrsError::SubmatrixOutOfBounds {
+        row,
+        column,
+        shape,
+        matrix_shape: parent,
+    };
+    if row_step == 0 || column_step == 0 {
+        return Err(out_of_bounds);
+    }
+
+    // The step of a dimension with a single element is never used, so it can not make the range fail
+    let row_step = if shape.rows > 1 { row_step } else { 1 };
+    let column_step = if shape.columns > 1 { column_step } else { 1 };
+    // Index of the last element of the range, if it is below `limit`
+    let fits = |start: usize, count: usize, step: usize, limit: usize| {
+        count == 0
+            || (count - 1)
+                .checked_mul(step)
+                .and_then(|n| n.checked_add(start))
+                .is_some_and(|last| last < limit)
+    };
+    if !fits(row, shape.rows, row_step, parent.rows)
+        || !fits(column, shape.columns, column_step, parent.columns)
+    {
+        return Err(out_of_bounds);
+    }
+
+    if shape.is_empty() {
+        return Ok((0, row_stride, column_stride));
+    }
+
+    // The last element of the range lies inside the parent, so the offset of every element of the
+    // new view fits in a `usize` once the strides themselves do
+    let offset = row
+        .checked_mul(row_stride)
+        .zip(column.checked_mul(column_stride))
+        .and_then(|(r, c)| r.checked_add(c));
+    let row_stride = row_stride.checked_mul(row_step);
+    let column_stride = column_stride.checked_mul(column_step);
+    match (offset, row_stride, column_stride) {
+        (Some(offset), Some(row_stride), Some(column_stride)) => {
+            Ok((offset, row_stride, column_stride))
+        }
+        _ => Err(out_of_bounds),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Read only views
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'a, T> MatrixView<'a, T> {
+    // Caller guarantees that `data` holds exactly `shape.size()` elements
+    pub(crate) fn column_major(data: &'a [T], shape: Shape) -> MatrixView<'a, T> {
+        MatrixView {
+            data: data.as_ptr(),
+            shape,
+            row_stride: 1,
+            column_stride: shape.rows,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Creates a view over a buffer of elements stored column by column
+    /// If `shape.rows * shape.columns` overflows or is not the length of the buffer, returns an error
+    pub fn from_column_major_slice(
+        data: &'a [T],
+        shape: Shape,
+    ) -> Result<MatrixView<'a, T>, LalgrsError> {
+        let expected = shape
+            .checked_size()
+            .ok_or(LalgrsError::InvalidMatrixDimensions)?;
+        if data.len() != expected {
+            return Err(LalgrsError::InvalidElementCount {
+                expected,
+                found: data.len(),
+            });
+        }
+        Ok(MatrixView::column_major(data, shape))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.shape.rows
+    }
+    pub fn columns(&self) -> usize {
+        self.shape.columns
+    }
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+    pub fn is_empty(&self) -> bool {
+        self.shape.is_empty()
+    }
+
+    /// ## Returns a reference to the element at the given row and column
+    /// Returns `None` if the position is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+        if row >= self.shape.rows || column >= self.shape.columns {
+            return None;
+        }
+        let offset = row * self.row_stride + column * self.column_stride;
+        // SAFETY: the offset of a position inside the shape is a valid element (see the struct invariant)
+        Some(unsafe { &*self.data.add(offset) })
+    }
+
+    /// ## View of the submatrix of the given shape, starting at `(row, column)`
+    /// If the submatrix does not fit inside the view, returns an error
+    pub fn submatrix(
+        &self,
+        row: usize,
+        column: usize,
+        shape: Shape,
+    ) -> Result<MatrixView<'a, T>, LalgrsError> {
+        self.strided(row, column, shape, 1, 1)
+    }
+
+    /// ## View of every `row_step`-th row and `column_step`-th column, starting at `(row, column)`
+    /// `shape` is the shape of the resulting view.
+    /// If the range does not fit inside the view or one of the steps is zero, returns an error
+    pub fn strided(
+        &self,
+        row: usize,
+        column: usize,
+        shape: Shape,
+        row_step: usize,
+        column_step: usize,
+    ) -> Result<MatrixView<'a, T>, LalgrsError> {
+        let (offset, row_stride, column_stride) = strided_range(
+            self.shape,
+            (self.row_stride, self.column_stride),
+            (row, column),
+            shape,
+            (row_step, column_step),
+        )?;
+        Ok(MatrixView {
+            // SAFETY: `strided_range` checked that the first element of the range is inside the view
+            data: if shape.is_empty() {
+                self.data
+            } else {
+                unsafe { self.data.add(offset) }
+            },
+            shape,
+            row_stride,
+            column_stride,
+            marker: PhantomData,
+        })
+    }
+
+    /// ## View of a single row
+    pub fn row(&self, row: usize) -> Result<MatrixView<'a, T>, LalgrsError> {
+        self.submatrix(row, 0, Shape::new(1, self.shape.columns))
+    }
+
+    /// ## View of a single column
+    pub fn column(&self, column: usize) -> Result<MatrixView<'a, T>, LalgrsError> {
+        self.submatrix(0, column, Shape::new(self.shape.rows, 1))
+    }
+
+    /// ## Transposed view. Does not move any element
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            shape: self.shape.transposed(),
+            row_stride: self.column_stride,
+            column_stride: self.row_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Iterates over the elements of the view, column by column
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        let view = *self;
+        (0..view.shape.columns)
+            .flat_map(move |j| (0..view.shape.rows).map(move |i| (i, j)))
+            .filter_map(move |(i, j)| view.get(i, j))
+    }
+}
+
+impl<T: Add<T, Output = T> + Clone> MatrixView<'_, T> {
+    /// ## Copies the elements of the view into a new matrix
+    pub fn to_matrix(&self) -> LalgrsMatrix<T> {
+        LalgrsMatrix::from_fn(self.shape.rows, self.shape.columns, |i, j| {
+            self[(i, j)].clone()
+        })
+    }
+
+    /// ## Copies the elements of the view into a new vector, column by column
+    /// Mostly useful for views of a single row or column
+    pub fn to_vector(&self) -> LalgrsVector<T> {
+        LalgrsVector::new(self.iter().cloned().collect())
+    }
+}
+
+/// ## Indexing by `(row, column)`
+/// Panics if the position is out of bounds. Use `MatrixView::get` for a non panicking alternative
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+    #[allow(clippy::panic)]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        match self.get(row, column) {
+            Some(value) => value,
+            None => panic!(
+                "index ({row}, {column}) out of bounds for view of shape {}",
+                self.shape
+            ),
+        }
+    }
+}
+
+/// ## Element wise comparison. Strides are not compared
+impl<T: PartialEq> PartialEq for MatrixView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Add<T, Output = T> + Clone> From<MatrixView<'_, T>> for LalgrsMatrix<T> {
+    fn from(value: MatrixView<'_, T>) -> Self {
+        value.to_matrix()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Mutable views
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// ## Creates a mutable view over a buffer of elements stored column by column
+    /// If `shape.rows * shape.columns` overflows or is not the length of the buffer, returns an error
+    pub fn from_column_major_slice(
+        data: &'a mut [T],
+        shape: Shape,
+    ) -> Result<MatrixViewMut<'a, T>, LalgrsError> {
+        let expected = shape
+            .checked_size()
+            .ok_or(LalgrsError::InvalidMatrixDimensions)?;
+        if data.len() != expected {
+            return Err(LalgrsError::InvalidElementCount {
+                expected,
+                found: data.len(),
+            });
+        }
+        Ok(MatrixViewMut {
+            data: data.as_mut_ptr(),
+            shape,
+            row_stride: 1,
+            column_stride: shape.rows,
+            marker: PhantomData,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.shape.rows
+    }
+    pub fn columns(&self) -> usize {
+        self.shape.columns
+    }
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+    pub fn is_empty(&self) -> bool {
+        self.shape.is_empty()
+    }
+
+    fn offset(&self, row: usize, column: usize) -> Option<usize> {
+        if row >= self.shape.rows || column >= self.shape.columns {
+            return None;
+        }
+        Some(row * self.row_stride + column * self.column_stride)
+    }
+
+    /// ## Returns a reference to the element at the given row and column
+    /// Returns `None` if the position is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        let offset = self.offset(row, column)?;
+        // SAFETY: the offset of a position inside the shape is a valid element (see the struct invariant)
+        Some(unsafe { &*self.data.add(offset) })
+    }
+
+    /// ## Returns a mutable reference to the element at the given row and column
+    /// Returns `None` if the position is out of bounds
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        let offset = self.offset(row, column)?;
+        // SAFETY: as in `get`, and `&mut self` guarantees the element is not otherwise borrowed
+        Some(unsafe { &mut *self.data.add(offset) })
+    }
+
+    /// ## Read only view of the same elements
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        // Only the elements inside the shape are ever read, and `&self` guarantees they are not mutated
+        // while the view is alive. Elements between them may belong to a sibling view from a split.
+        MatrixView {
+            data: self.data,
+            shape: self.shape,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Reborrows the view with a shorter lifetime, so it can be passed on without being consumed
+    pub fn reborrow(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            data: self.data,
+            shape: self.shape,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Mutable view of every `row_step`-th row and `column_step`-th column, starting at `(row, column)`
+    /// Consumes the view; call `reborrow` first to keep using it afterwards.
+    /// If the range does not fit inside the view or one of the steps is zero, returns an error
+    pub fn into_strided(
+        self,
+        row: usize,
+        column: usize,
+        shape: Shape,
+        row_step: usize,
+        column_step: usize,
+    ) -> Result<MatrixViewMut<'a, T>, LalgrsError> {
+        let (offset, row_stride, column_stride) = strided_range(
+            self.shape,
+            (self.row_stride, self.column_stride),
+            (row, column),
+            shape,
+            (row_step, column_step),
+        )?;
+        Ok(MatrixViewMut {
+            // SAFETY: `strided_range` checked that the first element of the range is inside the view
+            data: if shape.is_empty() {
+                self.data
+            } else {
+                unsafe { self.data.add(offset) }
+            },
+            shape,
+            row_stride,
+            column_stride,
+            marker: PhantomData,
+        })
+    }
+
+    /// ## Mutable view of the submatrix of the given shape, starting at `(row, column)`
+    /// If the submatrix does not fit inside the view, returns an error
+    pub fn into_submatrix(
+        self,
+        row: usize,
+        column: usize,
+        shape: Shape,
+    ) -> Result<MatrixViewMut<'a, T>, LalgrsError> {
+        self.into_strided(row, column, shape, 1, 1)
+    }
+
+    /// ## Splits the view into the rows before `row` and the rows from `row` onwards
+    /// If `row` is greater than the number of rows, returns an error
+    pub fn split_at_row(
+        self,
+        row: usize,
+    ) -> Result<(MatrixViewMut<'a, T>, MatrixViewMut<'a, T>), LalgrsError> {
+        let (rows, columns) = (self.shape.rows, self.shape.columns);
+        let top = self
+            .reborrow_unbounded()
+            .into_submatrix(0, 0, Shape::new(row, columns))?;
+        let bottom = self.into_submatrix(row, 0, Shape::new(rows - row, columns))?;
+        Ok((top, bottom))
+    }
+
+    /// ## Splits the view into the columns before `column` and the columns from `column` onwards
+    /// If `column` is greater than the number of columns, returns an error
+    pub fn split_at_column(
+        self,
+        column: usize,
+    ) -> Result<(MatrixViewMut<'a, T>, MatrixViewMut<'a, T>), LalgrsError> {
+        let (rows, columns) = (self.shape.rows, self.shape.columns);
+        let left = self
+            .reborrow_unbounded()
+            .into_submatrix(0, 0, Shape::new(rows, column))?;
+        let right = self.into_submatrix(0, column, Shape::new(rows, columns - column))?;
+        Ok((left, right))
+    }
+
+    // Copy of the view with the same lifetime. Only used by the split methods,
+    // which hand out views over disjoint sets of elements.
+    fn reborrow_unbounded(&self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            data: self.data,
+            shape: self.shape,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Transposed view. Does not move any element
+    pub fn transpose(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            data: self.data,
+            shape: self.shape.transposed(),
+            row_stride: self.column_stride,
+            column_stride: self.row_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## Calls `f` on every element of the view, column by column
+    pub fn apply<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        for j in 0..self.shape.columns {
+            for i in 0..self.shape.rows {
+                if let Some(value) = self.get_mut(i, j) {
+                    f(value);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Clone> MatrixViewMut<'_, T> {
+    /// ## Sets every element of the view to `value`
+    pub fn fill(&mut self, value: T) {
+        self.apply(|v| *v = value.clone());
+    }
+
+    /// ## Copies the elements of `source` into the view
+    /// If the shapes are different, returns an error
+    pub fn copy_from(&mut self, source: &MatrixView<'_, T>) -> Result<(), LalgrsError> {
+        if self.shape != source.shape() {
+            return Err(LalgrsError::MismatchedMatrixDimensions {
+                lhs: self.shape,
+                rhs: source.shape(),
+            });
+        }
+        for j in 0..self.shape.columns {
+            for i in 0..self.shape.rows {
+                if let (Some(target), Some(value)) = (self.get_mut(i, j), source.get(i, j)) {
+                    *target = value.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+    #[allow(clippy::panic)]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        match self.get(row, column) {
+            Some(value) => value,
+            None => panic!(
+                "index ({row}, {column}) out of bounds for view of shape {}",
+                self.shape
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    #[allow(clippy::panic)]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        let shape = self.shape;
+        match self.get_mut(row, column) {
+            Some(value) => value,
+            None => panic!("index ({row}, {column}) out of bounds for view of shape {shape}"),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # Views of a matrix
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Add<T, Output = T>> LalgrsMatrix<T> {
+    /// ## Read only view of the whole matrix
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView::column_major(&self.data, self.shape)
+    }
+
+    /// ## Mutable view of the whole matrix
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            data: self.data.as_mut_ptr(),
+            shape: self.shape,
+            row_stride: 1,
+            column_stride: self.shape.rows,
+            marker: PhantomData,
+        }
+    }
+
+    /// ## View of the submatrix of the given shape, starting at `(row, column)`
+    /// If the submatrix does not fit inside the matrix, returns an error
+    pub fn submatrix(
+        &self,
+        row: usize,
+        column: usize,
+        shape: Shape,
+    ) -> Result<MatrixView<'_, T>, LalgrsError> {
+        self.view().submatrix(row, column, shape)
+    }
+
+    /// ## Mutable view of the submatrix of the given shape, starting at `(row, column)`
+    /// If the submatrix does not fit inside the matrix, returns an error
+    pub fn submatrix_mut(
+        &mut self,
+        row: usize,
+        column: usize,
+        shape: Shape,
+    ) -> Result<MatrixViewMut<'_, T>, LalgrsError> {
+        self.view_mut().into_submatrix(row, column, shape)
+    }
+
+    /// ## View of every `row_step`-th row and `column_step`-th column, starting at `(row, column)`
+    /// If the range does not fit inside the matrix or one of the steps is zero, returns an error
+    pub fn strided(
+        &self,
+        row: usize,
+        column: usize,
+        shape: Shape,
+        row_step: usize,
+        column_step: usize,
+    ) -> Result<MatrixView<'_, T>, LalgrsError> {
+        self.view()
+            .strided(row, column, shape, row_step, column_step)
+    }
+
+    /// ## View of a single row
+    pub fn row(&self, row: usize) -> Result<MatrixView<'_, T>, LalgrsError> {
+        self.view().row(row)
+    }
+
+    /// ## View of a single column
+    pub fn column(&self, column: usize) -> Result<MatrixView<'_, T>, LalgrsError> {
+        self.view().column(column)
+    }
+
+    /// ## Mutable view of a single row
+    pub fn row_mut(&mut self, row: usize) -> Result<MatrixViewMut<'_, T>, LalgrsError> {
+        let columns = self.shape.columns;
+        self.view_mut()
+            .into_submatrix(row, 0, Shape::new(1, columns))
+    }
+
+    /// ## Mutable view of a single column
+    pub fn column_mut(&mut self, column: usize) -> Result<MatrixViewMut<'_, T>, LalgrsError> {
+        let rows = self.shape.rows;
+        self.view_mut()
+            .into_submatrix(0, column, Shape::new(rows, 1))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// # View Operations
+///////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// ## Anything that can be read as a matrix view
+/// Implemented for matrices, references to matrices and views,
+/// so functions can accept any of them through a single generic parameter
+pub trait AsMatrixView<T> {
+    fn as_matrix_view(&self) -> MatrixView<'_, T>;
+}
+
+impl<T: Add<T, Output = T>> AsMatrixView<T> for LalgrsMatrix<T> {
+    fn as_matrix_view(&self) -> MatrixView<'_, T> {
+        self.view()
+    }
+}
+
+impl<T> AsMatrixView<T> for MatrixView<'_, T> {
+    fn as_matrix_view(&self) -> MatrixView<'_, T> {
+        *self
+    }
+}
+
+impl<T> AsMatrixView<T> for MatrixViewMut<'_, T> {
+    fn as_matrix_view(&self) -> MatrixView<'_, T> {
+        self.as_view()
+    }
+}
+
+impl<T, M: AsMatrixView<T>> AsMatrixView<T> for &M {
+    fn as_matrix_view(&self) -> MatrixView<'_, T> {
+        (*self).as_matrix_view()
+    }
+}
+
+/// ## Shape of `LalgrsMatrix::new(vec![])`, which operators treat as a neutral operand
+const NULL_SHAPE: Shape = Shape {
+    rows: 0,
+    columns: 0,
+};
+
+/// ## Addition between two views
+/// If one of the two views is `0x0`, returns a copy of the other one.
+/// If the two views have different shapes, returns an error
+pub(crate) fn add<T: Add<T, Output = T> + Clone>(
+    lhs: MatrixView<'_, T>,
+    rhs: MatrixView<'_, T>,
+) -> Result<LalgrsMatrix<T>, LalgrsError> {
+    if lhs.shape() == NULL_SHAPE {
+        return Ok(rhs.to_matrix());
+    }
+
+    if rhs.shape() == NULL_SHAPE {
+        return Ok(lhs.to_matrix());
+    }
+
+    if lhs.shape() != rhs.shape() {
+        return Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: lhs.shape(),
+            rhs: rhs.shape(),
+        });
+    }
+
+    Ok(LalgrsMatrix::from_fn(lhs.rows(), lhs.columns(), |i, j| {
+        lhs[(i, j)].clone() + rhs[(i, j)].clone()
+    }))
+}
+
+/// ## Negation of a view
+pub(crate) fn neg<T: Add<T, Output = T> + Neg<Output = T> + Clone>(
+    view: MatrixView<'_, T>,
+) -> LalgrsMatrix<T> {
+    LalgrsMatrix::from_fn(view.rows(), view.columns(), |i, j| -view[(i, j)].clone())
+}
+
+/// ## Multiplication between a view and a scalar
+pub(crate) fn scale<T: Add<T, Output = T> + Mul<T, Output = T> + Clone>(
+    view: MatrixView<'_, T>,
+    scalar: T,
+) -> LalgrsMatrix<T> {
+    LalgrsMatrix::from_fn(view.rows(), view.columns(), |i, j| {
+        view[(i, j)].clone() * scalar.clone()
+    })
+}
+
+/// ## Dot product of `len` pairs of elements, zero if `len` is zero
+fn dot<T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero>(
+    len: usize,
+    lhs: impl Fn(usize) -> T,
+    rhs: impl Fn(usize) -> T,
+) -> T {
+    (0..len)
+        .map(|k| lhs(k) * rhs(k))
+        .reduce(|acc, product| acc + product)
+        .unwrap_or_else(T::zero)
+}
+
+/// ## Multiplication between two views
+/// If one of the two views is `0x0`, returns the `0x0` matrix.
+/// If the number of columns of `lhs` does not match the number of rows of `rhs`, returns an error.
+/// An `m x 0` and a `0 x n` view give the `m x n` zero matrix
+pub(crate) fn mul<T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero>(
+    lhs: MatrixView<'_, T>,
+    rhs: MatrixView<'_, T>,
+) -> Result<LalgrsMatrix<T>, LalgrsError> {
+    if lhs.shape() == NULL_SHAPE || rhs.shape() == NULL_SHAPE {
+        return LalgrsMatrix::new(vec![]);
+    }
+
+    if lhs.columns() != rhs.rows() {
+        return Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: lhs.shape(),
+            rhs: rhs.shape(),
+        });
+    }
+
+    Ok(LalgrsMatrix::from_fn(lhs.rows(), rhs.columns(), |i, j| {
+        // Dot product of the i-th row of the lhs operand with the j-th column of the rhs operand
+        dot(
+            lhs.columns(),
+            |k| lhs[(i, k)].clone(),
+            |k| rhs[(k, j)].clone(),
+        )
+    }))
+}
+
+/// ## Multiplication between a view and a vector
+/// If the number of columns of the view does not match the number of elements in the vector, returns an error.
+/// A view without columns gives a vector of zeros
+pub(crate) fn mul_vector<T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero>(
+    lhs: MatrixView<'_, T>,
+    rhs: &LalgrsVector<T>,
+) -> Result<LalgrsVector<T>, LalgrsError> {
+    if lhs.columns() != rhs.size() {
+        return Err(LalgrsError::MismatchedVectorAndMatrixDimensions {
+            vector_size: rhs.size(),
+            matrix_shape: lhs.shape(),
+        });
+    }
+
+    Ok(LalgrsVector::new(
+        (0..lhs.rows())
+            .map(|i| {
+                // Dot product of the i-th row of the view with the vector
+                dot(
+                    lhs.columns(),
+                    |k| lhs[(i, k)].clone(),
+                    |k| rhs.values[k].clone(),
+                )
+            })
+            .collect(),
+    ))
+}
+
+/// ## Matrix whose elements are given by a fallible `f(row, column)`, stopping at the first `None`
+fn try_from_fn<T: Add<T, Output = T> + Clone>(
+    rows: usize,
+    columns: usize,
+    f: impl Fn(usize, usize) -> Option<T>,
+) -> Option<LalgrsMatrix<T>> {
+    let columns: Vec<Vec<T>> = (0..columns)
+        .map(|j| (0..rows).map(|i| f(i, j)).collect())
+        .collect::<Option<_>>()?;
+    LalgrsMatrix::new(columns).ok()
+}
+
+/// ## Dot product of `len` pairs of elements as `dot`, or `None` on overflow
+fn checked_dot<T: Field>(
+    len: usize,
+    lhs: impl Fn(usize) -> T,
+    rhs: impl Fn(usize) -> T,
+) -> Option<T> {
+    (0..len).try_fold(T::zero(), |acc, k| {
+        acc.checked_add(&lhs(k).checked_mul(&rhs(k))?)
+    })
+}
+
+/// ## Element by element combination of two views with the shape rules of `add`
+/// If the two views have different shapes, or `f` overflows, returns an error
+pub(crate) fn checked_elementwise<T: Field>(
+    lhs: MatrixView<'_, T>,
+    rhs: MatrixView<'_, T>,
+    f: impl Fn(&T, &T) -> Option<T>,
+) -> Result<LalgrsMatrix<T>, LalgrsError> {
+    let zero = T::zero();
+    if lhs.shape() == NULL_SHAPE {
+        return try_from_fn(rhs.rows(), rhs.columns(), |i, j| f(&zero, &rhs[(i, j)]))
+            .ok_or(LalgrsError::ArithmeticOverflow);
+    }
+    if rhs.shape() == NULL_SHAPE {
+        return Ok(lhs.to_matrix());
+    }
+    if lhs.shape() != rhs.shape() {
+        return Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: lhs.shape(),
+            rhs: rhs.shape(),
+        });
+    }
+    try_from_fn(lhs.rows(), lhs.columns(), |i, j| {
+        f(&lhs[(i, j)], &rhs[(i, j)])
+    })
+    .ok_or(LalgrsError::ArithmeticOverflow)
+}
+
+/// ## Multiplication between two views as `mul`, reporting overflow as an error
+pub(crate) fn checked_mul<T: Field>(
+    lhs: MatrixView<'_, T>,
+    rhs: MatrixView<'_, T>,
+) -> Result<LalgrsMatrix<T>, LalgrsError> {
+    if lhs.shape() == NULL_SHAPE || rhs.shape() == NULL_SHAPE {
+        return LalgrsMatrix::new(vec![]);
+    }
+    if lhs.columns() != rhs.rows() {
+        return Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: lhs.shape(),
+            rhs: rhs.shape(),
+        });
+    }
+    try_from_fn(lhs.rows(), rhs.columns(), |i, j| {
+        checked_dot(
+            lhs.columns(),
+            |k| lhs[(i, k)].clone(),
+            |k| rhs[(k, j)].clone(),
+        )
+    })
+    .ok_or(LalgrsError::ArithmeticOverflow)
+}
+
+/// ## Multiplication between a view and a vector as `mul_vector`, reporting overflow as an error
+pub(crate) fn checked_mul_vector<T: Field>(
+    lhs: MatrixView<'_, T>,
+    rhs: &LalgrsVector<T>,
+) -> Result<LalgrsVector<T>, LalgrsError> {
+    if lhs.columns() != rhs.size() {
+        return Err(LalgrsError::MismatchedVectorAndMatrixDimensions {
+            vector_size: rhs.size(),
+            matrix_shape: lhs.shape(),
+        });
+    }
+    let values = (0..lhs.rows())
+        .map(|i| {
+            checked_dot(
+                lhs.columns(),
+                |k| lhs[(i, k)].clone(),
+                |k| rhs.values[k].clone(),
+            )
+        })
+        .collect::<Option<Vec<T>>>()
+        .ok_or(LalgrsError::ArithmeticOverflow)?;
+    Ok(LalgrsVector::new(values))
+}
+
+/// ## Operators between every combination of views, matrices and references to matrices
+/// The combination of two owned matrices is implemented next to the matrix definition
+macro_rules! impl_view_operators {
+    ($(($lhs:ty, $rhs:ty)),* $(,)?) => {
+        $(
+            impl<'a, 'b, T: Add<T, Output = T> + Clone> ops::Add<$rhs> for $lhs {
+                type Output = Result<LalgrsMatrix<T>, LalgrsError>;
+                fn add(self, rhs: $rhs) -> Self::Output {
+                    add(self.as_matrix_view(), rhs.as_matrix_view())
+                }
+            }
+
+            impl<'a, 'b, T: Add<T, Output = T> + Neg<Output = T> + Clone> ops::Sub<$rhs> for $lhs {
+                type Output = Result<LalgrsMatrix<T>, LalgrsError>;
+                fn sub(self, rhs: $rhs) -> Self::Output {
+                    add(self.as_matrix_view(), neg(rhs.as_matrix_view()).view())
+                }
+            }
+
+            impl<'a, 'b, T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero> ops::Mul<$rhs> for $lhs {
+                type Output = Result<LalgrsMatrix<T>, LalgrsError>;
+                fn mul(self, rhs: $rhs) -> Self::Output {
+                    mul(self.as_matrix_view(), rhs.as_matrix_view())
+                }
+            }
+        )*
+    };
+}
+
+impl_view_operators!(
+    (MatrixView<'a, T>, MatrixView<'b, T>),
+    (MatrixView<'a, T>, LalgrsMatrix<T>),
+    (MatrixView<'a, T>, &'b LalgrsMatrix<T>),
+    (LalgrsMatrix<T>, MatrixView<'b, T>),
+    (&'a LalgrsMatrix<T>, MatrixView<'b, T>),
+    (&'a LalgrsMatrix<T>, &'b LalgrsMatrix<T>),
+    (&'a LalgrsMatrix<T>, LalgrsMatrix<T>),
+    (LalgrsMatrix<T>, &'b LalgrsMatrix<T>),
+    (MatrixViewMut<'a, T>, MatrixViewMut<'b, T>),
+    (MatrixViewMut<'a, T>, MatrixView<'b, T>),
+    (MatrixView<'a, T>, MatrixViewMut<'b, T>),
+    (MatrixViewMut<'a, T>, LalgrsMatrix<T>),
+    (MatrixViewMut<'a, T>, &'b LalgrsMatrix<T>),
+    (LalgrsMatrix<T>, MatrixViewMut<'b, T>),
+    (&'a LalgrsMatrix<T>, MatrixViewMut<'b, T>),
+);
+
+/// ## Unary and scalar operators on views and references to matrices
+macro_rules! impl_unary_view_operators {
+    ($($operand:ty),* $(,)?) => {
+        $(
+            impl<'a, T: Add<T, Output = T> + Neg<Output = T> + Clone> ops::Neg for $operand {
+                type Output = LalgrsMatrix<T>;
+                fn neg(self) -> Self::Output {
+                    neg(self.as_matrix_view())
+                }
+            }
+
+            impl<'a, T: Add<T, Output = T> + Mul<T, Output = T> + Clone> ops::Mul<T> for $operand {
+                type Output = LalgrsMatrix<T>;
+                fn mul(self, rhs: T) -> Self::Output {
+                    scale(self.as_matrix_view(), rhs)
+                }
+            }
+
+            impl<'a, T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero> ops::Mul<LalgrsVector<T>> for $operand {
+                type Output = Result<LalgrsVector<T>, LalgrsError>;
+                fn mul(self, rhs: LalgrsVector<T>) -> Self::Output {
+                    mul_vector(self.as_matrix_view(), &rhs)
+                }
+            }
+
+            impl<'a, 'b, T: Add<T, Output = T> + Mul<T, Output = T> + Clone + Zero> ops::Mul<&'b LalgrsVector<T>> for $operand {
+                type Output = Result<LalgrsVector<T>, LalgrsError>;
+                fn mul(self, rhs: &'b LalgrsVector<T>) -> Self::Output {
+                    mul_vector(self.as_matrix_view(), rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_unary_view_operators!(MatrixView<'a, T>, MatrixViewMut<'a, T>, &'a LalgrsMatrix<T>);
@@ -0,0 +1,101 @@
+use lalgrs::{
+    approx::{matrix_mismatch_report, scalar_approx_eq},
+    assert_matrix_approx_eq, assert_vector_approx_eq, LalgrsMatrix, LalgrsVector, Rational, Ulps,
+};
+
+#[test]
+fn test_scalar_approx_eq() {
+    assert!(scalar_approx_eq(&1.0, &(1.0 + 1e-13), 1e-12, 0.0));
+    assert!(!scalar_approx_eq(&1.0, &(1.0 + 1e-11), 1e-12, 0.0));
+    // Relative tolerance scales with the magnitude of the values
+    assert!(scalar_approx_eq(&1e10, &(1e10 + 1.0), 0.0, 1e-9));
+    assert!(!scalar_approx_eq(&1e-10, &2e-10, 0.0, 1e-9));
+    assert!(scalar_approx_eq(&f64::INFINITY, &f64::INFINITY, 0.0, 0.0));
+    assert!(!scalar_approx_eq(&f64::NAN, &f64::NAN, 1.0, 1.0));
+    assert!(scalar_approx_eq(
+        &Rational::new(1, 3).unwrap(),
+        &Rational::new(333, 1000).unwrap(),
+        1e-3,
+        0.0
+    ));
+}
+
+#[test]
+fn test_ulps_distance() {
+    assert_eq!(1f64.ulps_distance(&1.0), Some(0));
+    assert_eq!(1f64.ulps_distance(&(1.0 + f64::EPSILON)), Some(1));
+    assert_eq!(0f64.ulps_distance(&-0.0), Some(0));
+    // Through zero, the distance is the sum of the distances to zero
+    let tiny = f64::from_bits(1);
+    assert_eq!(tiny.ulps_distance(&-tiny), Some(2));
+    assert_eq!(1f32.ulps_distance(&(1.0 - f32::EPSILON / 2.0)), Some(1));
+    assert_eq!(f64::NAN.ulps_distance(&1.0), None);
+    assert_eq!(
+        f64::MAX.ulps_distance(&-f64::MAX),
+        Some(0xFFDF_FFFF_FFFF_FFFE)
+    );
+}
+
+#[test]
+fn test_matrix_and_vector_comparison() {
+    let a: LalgrsMatrix<f64> =
+        LalgrsMatrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    let b = LalgrsMatrix::from_rows(vec![vec![1.0, 2.0 + 1e-14], vec![3.0, 4.0]]).unwrap();
+    assert!(a.approx_eq(&b, 1e-12, 0.0));
+    assert!(!a.approx_eq(&b, 1e-15, 1e-15));
+    assert!(!a.approx_eq(&a.transpose(), 0.5, 0.0));
+    assert!(!a.approx_eq(&LalgrsMatrix::zeros(2, 3), 1e10, 0.0));
+
+    let next = LalgrsMatrix::from_fn(2, 2, |i, j| f64::from_bits(a[(i, j)].to_bits() + 2));
+    assert!(a.ulps_eq(&next, 2));
+    assert!(!a.ulps_eq(&next, 1));
+
+    let u = LalgrsVector::new(vec![1.0, -1.0]);
+    let v = LalgrsVector::new(vec![1.0 + 1e-13, -1.0]);
+    assert!(u.approx_eq(&v, 1e-12, 0.0));
+    assert!(!u.approx_eq(&LalgrsVector::new(vec![1.0]), 1.0, 1.0));
+    assert!(u.ulps_eq(&u, 0));
+    assert!(!u.ulps_eq(&v, 4));
+
+    assert_matrix_approx_eq!(a, b, 1e-12);
+    assert_vector_approx_eq!(u, v, 1e-12, 0.0);
+}
+
+#[test]
+fn test_mismatch_report() {
+    let a = LalgrsMatrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    let b = LalgrsMatrix::from_rows(vec![vec![1.0, 2.5], vec![3.0, 3.0]]).unwrap();
+    assert_eq!(matrix_mismatch_report(&a, &a, 0.0, 0.0), None);
+    let report = matrix_mismatch_report(&a, &b, 1e-12, 0.0).unwrap();
+    assert_eq!(
+        report,
+        "  (0, 1): left = 2, right = 2.5, difference = 0.5\n  \
+         (1, 1): left = 4, right = 3, difference = 1\n\
+         2 differing elements, maximum deviation 1 at (1, 1) \
+         (absolute tolerance 0.000000000001, relative tolerance 0)"
+    );
+    assert_eq!(
+        matrix_mismatch_report(&a, &a.transpose(), 1e-12, 0.0).map(|r| r.contains("(1, 0)")),
+        Some(true)
+    );
+    assert_eq!(
+        matrix_mismatch_report(&a, &LalgrsMatrix::zeros(1, 2), 1e-12, 0.0),
+        Some("shapes differ: left is 2x2, right is 1x2".to_string())
+    );
+}
+
+#[test]
+#[should_panic(expected = "maximum deviation 0.5 at (0, 1)")]
+fn test_assert_matrix_approx_eq_fails() {
+    let a = LalgrsMatrix::from_rows(vec![vec![1.0, 2.0]]).unwrap();
+    let b = LalgrsMatrix::from_rows(vec![vec![1.0, 2.5]]).unwrap();
+    assert_matrix_approx_eq!(a, b, 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "[1]: left = 2, right = 3")]
+fn test_assert_vector_approx_eq_fails() {
+    let u = LalgrsVector::new(vec![1.0, 2.0]);
+    let v = LalgrsVector::new(vec![1.0, 3.0]);
+    assert_vector_approx_eq!(u, v, 1e-6, 1e-6);
+}
@@ -0,0 +1,132 @@
+use lalgrs::{LalgrsError, LalgrsMatrix, Shape};
+
+fn init_matrices() -> (LalgrsMatrix<i32>, LalgrsMatrix<i32>) {
+    let a = LalgrsMatrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    let b = LalgrsMatrix::from_rows(vec![vec![0, 5, 6]]).unwrap();
+    (a, b)
+}
+
+#[test]
+fn test_kronecker() {
+    let (a, b) = init_matrices();
+    assert_eq!(
+        a.kronecker(&b),
+        LalgrsMatrix::from_rows(vec![vec![0, 5, 6, 0, 10, 12], vec![0, 15, 18, 0, 20, 24]])
+            .unwrap()
+    );
+
+    // Mixed product property: (A ⊗ B)(C ⊗ D) = AC ⊗ BD
+    let pauli_x = LalgrsMatrix::from_rows(vec![vec![0, 1], vec![1, 0]]).unwrap();
+    let pauli_z = LalgrsMatrix::from_rows(vec![vec![1, 0], vec![0, -1]]).unwrap();
+    let lhs = (pauli_x.kronecker(&pauli_z) * pauli_z.kronecker(&pauli_x)).unwrap();
+    let rhs = (pauli_x.clone() * pauli_z.clone())
+        .unwrap()
+        .kronecker(&(pauli_z.clone() * pauli_x.clone()).unwrap());
+    assert_eq!(lhs, rhs);
+    assert_eq!(
+        LalgrsMatrix::<i32>::identity(2).kronecker(&LalgrsMatrix::identity(4)),
+        LalgrsMatrix::identity(8)
+    );
+    assert_eq!(
+        a.kronecker(&LalgrsMatrix::zeros(0, 3)).shape(),
+        Shape::new(0, 6)
+    );
+}
+
+#[test]
+fn test_direct_sum() {
+    let (a, b) = init_matrices();
+    assert_eq!(
+        a.direct_sum(&b),
+        LalgrsMatrix::from_rows(vec![
+            vec![1, 2, 0, 0, 0],
+            vec![3, 4, 0, 0, 0],
+            vec![0, 0, 0, 5, 6],
+        ])
+        .unwrap()
+    );
+    assert_eq!(a.direct_sum(&LalgrsMatrix::zeros(0, 0)), a);
+}
+
+#[test]
+fn test_stacking() {
+    let (a, b) = init_matrices();
+    let column = LalgrsMatrix::from_rows(vec![vec![7], vec![8]]).unwrap();
+    assert_eq!(
+        LalgrsMatrix::hstack(&[&a, &column, &a]).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1, 2, 7, 1, 2], vec![3, 4, 8, 3, 4]]).unwrap()
+    );
+    let row = LalgrsMatrix::from_rows(vec![vec![9, 9]]).unwrap();
+    assert_eq!(
+        LalgrsMatrix::vstack(&[&a, &row]).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![9, 9]]).unwrap()
+    );
+    assert_eq!(
+        LalgrsMatrix::hstack(&[&a, &b]),
+        Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(2, 3),
+            rhs: Shape::new(1, 3)
+        })
+    );
+    assert_eq!(
+        LalgrsMatrix::vstack(&[&a, &b]),
+        Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(1, 2),
+            rhs: Shape::new(1, 3)
+        })
+    );
+    assert_eq!(
+        LalgrsMatrix::<i32>::hstack(&[]).unwrap().shape(),
+        Shape::new(0, 0)
+    );
+}
+
+#[test]
+fn test_block_assembly() {
+    // Saddle point matrix [A Bᵀ; B 0]
+    let a = LalgrsMatrix::from_rows(vec![vec![4.0, 1.0], vec![1.0, 3.0]]).unwrap();
+    let b = LalgrsMatrix::from_rows(vec![vec![1.0, -1.0]]).unwrap();
+    let zero = LalgrsMatrix::zeros(1, 1);
+    let saddle = LalgrsMatrix::block(&[&[&a, &b.transpose()], &[&b, &zero]]).unwrap();
+    assert_eq!(
+        saddle,
+        LalgrsMatrix::from_rows(vec![
+            vec![4.0, 1.0, 1.0],
+            vec![1.0, 3.0, -1.0],
+            vec![1.0, -1.0, 0.0],
+        ])
+        .unwrap()
+    );
+
+    // Splitting undoes the assembly
+    let (top, bottom) = saddle.split_at_row(2).unwrap();
+    let (top_left, top_right) = top.split_at_column(2).unwrap();
+    assert_eq!(top_left, a);
+    assert_eq!(top_right, b.transpose());
+    assert_eq!(
+        bottom.split_at_column(2).unwrap(),
+        (b.clone(), zero.clone())
+    );
+    let (all, none) = saddle.split_at_row(3).unwrap();
+    assert_eq!((all, none.shape()), (saddle.clone(), Shape::new(0, 3)));
+
+    assert_eq!(
+        LalgrsMatrix::block(&[&[&a, &b.transpose()], &[&b]]),
+        Err(LalgrsError::InvalidMatrixDimensions)
+    );
+    assert_eq!(
+        LalgrsMatrix::block(&[&[&a, &b], &[&b, &zero]]),
+        Err(LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(2, 2),
+            rhs: Shape::new(1, 2)
+        })
+    );
+    assert!(matches!(
+        saddle.split_at_row(4),
+        Err(LalgrsError::SubmatrixOutOfBounds { .. })
+    ));
+    assert!(matches!(
+        saddle.split_at_column(4),
+        Err(LalgrsError::SubmatrixOutOfBounds { .. })
+    ));
+}
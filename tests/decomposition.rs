@@ -0,0 +1,273 @@
+use lalgrs::{
+    assert_matrix_approx_eq, assert_vector_approx_eq, Complex, LalgrsError, LalgrsMatrix,
+    LalgrsVector, Rng,
+};
+
+fn assert_orthonormal_columns(matrix: &LalgrsMatrix<f64>) {
+    let gram = (matrix.transpose() * matrix.clone()).unwrap();
+    assert_matrix_approx_eq!(gram, LalgrsMatrix::identity(matrix.columns()), 1e-12, 0.0);
+}
+
+#[test]
+fn test_svd_reconstructs_matrix() {
+    let mut rng = Rng::seed_from_u64(7);
+    for (rows, columns) in [(5, 3), (3, 5), (4, 4), (1, 6)] {
+        let matrix = LalgrsMatrix::random_uniform(rows, columns, -1.0, 1.0, &mut rng);
+        let svd = matrix.svd().unwrap();
+        let k = rows.min(columns);
+        assert_eq!(svd.u.shape().columns, k);
+        assert_eq!(svd.v_t.shape().rows, k);
+        assert_orthonormal_columns(&svd.u);
+        assert_orthonormal_columns(&svd.v_t.transpose());
+        assert!(svd
+            .singular_values
+            .values
+            .iter()
+            .zip(svd.singular_values.values.iter().skip(1))
+            .all(|(a, b)| a >= b && *b >= 0.0));
+        assert_matrix_approx_eq!(svd.reconstruct(), matrix, 1e-12, 0.0);
+    }
+}
+
+#[test]
+fn test_svd_known_values() {
+    let matrix = LalgrsMatrix::from_rows(vec![vec![3.0, 0.0], vec![0.0, -4.0]]).unwrap();
+    let svd = matrix.svd().unwrap();
+    assert_eq!(svd.singular_values.values, [4.0, 3.0]);
+    assert_eq!(svd.rank(1e-12), 2);
+}
+
+#[test]
+fn test_svd_rank_deficient() {
+    // Every column is a multiple of the first one
+    let matrix = LalgrsMatrix::from_fn(4, 3, |i, j| (i + 1) as f64 * (j + 1) as f64);
+    let svd = matrix.svd().unwrap();
+    assert_eq!(svd.rank(1e-10), 1);
+    assert_orthonormal_columns(&svd.u);
+    assert_matrix_approx_eq!(svd.reconstruct(), matrix, 1e-12, 0.0);
+
+    let truncated = svd.truncate(1);
+    assert_eq!(truncated.singular_values.size(), 1);
+    assert_matrix_approx_eq!(truncated.reconstruct(), matrix, 1e-12, 0.0);
+    assert_eq!(svd.truncate(10), svd);
+
+    let zero = LalgrsMatrix::<f64>::zeros(3, 2);
+    let svd = zero.svd().unwrap();
+    assert_eq!(svd.rank(0.0), 0);
+    assert_orthonormal_columns(&svd.u);
+
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(0, 2).svd().unwrap_err(),
+        LalgrsError::EmptyMatrix
+    );
+}
+
+fn init_indefinite() -> LalgrsMatrix<f64> {
+    // Zero diagonal, so Bunch-Kaufman has to use 2x2 pivots
+    LalgrsMatrix::from_rows(vec![
+        vec![0.0, 1.0, 2.0, 0.5],
+        vec![1.0, 0.0, -1.0, 3.0],
+        vec![2.0, -1.0, 0.0, 1.0],
+        vec![0.5, 3.0, 1.0, 0.0],
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_cholesky() {
+    let mut rng = Rng::seed_from_u64(3);
+    let matrix = LalgrsMatrix::random_spd(6, &mut rng);
+    let cholesky = matrix.cholesky().unwrap();
+    let l = cholesky.l();
+    assert!((0..6).all(|i| (i + 1..6).all(|j| l[(i, j)] == 0.0) && l[(i, i)] > 0.0));
+    assert_matrix_approx_eq!(cholesky.reconstruct(), matrix, 1e-10, 0.0);
+
+    let b = LalgrsVector::new(vec![1.0, -2.0, 0.5, 3.0, 0.0, 1.0]);
+    let x = cholesky.solve(&b).unwrap();
+    assert_vector_approx_eq!((matrix.clone() * x).unwrap(), b, 1e-10, 0.0);
+
+    let rhs = LalgrsMatrix::random_uniform(6, 2, -1.0, 1.0, &mut rng);
+    let solution = cholesky.solve_matrix(&rhs).unwrap();
+    assert_matrix_approx_eq!((matrix.clone() * solution).unwrap(), rhs, 1e-10, 0.0);
+
+    let known = LalgrsMatrix::from_rows(vec![vec![4.0, 2.0], vec![2.0, 5.0]]).unwrap();
+    let cholesky = known.cholesky().unwrap();
+    assert_eq!(
+        cholesky.l(),
+        &LalgrsMatrix::from_rows(vec![vec![2.0, 0.0], vec![1.0, 2.0]]).unwrap()
+    );
+    assert_eq!(cholesky.determinant(), 16.0);
+    assert!((cholesky.log_determinant() - 16f64.ln()).abs() < 1e-12);
+}
+
+#[test]
+fn test_cholesky_errors() {
+    assert_eq!(
+        init_indefinite().cholesky().unwrap_err(),
+        LalgrsError::NotPositiveDefinite
+    );
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(2, 3).cholesky().unwrap_err(),
+        LalgrsError::NonSquareMatrix {
+            shape: (2, 3).into()
+        }
+    );
+    let cholesky = LalgrsMatrix::<f64>::identity(2).cholesky().unwrap();
+    assert_eq!(
+        cholesky
+            .solve(&LalgrsVector::new(vec![1.0, 2.0, 3.0]))
+            .unwrap_err(),
+        LalgrsError::MismatchedVectorAndMatrixDimensions {
+            vector_size: 3,
+            matrix_shape: (2, 2).into()
+        }
+    );
+}
+
+#[test]
+fn test_cholesky_update_and_downdate() {
+    let mut rng = Rng::seed_from_u64(11);
+    let matrix = LalgrsMatrix::random_spd(5, &mut rng);
+    let x = LalgrsVector::new(vec![0.3, -1.2, 0.8, 2.0, -0.5]);
+    let outer = LalgrsMatrix::from_fn(5, 5, |i, j| x.values[i] * x.values[j]);
+
+    let mut cholesky = matrix.cholesky().unwrap();
+    cholesky.rank_one_update(&x).unwrap();
+    let updated = (matrix.clone() + outer.clone()).unwrap();
+    assert_matrix_approx_eq!(cholesky.l(), updated.cholesky().unwrap().l(), 1e-10, 0.0);
+
+    cholesky.rank_one_downdate(&x).unwrap();
+    assert_matrix_approx_eq!(cholesky.l(), matrix.cholesky().unwrap().l(), 1e-10, 0.0);
+
+    // Removing a large enough outer product makes the matrix indefinite
+    let before = cholesky.clone();
+    assert_eq!(
+        cholesky.rank_one_downdate(&(x * 10.0)).unwrap_err(),
+        LalgrsError::NotPositiveDefinite
+    );
+    assert_eq!(cholesky, before);
+}
+
+#[test]
+fn test_ldlt() {
+    let matrix = init_indefinite();
+    let ldlt = matrix.ldlt().unwrap();
+    assert_matrix_approx_eq!(ldlt.reconstruct(), matrix, 1e-12, 0.0);
+    let l = ldlt.l();
+    assert!((0..4).all(|i| l[(i, i)] == 1.0 && (i + 1..4).all(|j| l[(i, j)] == 0.0)));
+    assert!(ldlt.d()[(1, 0)] != 0.0);
+
+    let b = LalgrsVector::new(vec![1.0, 2.0, 3.0, 4.0]);
+    let x = ldlt.solve(&b).unwrap();
+    assert_vector_approx_eq!((matrix.clone() * x).unwrap(), b, 1e-12, 0.0);
+
+    // The absolute value of the determinant is the product of the singular values
+    let singular_value_product = matrix
+        .svd()
+        .unwrap()
+        .singular_values
+        .values
+        .iter()
+        .product::<f64>();
+    assert!((ldlt.determinant().abs() - singular_value_product).abs() < 1e-10);
+    assert_eq!(ldlt.inertia(), (2, 2, 0));
+
+    let spd = LalgrsMatrix::from_rows(vec![vec![4.0, 2.0], vec![2.0, 5.0]]).unwrap();
+    assert_eq!(spd.ldlt().unwrap().determinant(), 16.0);
+    assert_eq!(spd.ldlt().unwrap().inertia(), (2, 0, 0));
+}
+
+#[test]
+fn test_ldlt_singular() {
+    let singular = LalgrsMatrix::from_rows(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![2.0, 4.0, 6.0],
+        vec![3.0, 6.0, -1.0],
+    ])
+    .unwrap();
+    let ldlt = singular.ldlt().unwrap();
+    assert_matrix_approx_eq!(ldlt.reconstruct(), singular, 1e-12, 0.0);
+    assert_eq!(ldlt.determinant(), 0.0);
+    assert_eq!(ldlt.inertia().2, 1);
+    assert_eq!(
+        ldlt.solve(&LalgrsVector::new(vec![1.0, 0.0, 0.0]))
+            .unwrap_err(),
+        LalgrsError::SingularMatrix
+    );
+}
+
+#[test]
+fn test_ldlt_update_and_downdate() {
+    let x = LalgrsVector::new(vec![1.0, -0.5, 2.0, 0.25]);
+    let outer = LalgrsMatrix::from_fn(4, 4, |i, j| x.values[i] * x.values[j]);
+
+    // Diagonal D takes the fast path, 2x2 blocks are decomposed again
+    let mut rng = Rng::seed_from_u64(5);
+    for matrix in [LalgrsMatrix::random_spd(4, &mut rng), init_indefinite()] {
+        let mut ldlt = matrix.ldlt().unwrap();
+        ldlt.rank_one_update(&x).unwrap();
+        assert_matrix_approx_eq!(
+            ldlt.reconstruct(),
+            (matrix.clone() + outer.clone()).unwrap(),
+            1e-10,
+            0.0
+        );
+        ldlt.rank_one_downdate(&x).unwrap();
+        ldlt.rank_one_downdate(&x).unwrap();
+        assert_matrix_approx_eq!(
+            ldlt.reconstruct(),
+            (matrix.clone() - outer.clone()).unwrap(),
+            1e-10,
+            0.0
+        );
+    }
+}
+
+#[test]
+fn test_eigenvalues() {
+    let rotation = LalgrsMatrix::from_rows(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+    assert_eq!(
+        rotation.eigenvalues().unwrap(),
+        vec![Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)]
+    );
+
+    let triangular = LalgrsMatrix::from_rows(vec![
+        vec![4.0, 1.0, 2.0],
+        vec![0.0, -3.0, 5.0],
+        vec![0.0, 0.0, 1.0],
+    ])
+    .unwrap();
+    let mut eigenvalues: Vec<f64> = triangular
+        .eigenvalues()
+        .unwrap()
+        .iter()
+        .map(|z| {
+            assert!(z.is_real());
+            z.re
+        })
+        .collect();
+    eigenvalues.sort_by(f64::total_cmp);
+    assert_vector_approx_eq!(
+        LalgrsVector::new(eigenvalues),
+        LalgrsVector::new(vec![-3.0, 1.0, 4.0]),
+        1e-12,
+        0.0
+    );
+
+    // The eigenvalues of a random matrix sum to its trace and multiply to its determinant
+    let mut rng = Rng::seed_from_u64(7);
+    let a = LalgrsMatrix::random_normal(8, 8, 0.0, 1.0, &mut rng);
+    let eigenvalues = a.eigenvalues().unwrap();
+    let sum = eigenvalues.iter().fold(Complex::default(), |s, z| s + *z);
+    let product = eigenvalues.iter().fold(Complex::from(1.0), |p, z| p * *z);
+    let trace: f64 = (0..8).map(|i| a[(i, i)]).sum();
+    assert!((sum - Complex::from(trace)).abs() < 1e-10);
+    assert!((product - Complex::from(a.determinant().unwrap())).abs() < 1e-9);
+
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(2, 3).eigenvalues().unwrap_err(),
+        LalgrsError::NonSquareMatrix {
+            shape: (2, 3).into()
+        }
+    );
+}
@@ -0,0 +1,178 @@
+use lalgrs::{
+    eigensolvers::{arnoldi, lanczos, power_iteration, Extreme},
+    Complex, LalgrsError, LalgrsMatrix, LalgrsVector, LinearOperator, Rng, Shape,
+};
+
+/// Symmetric matrix `Q * diag(values) * Qᵀ` with a random orthogonal `Q`
+fn symmetric_with_eigenvalues(values: &[f64], rng: &mut Rng) -> LalgrsMatrix<f64> {
+    let n = values.len();
+    let q = LalgrsMatrix::random_orthogonal(n, rng);
+    LalgrsMatrix::from_fn(n, n, |i, j| {
+        (0..n).map(|k| q[(i, k)] * values[k] * q[(j, k)]).sum()
+    })
+}
+
+/// Matrix free second difference operator, the tridiagonal matrix with 2 on the diagonal and -1 beside it
+struct SecondDifference {
+    size: usize,
+}
+
+impl LinearOperator for SecondDifference {
+    fn shape(&self) -> Shape {
+        Shape::new(self.size, self.size)
+    }
+
+    fn apply(&self, x: &LalgrsVector<f64>) -> Result<LalgrsVector<f64>, LalgrsError> {
+        let x: Vec<f64> = x.iter().copied().collect();
+        Ok((0..self.size)
+            .map(|i| {
+                let left = if i > 0 { x[i - 1] } else { 0.0 };
+                let right = x.get(i + 1).copied().unwrap_or(0.0);
+                2.0 * x[i] - left - right
+            })
+            .collect())
+    }
+}
+
+#[test]
+fn test_power_and_inverse_iteration() {
+    let mut rng = Rng::seed_from_u64(48);
+    let mut values: Vec<f64> = (1..=20).map(|i| i as f64).collect();
+    values[19] = -40.0;
+    let a = symmetric_with_eigenvalues(&values, &mut rng);
+
+    let dominant = power_iteration(&a, 1e-10, 1000).unwrap();
+    assert!((dominant.value + 40.0).abs() < 1e-8);
+    assert!(dominant.residual <= 1e-10 * 40.0);
+    let av = (a.clone() * dominant.vector.clone()).unwrap();
+    assert!(av.approx_eq(&dominant.vector.map(|x| -40.0 * x), 1e-8, 0.0));
+
+    // Views are operators too
+    let view = a.submatrix(0, 0, Shape::new(20, 20)).unwrap();
+    assert_eq!(power_iteration(&view, 1e-10, 1000).unwrap(), dominant);
+
+    let near = a.inverse_iteration(7.3, 1e-12, 100).unwrap();
+    assert!((near.value - 7.0).abs() < 1e-10);
+    // A shift that is an eigenvalue converges straight away
+    let exact = a.inverse_iteration(3.0, 1e-12, 100).unwrap();
+    assert!((exact.value - 3.0).abs() < 1e-10);
+    assert!(exact.iterations <= 2);
+
+    let rqi = a
+        .rayleigh_quotient_iteration(&near.vector.map(|x| x + 0.01), 1e-12, 20)
+        .unwrap();
+    assert!((rqi.value - 7.0).abs() < 1e-10);
+    assert!(rqi.iterations < near.iterations);
+
+    let rotation = LalgrsMatrix::from_rows(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+    assert_eq!(
+        power_iteration(&rotation, 1e-10, 50).unwrap_err(),
+        LalgrsError::NoConvergence { iterations: 50 }
+    );
+    assert_eq!(
+        power_iteration(&LalgrsMatrix::<f64>::zeros(2, 3), 1e-10, 50).unwrap_err(),
+        LalgrsError::NonSquareMatrix {
+            shape: Shape::new(2, 3)
+        }
+    );
+    assert_eq!(
+        a.rayleigh_quotient_iteration(&LalgrsVector::new(vec![0.0; 20]), 1e-10, 10)
+            .unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "initial",
+            value: 0.0
+        }
+    );
+}
+
+#[test]
+fn test_lanczos() {
+    let mut rng = Rng::seed_from_u64(480);
+    let values: Vec<f64> = (0..60).map(|i| (i as f64).powi(2) / 10.0 - 5.0).collect();
+    let a = symmetric_with_eigenvalues(&values, &mut rng);
+
+    let largest = lanczos(&a, 3, Extreme::Largest, 1e-10, 60).unwrap();
+    let found: Vec<f64> = largest.iter().map(|p| p.value).collect();
+    for (f, e) in found.iter().zip([values[59], values[58], values[57]]) {
+        assert!((f - e).abs() < 1e-8, "{found:?}");
+    }
+    for pair in &largest {
+        assert!(pair.residual < 1e-7);
+        assert!(pair.iterations <= 60);
+    }
+
+    let smallest = lanczos(&a, 2, Extreme::Smallest, 1e-10, 60).unwrap();
+    assert!((smallest[0].value + 5.0).abs() < 1e-8);
+    assert!((smallest[1].value + 4.9).abs() < 1e-8);
+    // Eigenvectors of distinct eigenvalues are orthogonal
+    let overlap: f64 = largest[0]
+        .vector
+        .iter()
+        .zip(smallest[0].vector.iter())
+        .map(|(x, y)| x * y)
+        .sum();
+    assert!(overlap.abs() < 1e-8);
+
+    // A matrix free operator, with eigenvalues 2 - 2cos(kπ / (n + 1))
+    let n = 100;
+    let operator = SecondDifference { size: n };
+    let pairs = lanczos(&operator, 2, Extreme::Largest, 1e-10, n).unwrap();
+    for (k, pair) in [n, n - 1].into_iter().zip(&pairs) {
+        let expected = 2.0 - 2.0 * (k as f64 * std::f64::consts::PI / (n + 1) as f64).cos();
+        assert!((pair.value - expected).abs() < 1e-8);
+    }
+
+    assert_eq!(
+        lanczos(&a, 0, Extreme::Largest, 1e-10, 60).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "k",
+            value: 0.0
+        }
+    );
+    assert_eq!(
+        lanczos(&a, 3, Extreme::Largest, 1e-10, 2).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "max_iterations",
+            value: 2.0
+        }
+    );
+    assert_eq!(
+        lanczos(&operator, 2, Extreme::Smallest, 1e-14, 5).unwrap_err(),
+        LalgrsError::NoConvergence { iterations: 5 }
+    );
+}
+
+#[test]
+fn test_arnoldi() {
+    let mut rng = Rng::seed_from_u64(4800);
+    let a = LalgrsMatrix::random_normal(30, 30, 0.0, 1.0, &mut rng);
+    let mut expected = a.eigenvalues().unwrap();
+    expected.sort_by(|x, y| y.abs().total_cmp(&x.abs()));
+
+    let pairs = arnoldi(&a, 4, Extreme::LargestMagnitude, 1e-10, 30).unwrap();
+    assert_eq!(pairs.len(), 4);
+    for pair in &pairs {
+        assert!(
+            expected[..5].iter().any(|e| (*e - pair.value).abs() < 1e-8),
+            "{} not in {expected:?}",
+            pair.value
+        );
+        assert!(pair.residual < 1e-8);
+        let length: f64 = pair.vector.iter().map(|v| v.norm_sqr()).sum();
+        assert!((length - 1.0).abs() < 1e-12);
+    }
+
+    // A rotation block gives a complex conjugate pair
+    let b = LalgrsMatrix::from_rows(vec![
+        vec![1.0, -2.0, 0.0],
+        vec![2.0, 1.0, 0.0],
+        vec![0.0, 0.0, 0.5],
+    ])
+    .unwrap();
+    let pairs = arnoldi(&b, 2, Extreme::Largest, 1e-12, 3).unwrap();
+    assert!((pairs[0].value - Complex::new(1.0, 2.0)).abs() < 1e-12);
+    assert!((pairs[1].value - Complex::new(1.0, -2.0)).abs() < 1e-12);
+    assert!(pairs.iter().all(|p| p.residual < 1e-12));
+    let smallest = arnoldi(&b, 1, Extreme::Smallest, 1e-12, 3).unwrap();
+    assert!((smallest[0].value - Complex::from(0.5)).abs() < 1e-12);
+}
@@ -0,0 +1,189 @@
+use lalgrs::{elementwise::Broadcast, LalgrsError, LalgrsMatrix, LalgrsVector, Rational, Shape};
+
+fn init_matrices() -> (LalgrsMatrix<f64>, LalgrsMatrix<f64>) {
+    let a = LalgrsMatrix::from_rows(vec![vec![1.0, -2.0, 3.0], vec![-4.0, 5.0, -6.0]]).unwrap();
+    let b = LalgrsMatrix::from_rows(vec![vec![2.0, 2.0, 2.0], vec![0.5, -1.0, 4.0]]).unwrap();
+    (a, b)
+}
+
+#[test]
+fn test_matrix_elementwise() {
+    let (a, b) = init_matrices();
+    assert_eq!(
+        a.elementwise_mul(&b).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![2.0, -4.0, 6.0], vec![-2.0, -5.0, -24.0]]).unwrap()
+    );
+    assert_eq!(
+        a.elementwise_div(&b).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![0.5, -1.0, 1.5], vec![-8.0, -5.0, -1.5]]).unwrap()
+    );
+    assert_eq!(
+        a.elementwise_min(&b).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1.0, -2.0, 2.0], vec![-4.0, -1.0, -6.0]]).unwrap()
+    );
+    assert_eq!(
+        a.elementwise_max(&b).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![2.0, 2.0, 3.0], vec![0.5, 5.0, 4.0]]).unwrap()
+    );
+    assert_eq!(
+        a.abs().unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap()
+    );
+    assert_eq!(
+        a.elementwise_powi(2).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1.0, 4.0, 9.0], vec![16.0, 25.0, 36.0]]).unwrap()
+    );
+    assert_eq!(
+        b.elementwise_powi(-1).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![0.5, 0.5, 0.5], vec![2.0, -1.0, 0.25]]).unwrap()
+    );
+    assert_eq!(
+        a.abs()
+            .unwrap()
+            .elementwise_powf(0.5)
+            .elementwise_powi(2)
+            .unwrap()
+            .map(|v| v.round()),
+        a.abs().unwrap()
+    );
+
+    assert_eq!(
+        a.elementwise_mul(&a.transpose()).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(2, 3),
+            rhs: Shape::new(3, 2)
+        }
+    );
+}
+
+#[test]
+fn test_matrix_map_and_zip_with() {
+    let (a, b) = init_matrices();
+    assert_eq!(
+        a.map(|v| (*v > 0.0) as u8),
+        LalgrsMatrix::from_rows(vec![vec![1, 0, 1], vec![0, 1, 0]]).unwrap()
+    );
+    let labels = a.map(|v| *v as i64);
+    assert_eq!(
+        a.zip_with(&labels, |v, l| v - *l as f64).unwrap(),
+        LalgrsMatrix::zeros(2, 3)
+    );
+    assert_eq!(
+        a.zip_with(&b, |x, y| x + y).unwrap(),
+        (a.clone() + b.clone()).unwrap()
+    );
+
+    let rationals = LalgrsMatrix::from_rows(vec![vec![Rational::new(-1, 2).unwrap()]]).unwrap();
+    assert_eq!(
+        rationals.elementwise_powi(-3).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![Rational::from(-8)]]).unwrap()
+    );
+    assert_eq!(
+        rationals.abs().unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![Rational::new(1, 2).unwrap()]]).unwrap()
+    );
+
+    let zero = LalgrsMatrix::from_rows(vec![vec![Rational::from(0)]]).unwrap();
+    assert_eq!(
+        zero.elementwise_powi(-1).unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+    let minimum = LalgrsMatrix::from_rows(vec![vec![Rational::from(i64::MIN)]]).unwrap();
+    assert_eq!(minimum.abs().unwrap_err(), LalgrsError::ArithmeticOverflow);
+    assert_eq!(
+        minimum.elementwise_powi(2).unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+    assert_eq!(
+        LalgrsVector::new(vec![Rational::from(1), Rational::from(0)])
+            .elementwise_powi(-2)
+            .unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+}
+
+#[test]
+fn test_broadcasting() {
+    let data =
+        LalgrsMatrix::from_rows(vec![vec![1.0, 10.0], vec![2.0, 20.0], vec![3.0, 30.0]]).unwrap();
+    // Centre each column on its mean
+    let means = LalgrsVector::new(vec![2.0, 20.0]);
+    assert_eq!(
+        data.broadcast_sub(Broadcast::Row(&means)).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![-1.0, -10.0], vec![0.0, 0.0], vec![1.0, 10.0]]).unwrap()
+    );
+    assert_eq!(
+        data.broadcast_div(Broadcast::Row(&LalgrsVector::new(vec![1.0, 10.0])))
+            .unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1.0, 1.0], vec![2.0, 2.0], vec![3.0, 3.0]]).unwrap()
+    );
+
+    let offsets = LalgrsVector::new(vec![1.0, 2.0, 3.0]);
+    assert_eq!(
+        data.broadcast_add(Broadcast::Column(&offsets)).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![2.0, 11.0], vec![4.0, 22.0], vec![6.0, 33.0]]).unwrap()
+    );
+    assert_eq!(
+        data.broadcast_mul(Broadcast::Column(&offsets)).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1.0, 10.0], vec![4.0, 40.0], vec![9.0, 90.0]]).unwrap()
+    );
+    assert_eq!(
+        data.broadcast_with(Broadcast::Column(&offsets), |a, b| (a > b) as u8)
+            .unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![0, 1], vec![0, 1], vec![0, 1]]).unwrap()
+    );
+
+    assert_eq!(
+        data.broadcast_sub(Broadcast::Column(&means)).unwrap_err(),
+        LalgrsError::MismatchedVectorAndMatrixDimensions {
+            vector_size: 2,
+            matrix_shape: Shape::new(3, 2)
+        }
+    );
+    assert_eq!(
+        data.broadcast_sub(Broadcast::Row(&offsets)).unwrap_err(),
+        LalgrsError::MismatchedVectorAndMatrixDimensions {
+            vector_size: 3,
+            matrix_shape: Shape::new(3, 2)
+        }
+    );
+}
+
+#[test]
+fn test_vector_elementwise() {
+    let u = LalgrsVector::new(vec![1.0, -4.0, 9.0]);
+    let v = LalgrsVector::new(vec![2.0, 2.0, -3.0]);
+    assert_eq!(
+        u.elementwise_mul(&v).unwrap(),
+        LalgrsVector::new(vec![2.0, -8.0, -27.0])
+    );
+    assert_eq!(
+        u.elementwise_div(&v).unwrap(),
+        LalgrsVector::new(vec![0.5, -2.0, -3.0])
+    );
+    assert_eq!(
+        u.elementwise_min(&v).unwrap(),
+        LalgrsVector::new(vec![1.0, -4.0, -3.0])
+    );
+    assert_eq!(
+        u.elementwise_max(&v).unwrap(),
+        LalgrsVector::new(vec![2.0, 2.0, 9.0])
+    );
+    assert_eq!(u.abs().unwrap(), LalgrsVector::new(vec![1.0, 4.0, 9.0]));
+    assert_eq!(
+        u.abs().unwrap().elementwise_powf(0.5),
+        LalgrsVector::new(vec![1.0, 2.0, 3.0])
+    );
+    assert_eq!(
+        v.elementwise_powi(3).unwrap(),
+        LalgrsVector::new(vec![8.0, 8.0, -27.0])
+    );
+    assert_eq!(
+        u.elementwise_mul(&LalgrsVector::new(vec![1.0]))
+            .unwrap_err(),
+        LalgrsError::MismatchedVectorDimensions {
+            vector1: 3,
+            vector2: 1
+        }
+    );
+}
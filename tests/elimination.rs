@@ -0,0 +1,156 @@
+use lalgrs::{elimination::RowOperation, LalgrsError, LalgrsMatrix, Rational};
+
+fn rationals(rows: Vec<Vec<i64>>) -> LalgrsMatrix<Rational> {
+    LalgrsMatrix::from_rows(
+        rows.into_iter()
+            .map(|row| row.into_iter().map(Rational::from).collect())
+            .collect(),
+    )
+    .unwrap()
+}
+
+fn init_rational_matrix() -> LalgrsMatrix<Rational> {
+    rationals(vec![vec![0, 2, 4, 2], vec![1, 1, 1, 1], vec![2, 4, 6, 4]])
+}
+
+#[test]
+fn test_rref_rational() {
+    let echelon = init_rational_matrix().rref().unwrap();
+    assert_eq!(
+        echelon.matrix,
+        rationals(vec![vec![1, 0, -1, 0], vec![0, 1, 2, 1], vec![0, 0, 0, 0]])
+    );
+    assert_eq!(echelon.pivots, [0, 1]);
+    assert_eq!(echelon.rank(), 2);
+
+    let fractions = rationals(vec![vec![2, 1], vec![1, 3]]);
+    assert_eq!(
+        fractions.rref().unwrap().matrix,
+        rationals(vec![vec![1, 0], vec![0, 1]])
+    );
+}
+
+#[test]
+fn test_row_echelon_rational() {
+    let (echelon, trace) = init_rational_matrix().row_echelon_traced().unwrap();
+    assert_eq!(
+        echelon.matrix,
+        rationals(vec![vec![1, 1, 1, 1], vec![0, 2, 4, 2], vec![0, 0, 0, 0]])
+    );
+    assert_eq!(echelon.pivots, [0, 1]);
+    // The first non zero pivot is used, without scaling rows
+    assert_eq!(
+        trace.steps[0].0,
+        RowOperation::Swap {
+            first: 0,
+            second: 1
+        }
+    );
+    assert!(trace
+        .steps
+        .iter()
+        .all(|(operation, _)| !matches!(operation, RowOperation::Scale { .. })));
+    assert_eq!(trace.steps.last().map(|(_, m)| m), Some(&echelon.matrix));
+}
+
+#[test]
+fn test_trace_replays_to_result() {
+    let (echelon, trace) = init_rational_matrix().rref_traced().unwrap();
+    let mut matrix = trace.start.clone();
+    for (operation, expected) in &trace.steps {
+        operation.apply(&mut matrix).unwrap();
+        assert_eq!(&matrix, expected);
+    }
+    assert_eq!(matrix, echelon.matrix);
+}
+
+#[test]
+fn test_rational_overflow() {
+    // Eliminating the first column leaves 1 + 2 * 2⁶², which does not fit in an i64
+    let matrix = rationals(vec![vec![1, 1 << 62], vec![-2, 1]]);
+    assert_eq!(matrix.rref().unwrap_err(), LalgrsError::ArithmeticOverflow);
+    assert_eq!(
+        matrix.row_echelon_traced().unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+
+    let operation = RowOperation::Scale {
+        row: 0,
+        factor: Rational::from(2),
+    };
+    let mut matrix = rationals(vec![vec![i64::MAX]]);
+    assert_eq!(
+        operation.apply(&mut matrix).unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+    assert_eq!(
+        Rational::from(i64::MAX).checked_mul(&Rational::from(2)),
+        None
+    );
+}
+
+#[test]
+fn test_trace_rendering() {
+    let matrix = rationals(vec![vec![2, 4], vec![3, 1]]);
+    let (_, trace) = matrix.rref_traced().unwrap();
+    let text = trace.to_text();
+    assert!(text.starts_with("[ 2  4 ]\n[ 3  1 ]\n"));
+    assert!(text.contains("\nR1 -> (1/2) R1\n[ 1  2 ]\n[ 3  1 ]\n"));
+    assert!(text.contains("\nR2 -> R2 - (3) R1\n[  1   2 ]\n[  0  -5 ]\n"));
+
+    let latex = trace.to_latex();
+    assert!(latex.starts_with("\\begin{bmatrix} 2 & 4 \\\\ 3 & 1 \\end{bmatrix}"));
+    assert!(latex.contains("&\\xrightarrow{R_{1} \\to \\frac{1}{2} R_{1}}"));
+    assert!(latex.contains("R_{2} \\to R_{2} - 3 R_{1}"));
+    assert!(latex.contains("R_{2} \\to -\\frac{1}{5} R_{2}"));
+}
+
+#[test]
+fn test_rref_f64() {
+    let matrix = LalgrsMatrix::from_rows(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 9.0],
+    ])
+    .unwrap();
+    let (echelon, trace) = matrix.rref_traced().unwrap();
+    assert_eq!(echelon.rank(), 2);
+    assert_eq!(echelon.pivots, [0, 1]);
+    let expected: [[f64; 3]; 3] = [[1.0, 0.0, -1.0], [0.0, 1.0, 2.0], [0.0, 0.0, 0.0]];
+    for (i, row) in expected.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            assert!((echelon.matrix[(i, j)] - value).abs() < 1e-12);
+        }
+    }
+    // Partial pivoting moves the largest element of the first column to the top
+    assert_eq!(
+        trace.steps[0].0,
+        RowOperation::Swap {
+            first: 0,
+            second: 2
+        }
+    );
+    // Eliminated elements are exactly zero
+    assert_eq!(echelon.matrix[(2, 2)], 0.0);
+    assert_eq!(echelon.matrix[(1, 0)], 0.0);
+
+    // Rounding errors are cleared by traced steps, so replaying the trace gives the same matrices
+    let matrix = LalgrsMatrix::from_rows(vec![
+        vec![0.1, 0.2, 0.3],
+        vec![0.4, 0.5, 0.6],
+        vec![0.7, 0.8, 0.9],
+    ])
+    .unwrap();
+    let (echelon, trace) = matrix.rref_traced().unwrap();
+    assert_eq!(echelon.rank(), 2);
+    assert!(trace
+        .steps
+        .iter()
+        .any(|(operation, _)| matches!(operation, RowOperation::Round { .. })));
+    let mut replayed = trace.start.clone();
+    for (operation, expected) in &trace.steps {
+        operation.apply(&mut replayed).unwrap();
+        assert_eq!(&replayed, expected, "{operation}");
+    }
+    assert_eq!(replayed, echelon.matrix);
+}
@@ -0,0 +1,184 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+use lalgrs::{
+    geometry::{Isometry3, Rotation2, Rotation3, Transform, Translation, UnitQuaternion},
+    LalgrsError, LalgrsVector, SVector,
+};
+
+fn assert_vector_close(actual: &LalgrsVector<f64>, expected: &[f64]) {
+    assert_eq!(actual.size(), expected.len());
+    for (a, e) in actual.values.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-12, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_rotation2() {
+    let rotation = Rotation2::new(FRAC_PI_2);
+    assert_vector_close(
+        &rotation
+            .transform_point(&LalgrsVector::new(vec![1.0, 0.0]))
+            .unwrap(),
+        &[0.0, 1.0],
+    );
+    assert!(((rotation * rotation).angle() - PI).abs() < 1e-12);
+    assert!((rotation * rotation.inverse()).angle().abs() < 1e-12);
+    assert_eq!(
+        rotation
+            .transform_vector(&LalgrsVector::new(vec![1.0, 0.0, 0.0]))
+            .unwrap_err(),
+        LalgrsError::MismatchedVectorDimensions {
+            vector1: 3,
+            vector2: 2
+        }
+    );
+}
+
+#[test]
+fn test_rotation3_axis_angle() {
+    let rotation = Rotation3::from_axis_angle(SVector::new([0.0, 0.0, 2.0]), FRAC_PI_2).unwrap();
+    assert_vector_close(
+        &rotation
+            .transform_vector(&LalgrsVector::new(vec![1.0, 0.0, 0.0]))
+            .unwrap(),
+        &[0.0, 1.0, 0.0],
+    );
+
+    let (axis, angle) = rotation.axis_angle().unwrap();
+    assert!((angle - FRAC_PI_2).abs() < 1e-12);
+    assert!((axis - SVector::new([0.0, 0.0, 1.0])).norm() < 1e-12);
+    assert!(Rotation3::identity().axis_angle().is_none());
+
+    assert_eq!(
+        Rotation3::from_axis_angle(SVector::new([0.0, 0.0, 0.0]), 1.0).unwrap_err(),
+        LalgrsError::ZeroLengthAxis
+    );
+}
+
+#[test]
+fn test_rotation3_euler_angles() {
+    let (roll, pitch, yaw) = (0.1, -0.4, 2.0);
+    let rotation = Rotation3::from_euler_angles(roll, pitch, yaw);
+    let composed = Rotation3::from_euler_angles(0.0, 0.0, yaw)
+        * Rotation3::from_euler_angles(0.0, pitch, 0.0)
+        * Rotation3::from_euler_angles(roll, 0.0, 0.0);
+    let point = LalgrsVector::new(vec![1.0, 2.0, 3.0]);
+    assert_vector_close(
+        &rotation.transform_point(&point).unwrap(),
+        &composed
+            .transform_point(&point)
+            .unwrap()
+            .values
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+    );
+
+    let (r, p, y) = rotation.euler_angles();
+    assert!((r - roll).abs() < 1e-12 && (p - pitch).abs() < 1e-12 && (y - yaw).abs() < 1e-12);
+}
+
+#[test]
+fn test_quaternion_matches_rotation_matrix() {
+    let axis = SVector::new([1.0, -2.0, 0.5]);
+    let quaternion = UnitQuaternion::from_axis_angle(axis, 2.5).unwrap();
+    let rotation = Rotation3::from_axis_angle(axis, 2.5).unwrap();
+    let point = LalgrsVector::new(vec![0.3, 0.7, -1.1]);
+    let expected = rotation.transform_point(&point).unwrap();
+    assert_vector_close(
+        &quaternion.transform_point(&point).unwrap(),
+        &expected.values.iter().copied().collect::<Vec<_>>(),
+    );
+
+    let round_trip = UnitQuaternion::from_rotation(&rotation);
+    assert!(round_trip.angle_to(&quaternion) < 1e-9);
+    assert!((quaternion * quaternion.inverse()).angle() < 1e-12);
+}
+
+#[test]
+fn test_quaternion_slerp() {
+    let axis = SVector::new([0.0, 1.0, 0.0]);
+    let start = UnitQuaternion::identity();
+    let end = UnitQuaternion::from_axis_angle(axis, FRAC_PI_2).unwrap();
+    let middle = start.slerp(&end, 0.5);
+    assert!(middle.angle_to(&UnitQuaternion::from_axis_angle(axis, FRAC_PI_4).unwrap()) < 1e-12);
+    assert!(start.slerp(&end, 0.0).angle_to(&start) < 1e-12);
+    assert!(start.slerp(&end, 1.0).angle_to(&end) < 1e-12);
+    assert!(start.slerp(&start, 0.3).angle_to(&start) < 1e-12);
+}
+
+#[test]
+fn test_isometry() {
+    let isometry = Isometry3::new(
+        Translation::new(1.0, 2.0, 3.0),
+        UnitQuaternion::from_axis_angle(SVector::new([0.0, 0.0, 1.0]), FRAC_PI_2).unwrap(),
+    );
+    let point = LalgrsVector::new(vec![1.0, 0.0, 0.0]);
+    assert_vector_close(&isometry.transform_point(&point).unwrap(), &[1.0, 3.0, 3.0]);
+    assert_vector_close(
+        &isometry.transform_vector(&point).unwrap(),
+        &[0.0, 1.0, 0.0],
+    );
+    assert_vector_close(
+        &(isometry.inverse() * isometry)
+            .transform_point(&point)
+            .unwrap(),
+        &[1.0, 0.0, 0.0],
+    );
+
+    let homogeneous = Transform::from(isometry);
+    assert_vector_close(
+        &homogeneous.transform_point(&point).unwrap(),
+        &[1.0, 3.0, 3.0],
+    );
+    assert_vector_close(
+        &homogeneous.transform_vector(&point).unwrap(),
+        &[0.0, 1.0, 0.0],
+    );
+}
+
+#[test]
+fn test_projections() {
+    let perspective = Transform::perspective(FRAC_PI_2, 2.0, 1.0, 10.0).unwrap();
+    assert_vector_close(
+        &perspective
+            .transform_point(&LalgrsVector::new(vec![0.0, 0.0, -1.0]))
+            .unwrap(),
+        &[0.0, 0.0, -1.0],
+    );
+    assert_vector_close(
+        &perspective
+            .transform_point(&LalgrsVector::new(vec![20.0, 10.0, -10.0]))
+            .unwrap(),
+        &[1.0, 1.0, 1.0],
+    );
+    assert_eq!(
+        perspective
+            .transform_point(&LalgrsVector::new(vec![1.0, 1.0, 0.0]))
+            .unwrap_err(),
+        LalgrsError::PointAtInfinity
+    );
+    assert_eq!(
+        Transform::perspective(1.0, 1.0, 0.0, 10.0).unwrap_err(),
+        LalgrsError::InvalidProjection {
+            near: 0.0,
+            far: 10.0
+        }
+    );
+
+    let orthographic = Transform::orthographic(-2.0, 2.0, -1.0, 1.0, 0.5, 4.5).unwrap();
+    assert_vector_close(
+        &orthographic
+            .transform_point(&LalgrsVector::new(vec![2.0, -1.0, -4.5]))
+            .unwrap(),
+        &[1.0, -1.0, 1.0],
+    );
+
+    let moved = Transform::from(Translation::new(0.0, 0.0, -5.0));
+    assert_vector_close(
+        &(orthographic * moved)
+            .transform_point(&LalgrsVector::new(vec![0.0, 0.0, 4.5]))
+            .unwrap(),
+        &[0.0, 0.0, -1.0],
+    );
+}
@@ -0,0 +1,213 @@
+use lalgrs::{graph::Graph, LalgrsError, LalgrsMatrix};
+
+/// Cliques on consecutive vertices, each joined to the next by a single edge
+fn chained_cliques(sizes: &[usize]) -> Graph {
+    let mut edges = Vec::new();
+    let mut start = 0;
+    for size in sizes {
+        for i in start..start + size {
+            for j in i + 1..start + size {
+                edges.push((i, j));
+            }
+        }
+        if start > 0 {
+            edges.push((start - 1, start));
+        }
+        start += size;
+    }
+    Graph::undirected(start, &edges).unwrap()
+}
+
+#[test]
+fn test_graph_matrices() {
+    let path = Graph::undirected(3, &[(0, 1), (1, 2)]).unwrap();
+    assert_eq!(
+        path.adjacency_matrix(),
+        LalgrsMatrix::from_rows(vec![
+            vec![0.0, 1.0, 0.0],
+            vec![1.0, 0.0, 1.0],
+            vec![0.0, 1.0, 0.0]
+        ])
+        .unwrap()
+    );
+    assert_eq!(
+        path.degree_matrix(),
+        LalgrsMatrix::from_rows(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 2.0, 0.0],
+            vec![0.0, 0.0, 1.0]
+        ])
+        .unwrap()
+    );
+    assert_eq!(
+        path.laplacian_matrix(),
+        LalgrsMatrix::from_rows(vec![
+            vec![1.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 1.0]
+        ])
+        .unwrap()
+    );
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    assert!(path.normalized_laplacian().approx_eq(
+        &LalgrsMatrix::from_rows(vec![
+            vec![1.0, -h, 0.0],
+            vec![-h, 1.0, -h],
+            vec![0.0, -h, 1.0]
+        ])
+        .unwrap(),
+        1e-15,
+        0.0
+    ));
+
+    let directed = Graph::weighted(3, &[(0, 1, 2.0), (0, 1, 0.5), (2, 0, 1.0)], true).unwrap();
+    assert_eq!(
+        directed.adjacency_matrix(),
+        LalgrsMatrix::from_rows(vec![
+            vec![0.0, 2.5, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![1.0, 0.0, 0.0]
+        ])
+        .unwrap()
+    );
+    assert_eq!(
+        directed.laplacian_matrix(),
+        LalgrsMatrix::from_rows(vec![
+            vec![2.5, -2.5, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![-1.0, 0.0, 1.0]
+        ])
+        .unwrap()
+    );
+
+    assert_eq!(
+        Graph::undirected(3, &[(0, 3)]).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "vertex",
+            value: 3.0
+        }
+    );
+    assert_eq!(
+        Graph::weighted(2, &[(0, 1, -1.0)], false).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "weight",
+            value: -1.0
+        }
+    );
+}
+
+#[test]
+fn test_pagerank() {
+    let cycle = Graph::directed(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]).unwrap();
+    let rank = cycle.pagerank(0.85, 1e-12).unwrap();
+    assert!(rank.iter().all(|r| (r - 0.25).abs() < 1e-12));
+
+    // Vertex 1 has no outgoing edges, so its rank is spread uniformly:
+    // r0 = 1/4 + r1/4 and r1 = 1/4 + r1/4 + r0/2, which gives r = (0.4, 0.6)
+    let dangling = Graph::directed(2, &[(0, 1)]).unwrap();
+    let rank = dangling.pagerank(0.5, 1e-14).unwrap();
+    assert!((rank.values[0] - 0.4).abs() < 1e-12);
+    assert!((rank.values[1] - 0.6).abs() < 1e-12);
+
+    // The ranks are a fixed point of the damped random walk
+    let graph = Graph::weighted(
+        4,
+        &[
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 0, 3.0),
+            (2, 1, 1.0),
+            (3, 2, 2.0),
+        ],
+        true,
+    )
+    .unwrap();
+    let rank = graph.pagerank(0.85, 1e-14).unwrap();
+    assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    let adjacency = graph.adjacency_matrix();
+    for j in 0..4 {
+        let inflow: f64 = (0..4)
+            .map(|i| {
+                let out: f64 = (0..4).map(|k| adjacency[(i, k)]).sum();
+                rank.values[i] * adjacency[(i, j)] / out
+            })
+            .sum();
+        assert!((rank.values[j] - (0.15 / 4.0 + 0.85 * inflow)).abs() < 1e-12);
+    }
+    // Nothing links to vertex 3, so it only gets the random jumps
+    assert!((rank.values[3] - 0.15 / 4.0).abs() < 1e-12);
+
+    assert_eq!(
+        cycle.pagerank(1.0, 1e-12).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "damping",
+            value: 1.0
+        }
+    );
+}
+
+#[test]
+fn test_connected_components() {
+    let graph = Graph::directed(8, &[(0, 1), (1, 2), (5, 4), (6, 5), (7, 3)]).unwrap();
+    assert_eq!(
+        graph.connected_components().unwrap(),
+        vec![0, 0, 0, 1, 2, 2, 2, 1]
+    );
+    assert_eq!(
+        Graph::undirected(3, &[])
+            .unwrap()
+            .connected_components()
+            .unwrap(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(
+        chained_cliques(&[4, 5]).connected_components().unwrap(),
+        vec![0; 9]
+    );
+}
+
+#[test]
+fn test_fiedler_vector_and_spectral_clustering() {
+    // The path on four vertices has algebraic connectivity 2 - √2, with an antisymmetric Fiedler vector
+    let path = Graph::undirected(4, &[(0, 1), (1, 2), (2, 3)]).unwrap();
+    let connectivity = path.algebraic_connectivity().unwrap();
+    assert!((connectivity - (2.0 - 2f64.sqrt())).abs() < 1e-12);
+    let fiedler = path.fiedler_vector().unwrap();
+    assert!((fiedler.values[0] + fiedler.values[3]).abs() < 1e-12);
+    assert!((fiedler.values[1] + fiedler.values[2]).abs() < 1e-12);
+    assert!(fiedler.values[0] * fiedler.values[1] > 0.0);
+
+    let disconnected =
+        Graph::undirected(6, &[(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)]).unwrap();
+    assert!(disconnected.algebraic_connectivity().unwrap().abs() < 1e-12);
+    assert_eq!(
+        disconnected.spectral_clustering(2).unwrap(),
+        vec![0, 0, 0, 1, 1, 1]
+    );
+
+    assert_eq!(
+        chained_cliques(&[4, 4]).spectral_clustering(2).unwrap(),
+        vec![0, 0, 0, 0, 1, 1, 1, 1]
+    );
+    assert_eq!(
+        chained_cliques(&[5, 4, 6]).spectral_clustering(3).unwrap(),
+        vec![0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2]
+    );
+    assert_eq!(
+        path.spectral_clustering(5).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "clusters",
+            value: 5.0
+        }
+    );
+    assert_eq!(
+        Graph::undirected(1, &[])
+            .unwrap()
+            .fiedler_vector()
+            .unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "vertices",
+            value: 1.0
+        }
+    );
+}
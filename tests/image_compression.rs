@@ -0,0 +1,90 @@
+use lalgrs::{
+    compression::{compress_image, mean_squared_error, peak_signal_to_noise_ratio},
+    image::{Image, NetpbmFormat},
+    LalgrsError, LalgrsMatrix, Rng,
+};
+
+fn init_color_image() -> Image {
+    let mut rng = Rng::seed_from_u64(42);
+    let mut channel = || LalgrsMatrix::random_uniform(12, 8, 0.0, 1.0, &mut rng);
+    Image::rgb(channel(), channel(), channel()).unwrap()
+}
+
+#[test]
+fn test_compression_ratio() {
+    let image = init_color_image();
+    let compressed = compress_image(&image, 2).unwrap();
+    assert_eq!(compressed.rank(), 2);
+    assert_eq!(compressed.factors().len(), 3);
+    assert_eq!(compressed.stored_values(), 3 * 2 * (12 + 8 + 1));
+    assert!((compressed.compression_ratio() - 96.0 / 42.0).abs() < 1e-12);
+
+    // Asking for more triplets than there are keeps all of them
+    assert_eq!(compress_image(&image, 100).unwrap().rank(), 8);
+    assert_eq!(
+        compress_image(&image, 0).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "rank",
+            value: 0.0
+        }
+    );
+}
+
+#[test]
+fn test_quality_improves_with_rank() {
+    let image = init_color_image();
+    let reports: Vec<_> = (1..=8)
+        .map(|rank| {
+            compress_image(&image, rank)
+                .unwrap()
+                .report(&image)
+                .unwrap()
+        })
+        .collect();
+    for pair in reports.windows(2) {
+        assert!(pair[1].mse <= pair[0].mse);
+        assert!(pair[1].psnr >= pair[0].psnr);
+        assert!(pair[1].compression_ratio < pair[0].compression_ratio);
+    }
+    let full = reports[7];
+    assert!(full.mse < 1e-20);
+    assert!(full.psnr > 200.0);
+}
+
+#[test]
+fn test_low_rank_image_is_exact() {
+    let stripes = LalgrsMatrix::from_fn(6, 6, |_, j| if j % 2 == 0 { 0.25 } else { 0.75 });
+    let image = Image::grayscale(stripes);
+    let compressed = compress_image(&image, 1).unwrap();
+    let report = compressed.report(&image).unwrap();
+    assert!(report.mse < 1e-20);
+    assert_eq!(
+        compressed
+            .reconstruct()
+            .to_netpbm_bytes(NetpbmFormat::PlainGray),
+        image.to_netpbm_bytes(NetpbmFormat::PlainGray)
+    );
+}
+
+#[test]
+fn test_metrics() {
+    let black = Image::grayscale(LalgrsMatrix::zeros(2, 2));
+    let gray = Image::grayscale(LalgrsMatrix::from_element(2, 2, 0.1));
+    assert!((mean_squared_error(&black, &gray).unwrap() - 0.01).abs() < 1e-15);
+    assert!((peak_signal_to_noise_ratio(&black, &gray).unwrap() - 20.0).abs() < 1e-12);
+    assert_eq!(
+        peak_signal_to_noise_ratio(&black, &black).unwrap(),
+        f64::INFINITY
+    );
+    assert!(matches!(
+        mean_squared_error(&black, &init_color_image()).unwrap_err(),
+        LalgrsError::InvalidImage(_)
+    ));
+    assert_eq!(
+        mean_squared_error(&black, &Image::grayscale(LalgrsMatrix::zeros(2, 3))).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: (2, 2).into(),
+            rhs: (2, 3).into()
+        }
+    );
+}
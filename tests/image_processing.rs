@@ -0,0 +1,257 @@
+use std::f64::consts::FRAC_PI_2;
+
+use lalgrs::{
+    assert_matrix_approx_eq,
+    convolution::Border,
+    image::{gaussian_kernel, luma_weights, EdgeOperator, Image, NetpbmFormat},
+    LalgrsError, LalgrsMatrix, LalgrsVector, SMatrix2,
+};
+
+fn init_gray_image() -> Image {
+    Image::grayscale(
+        LalgrsMatrix::from_rows(vec![vec![0.0, 0.2, 0.4], vec![0.6, 0.8, 1.0]]).unwrap(),
+    )
+}
+
+#[test]
+fn test_parse_plain_pgm() {
+    let bytes = b"P2\n# a comment\n3 2\n10\n0 2 4\n6 8 10\n";
+    let image = Image::from_netpbm_bytes(bytes).unwrap();
+    assert_eq!((image.width(), image.height()), (3, 2));
+    assert_eq!(image.max_value, 10);
+    assert!(!image.is_color());
+    assert_matrix_approx_eq!(
+        image.channels()[0],
+        init_gray_image().channels()[0],
+        1e-12,
+        0.0
+    );
+}
+
+#[test]
+fn test_parse_binary_ppm() {
+    let mut bytes = b"P6 2 1 255\n".to_vec();
+    bytes.extend_from_slice(&[255, 0, 0, 0, 0, 255]);
+    let image = Image::from_netpbm_bytes(&bytes).unwrap();
+    assert!(image.is_color());
+    let channels = image.channels();
+    assert_eq!(
+        channels[0],
+        LalgrsMatrix::from_rows(vec![vec![1.0, 0.0]]).unwrap()
+    );
+    assert_eq!(
+        channels[1],
+        LalgrsMatrix::from_rows(vec![vec![0.0, 0.0]]).unwrap()
+    );
+    assert_eq!(
+        channels[2],
+        LalgrsMatrix::from_rows(vec![vec![0.0, 1.0]]).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(matches!(
+        Image::from_netpbm_bytes(b"P4 1 1 1\n0").unwrap_err(),
+        LalgrsError::InvalidImage(_)
+    ));
+    assert!(matches!(
+        Image::from_netpbm_bytes(b"P2 2 2 10\n1 2 3").unwrap_err(),
+        LalgrsError::InvalidImage(_)
+    ));
+    assert!(matches!(
+        Image::from_netpbm_bytes(b"P2 1 1 10\n11").unwrap_err(),
+        LalgrsError::InvalidImage(_)
+    ));
+    assert!(matches!(
+        Image::from_netpbm_bytes(b"P5 2 2 255\n\x00").unwrap_err(),
+        LalgrsError::InvalidImage(_)
+    ));
+    // The number of samples overflows
+    assert!(matches!(
+        Image::from_netpbm_bytes(b"P6 4294967296 4294967296 255\n\x00").unwrap_err(),
+        LalgrsError::InvalidImage(_)
+    ));
+    assert!(matches!(
+        Image::from_netpbm_bytes(b"P5 6148914691236517205 1 65535\n\x00").unwrap_err(),
+        LalgrsError::InvalidImage(_)
+    ));
+}
+
+#[test]
+fn test_netpbm_round_trip() {
+    let gray = init_gray_image();
+    for format in [NetpbmFormat::PlainGray, NetpbmFormat::BinaryGray] {
+        let decoded = Image::from_netpbm_bytes(&gray.to_netpbm_bytes(format).unwrap()).unwrap();
+        assert_matrix_approx_eq!(decoded.channels()[0], gray.channels()[0], 0.5 / 255.0, 0.0);
+    }
+
+    let mut color = Image::rgb(
+        LalgrsMatrix::from_rows(vec![vec![0.0, 1.0]]).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![0.5, 0.25]]).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1.0, 0.0]]).unwrap(),
+    )
+    .unwrap();
+    color.max_value = 1000;
+    for format in [NetpbmFormat::PlainColor, NetpbmFormat::BinaryColor] {
+        let decoded = Image::from_netpbm_bytes(&color.to_netpbm_bytes(format).unwrap()).unwrap();
+        assert_eq!(decoded, color);
+    }
+
+    assert!(gray.to_netpbm_bytes(NetpbmFormat::PlainColor).is_err());
+
+    let path = std::env::temp_dir().join(format!("lalgrs_round_trip_{}.ppm", std::process::id()));
+    color.save(&path, NetpbmFormat::BinaryColor).unwrap();
+    assert_eq!(Image::load(&path).unwrap(), color);
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        Image::load(&path).unwrap_err(),
+        LalgrsError::Io(_)
+    ));
+}
+
+#[test]
+fn test_to_grayscale() {
+    let ones = LalgrsMatrix::from_rows(vec![vec![1.0, 0.0]]).unwrap();
+    let color = Image::rgb(ones.clone(), ones.clone(), ones).unwrap();
+    let gray = color.to_grayscale(&luma_weights()).unwrap();
+    assert_matrix_approx_eq!(
+        gray.channels()[0],
+        LalgrsMatrix::from_rows(vec![vec![1.0, 0.0]]).unwrap(),
+        1e-12,
+        0.0
+    );
+    assert_eq!(
+        color
+            .to_grayscale(&LalgrsVector::new(vec![0.5, 0.5]))
+            .unwrap_err(),
+        LalgrsError::MismatchedVectorDimensions {
+            vector1: 2,
+            vector2: 3
+        }
+    );
+}
+
+#[test]
+fn test_convolution_borders() {
+    let input = LalgrsMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let kernel = LalgrsMatrix::from_rows(vec![vec![1, 0, 0]]).unwrap();
+    // The kernel is flipped: each element takes the value of its right neighbour
+    assert_eq!(
+        input.convolve(&kernel, Border::Zero).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![2, 3, 0], vec![5, 6, 0]]).unwrap()
+    );
+    assert_eq!(
+        input.convolve(&kernel, Border::Clamp).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![2, 3, 3], vec![5, 6, 6]]).unwrap()
+    );
+    assert_eq!(
+        input.convolve(&kernel, Border::Wrap).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![2, 3, 1], vec![5, 6, 4]]).unwrap()
+    );
+    assert_eq!(
+        input
+            .convolve(&LalgrsMatrix::new(vec![]).unwrap(), Border::Zero)
+            .unwrap_err(),
+        LalgrsError::EmptyMatrix
+    );
+}
+
+#[test]
+fn test_gaussian_blur() {
+    let kernel = gaussian_kernel(1.0).unwrap();
+    assert_eq!(kernel.shape().columns, 7);
+    assert!((kernel.as_column_major_slice().iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert!(gaussian_kernel(0.0).is_err());
+
+    let constant = Image::grayscale(LalgrsMatrix::from_element(5, 5, 0.5));
+    let blurred = constant.gaussian_blur(1.5).unwrap();
+    assert_matrix_approx_eq!(blurred.channels()[0], constant.channels()[0], 1e-12, 0.0);
+
+    let mut impulse = LalgrsMatrix::zeros(5, 5);
+    impulse[(2, 2)] = 1.0;
+    let spread = Image::grayscale(impulse).gaussian_blur(1.0).unwrap();
+    let channel = &spread.channels()[0];
+    assert!(channel[(2, 2)] < 1.0 && channel[(2, 2)] > channel[(2, 3)]);
+    assert!((channel[(1, 2)] - channel[(2, 1)]).abs() < 1e-12);
+}
+
+#[test]
+fn test_edge_detection() {
+    let step = Image::grayscale(LalgrsMatrix::from_fn(
+        4,
+        6,
+        |_, j| if j < 3 { 0.0 } else { 1.0 },
+    ));
+    for (operator, strength) in [(EdgeOperator::Sobel, 4.0), (EdgeOperator::Prewitt, 3.0)] {
+        let edges = step.detect_edges(operator).unwrap();
+        let channel = &edges.channels()[0];
+        for i in 0..4 {
+            assert_eq!(channel[(i, 0)], 0.0);
+            assert_eq!(channel[(i, 2)], strength);
+            assert_eq!(channel[(i, 3)], strength);
+            assert_eq!(channel[(i, 5)], 0.0);
+        }
+    }
+}
+
+#[test]
+fn test_rotate_and_scale() {
+    let image = Image::grayscale(
+        LalgrsMatrix::from_rows(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+        ])
+        .unwrap(),
+    );
+    // A quarter turn clockwise moves the top left corner to the top right corner
+    let rotated = image.rotate(FRAC_PI_2).unwrap();
+    assert_matrix_approx_eq!(
+        rotated.channels()[0],
+        LalgrsMatrix::from_rows(vec![
+            vec![0.0, 0.0, 1.0],
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+        ])
+        .unwrap(),
+        1e-12,
+        0.0
+    );
+
+    let ramp = Image::grayscale(LalgrsMatrix::from_rows(vec![vec![0.0, 1.0]]).unwrap());
+    let scaled = ramp.scale(2.0, 1.0).unwrap();
+    assert_matrix_approx_eq!(
+        scaled.channels()[0],
+        LalgrsMatrix::from_rows(vec![vec![0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]]).unwrap(),
+        1e-12,
+        0.0
+    );
+    assert_eq!(
+        ramp.scale(0.0, 1.0).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "factor_x",
+            value: 0.0
+        }
+    );
+
+    // A strong downscale is invertible even though its determinant is tiny
+    let dot = Image::grayscale(LalgrsMatrix::from_fn(3, 3, |i, j| {
+        (i == 1 && j == 1) as u8 as f64
+    }));
+    let shrunk = dot
+        .affine(&SMatrix2::from_rows([[1e-8, 0.0], [0.0, 1e-8]]), 3, 3)
+        .unwrap();
+    assert_eq!(shrunk.channels()[0], dot.channels()[0]);
+    for singular in [
+        [[1.0, 2.0], [2.0, 4.0]],
+        [[1e-8, 2e-8], [2e-8, 4e-8]],
+        [[0.0; 2]; 2],
+    ] {
+        assert_eq!(
+            dot.affine(&SMatrix2::from_rows(singular), 3, 3)
+                .unwrap_err(),
+            LalgrsError::SingularMatrix
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use lalgrs::{
+    life::{Life, Pattern, Rule, Topology},
+    LalgrsError, LalgrsMatrix, Shape,
+};
+
+fn init_glider() -> Pattern {
+    Pattern::from_rle("#N Glider\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n").unwrap()
+}
+
+#[test]
+fn test_rule_parsing() {
+    let high_life: Rule = "B36/S23".parse().unwrap();
+    assert_eq!(high_life, Rule::high_life());
+    assert_eq!("s23/b3".parse::<Rule>().unwrap(), Rule::conway());
+    assert_eq!(high_life.to_string(), "B36/S23");
+    assert_eq!("B/S".parse::<Rule>().unwrap().to_string(), "B/S");
+    assert!(high_life.next_state(false, 6));
+    assert!(!Rule::conway().next_state(false, 6));
+
+    for invalid in ["B3", "B3/S2/S3", "B39/S23", "X3/S23", "B3/B23"] {
+        assert!(
+            matches!(invalid.parse::<Rule>(), Err(LalgrsError::InvalidRule(_))),
+            "{invalid}"
+        );
+    }
+    assert!(Rule::new(&[9], &[]).is_err());
+}
+
+#[test]
+fn test_neighbour_counts() {
+    let grid = LalgrsMatrix::from_rows(vec![vec![1, 0, 0], vec![0, 0, 0], vec![0, 0, 1]]).unwrap();
+    let bounded = Life::new(&grid, Rule::conway(), Topology::Bounded);
+    assert_eq!(
+        bounded.neighbour_counts(),
+        LalgrsMatrix::from_rows(vec![vec![0, 1, 0], vec![1, 2, 1], vec![0, 1, 0]]).unwrap()
+    );
+    // On a 3x3 torus every cell is a neighbour of every other cell
+    let toroidal = Life::new(&grid, Rule::conway(), Topology::Toroidal);
+    assert_eq!(
+        toroidal.neighbour_counts(),
+        LalgrsMatrix::from_rows(vec![vec![1, 2, 2], vec![2, 2, 2], vec![2, 2, 1]]).unwrap()
+    );
+}
+
+#[test]
+fn test_blinker_oscillates() {
+    let grid = LalgrsMatrix::from_fn(5, 5, |i, j| (i == 2 && (1..4).contains(&j)) as u8);
+    let mut life = Life::new(&grid, Rule::conway(), Topology::Bounded);
+    life.step();
+    assert_eq!(
+        life.grid(),
+        &LalgrsMatrix::from_fn(5, 5, |i, j| (j == 2 && (1..4).contains(&i)) as u8)
+    );
+    life.step();
+    assert_eq!(life.grid(), &grid);
+    assert_eq!(life.generation(), 2);
+    assert_eq!(life.population(), 3);
+}
+
+#[test]
+fn test_glider_on_torus_and_bounded_grid() {
+    let glider = init_glider();
+    let mut toroidal = Life::from_pattern(&glider, Shape::new(6, 6), Topology::Toroidal).unwrap();
+    let start = toroidal.grid().clone();
+    // A glider moves one cell diagonally every four generations
+    toroidal.advance(24);
+    assert_eq!(toroidal.grid(), &start);
+
+    let mut bounded = Life::from_pattern(&glider, Shape::new(6, 6), Topology::Bounded).unwrap();
+    bounded.advance(24);
+    assert_eq!(bounded.population(), 4);
+
+    assert!(matches!(
+        Life::from_pattern(&glider, Shape::new(2, 6), Topology::Bounded).unwrap_err(),
+        LalgrsError::SubmatrixOutOfBounds { .. }
+    ));
+}
+
+#[test]
+fn test_rle_round_trip() {
+    let glider = init_glider();
+    assert_eq!(glider.name.as_deref(), Some("Glider"));
+    assert_eq!(glider.rule, Some(Rule::conway()));
+    assert_eq!(
+        glider.cells,
+        LalgrsMatrix::from_rows(vec![vec![0, 1, 0], vec![0, 0, 1], vec![1, 1, 1]]).unwrap()
+    );
+    assert_eq!(
+        glider.to_rle(),
+        "#N Glider\nx = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n"
+    );
+    assert_eq!(Pattern::from_rle(&glider.to_rle()).unwrap(), glider);
+
+    // Empty rows are merged into a single run and long patterns are wrapped
+    let wide = Pattern {
+        name: None,
+        cells: LalgrsMatrix::from_fn(4, 100, |i, j| (i != 1 && j % 2 == 0) as u8),
+        rule: Some(Rule::high_life()),
+    };
+    let text = wide.to_rle();
+    assert!(text.lines().all(|l| l.len() <= 70));
+    assert!(text.contains("2$"));
+    assert_eq!(Pattern::from_rle(&text).unwrap(), wide);
+}
+
+#[test]
+fn test_cells_round_trip() {
+    let text = "!Name: Glider\n!A comment\n.O\n..O\nOOO\n";
+    let glider = Pattern::from_cells(text).unwrap();
+    assert_eq!(glider.cells, init_glider().cells);
+    assert_eq!(glider.name.as_deref(), Some("Glider"));
+    assert_eq!(glider.rule, None);
+    assert_eq!(glider.to_cells(), "!Name: Glider\n.O.\n..O\nOOO\n");
+    assert_eq!(Pattern::from_cells(&glider.to_cells()).unwrap(), glider);
+}
+
+#[test]
+fn test_pattern_errors() {
+    for invalid in [
+        "bo$2bo$3o!",
+        "x = 3\nbo!",
+        "x = 2, y = 3\n3o!",
+        "x = 3, y = 1\no$o!",
+        "x = 3, y = 3, rule = B3\no!",
+        "x = 3, y = 3\nozo!",
+        // Sizes and run counts that would overflow or exhaust memory
+        "x = 18446744073709551615, y = 2\no!",
+        "x = 3000000000, y = 3000000000\no!",
+        "x = 100000, y = 100000\n!",
+        "x = 3, y = 3\n99999999999999999999999o!",
+        "x = 3, y = 3\n18446744073709551615o!",
+        "x = 3, y = 3\n18446744073709551615$o!",
+        "x = 3, y = 0\n$!",
+    ] {
+        assert!(Pattern::from_rle(invalid).is_err(), "{invalid}");
+    }
+    assert!(matches!(
+        Pattern::from_cells(".O\nxO\n").unwrap_err(),
+        LalgrsError::InvalidPattern(_)
+    ));
+}
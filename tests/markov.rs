@@ -0,0 +1,170 @@
+use lalgrs::{assert_vector_approx_eq, LalgrsError, LalgrsMatrix, LalgrsVector, MarkovChain, Rng};
+
+fn weather() -> MarkovChain {
+    MarkovChain::new(LalgrsMatrix::from_rows(vec![vec![0.9, 0.1], vec![0.5, 0.5]]).unwrap())
+        .unwrap()
+}
+
+/// Symmetric random walk on 0..=4, absorbed at both ends
+fn gamblers_ruin() -> MarkovChain {
+    MarkovChain::new(LalgrsMatrix::from_fn(5, 5, |i, j| match i {
+        0 | 4 => (i == j) as u8 as f64,
+        _ if j + 1 == i || j == i + 1 => 0.5,
+        _ => 0.0,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_validation_and_transitions() {
+    assert_eq!(
+        MarkovChain::new(LalgrsMatrix::from_rows(vec![vec![0.5, 0.6], vec![0.5, 0.5]]).unwrap())
+            .unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "row sum",
+            value: 1.1
+        }
+    );
+    assert_eq!(
+        MarkovChain::new(LalgrsMatrix::from_rows(vec![vec![1.5, -0.5], vec![0.5, 0.5]]).unwrap())
+            .unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "probability",
+            value: -0.5
+        }
+    );
+    assert!(matches!(
+        MarkovChain::new(LalgrsMatrix::zeros(1, 2)),
+        Err(LalgrsError::NonSquareMatrix { .. })
+    ));
+    // Rows that sum to one up to rounding are accepted
+    assert!(
+        MarkovChain::new(LalgrsMatrix::from_rows(vec![vec![0.1, 0.2, 0.7]; 3]).unwrap()).is_ok()
+    );
+
+    let chain = weather();
+    assert_eq!(chain.n_step(0).unwrap(), LalgrsMatrix::identity(2));
+    assert!(chain.n_step(2).unwrap().approx_eq(
+        &LalgrsMatrix::from_rows(vec![vec![0.86, 0.14], vec![0.7, 0.3]]).unwrap(),
+        1e-15,
+        0.0
+    ));
+    let after = chain
+        .distribution_after(&LalgrsVector::new(vec![0.0, 1.0]), 1)
+        .unwrap();
+    assert_vector_approx_eq!(after, LalgrsVector::new(vec![0.5, 0.5]), 1e-15, 0.0);
+}
+
+#[test]
+fn test_stationary_distribution() {
+    let chain = weather();
+    let stationary = chain.stationary_distribution().unwrap();
+    assert_vector_approx_eq!(
+        stationary,
+        LalgrsVector::new(vec![5.0 / 6.0, 1.0 / 6.0]),
+        1e-14,
+        0.0
+    );
+    // Far from the start, every distribution approaches the stationary one
+    let limit = chain
+        .distribution_after(&LalgrsVector::new(vec![0.0, 1.0]), 200)
+        .unwrap();
+    assert!(limit.approx_eq(&stationary, 1e-12, 0.0));
+
+    // Two closed classes have a stationary distribution each
+    let reducible =
+        MarkovChain::new(LalgrsMatrix::from_rows(vec![vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap())
+            .unwrap();
+    assert_eq!(
+        reducible.stationary_distribution().unwrap_err(),
+        LalgrsError::SingularMatrix
+    );
+}
+
+#[test]
+fn test_absorbing_chain() {
+    let chain = gamblers_ruin();
+    assert_eq!(chain.absorbing_states(), vec![0, 4]);
+    assert_eq!(chain.transient_states(), vec![1, 2, 3]);
+    assert!(chain.fundamental_matrix().unwrap().approx_eq(
+        &LalgrsMatrix::from_rows(vec![
+            vec![1.5, 1.0, 0.5],
+            vec![1.0, 2.0, 1.0],
+            vec![0.5, 1.0, 1.5]
+        ])
+        .unwrap(),
+        1e-14,
+        0.0
+    ));
+    // From i, the walk reaches 4 before 0 with probability i / 4, after i * (4 - i) steps on average
+    assert!(chain.absorption_probabilities().unwrap().approx_eq(
+        &LalgrsMatrix::from_rows(vec![vec![0.75, 0.25], vec![0.5, 0.5], vec![0.25, 0.75]]).unwrap(),
+        1e-14,
+        0.0
+    ));
+    assert!(chain.expected_steps_to_absorption().unwrap().approx_eq(
+        &LalgrsVector::new(vec![3.0, 4.0, 3.0]),
+        1e-14,
+        0.0
+    ));
+
+    assert_eq!(
+        weather().fundamental_matrix().unwrap_err(),
+        LalgrsError::SingularMatrix
+    );
+    assert_eq!(
+        MarkovChain::new(LalgrsMatrix::identity(2))
+            .unwrap()
+            .fundamental_matrix()
+            .unwrap_err(),
+        LalgrsError::EmptyMatrix
+    );
+}
+
+#[test]
+fn test_hitting_times_and_simulation() {
+    let cycle = MarkovChain::new(
+        LalgrsMatrix::from_rows(vec![
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![1.0, 0.0, 0.0],
+        ])
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        cycle.expected_hitting_times(&[0]).unwrap(),
+        LalgrsVector::new(vec![0.0, 2.0, 1.0])
+    );
+    // Expected time to leave state 0 of the weather chain is 1 / 0.1
+    assert_vector_approx_eq!(
+        weather().expected_hitting_times(&[1]).unwrap(),
+        LalgrsVector::new(vec![10.0, 0.0]),
+        1e-12,
+        0.0
+    );
+    assert_eq!(
+        gamblers_ruin().expected_hitting_times(&[2]).unwrap_err(),
+        LalgrsError::SingularMatrix
+    );
+    assert_eq!(
+        cycle.expected_hitting_times(&[3]).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "state",
+            value: 3.0
+        }
+    );
+
+    let mut rng = Rng::seed_from_u64(47);
+    assert_eq!(cycle.simulate(1, 4, &mut rng).unwrap(), vec![1, 2, 0, 1, 2]);
+
+    let chain = weather();
+    let path = chain.simulate(0, 100_000, &mut rng).unwrap();
+    assert_eq!(path.len(), 100_001);
+    let rainy = path.iter().filter(|s| **s == 1).count() as f64 / path.len() as f64;
+    assert!((rainy - 1.0 / 6.0).abs() < 0.01);
+    assert_eq!(
+        chain.simulate(0, 50, &mut Rng::seed_from_u64(1)).unwrap(),
+        chain.simulate(0, 50, &mut Rng::seed_from_u64(1)).unwrap()
+    );
+}
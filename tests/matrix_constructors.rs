@@ -0,0 +1,125 @@
+use lalgrs::{LalgrsError, LalgrsMatrix, LalgrsVector, Rng};
+
+fn assert_close(actual: &LalgrsMatrix<f64>, expected: &LalgrsMatrix<f64>, n: usize) {
+    for i in 0..n {
+        for j in 0..n {
+            let (a, e) = (actual.get(i, j).unwrap(), expected.get(i, j).unwrap());
+            assert!((a - e).abs() < 1e-12, "({i}, {j}): {a} != {e}");
+        }
+    }
+}
+
+#[test]
+fn test_from_rows_and_columns() {
+    let from_rows = LalgrsMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let from_columns =
+        LalgrsMatrix::from_columns(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+    assert_eq!(from_rows, from_columns);
+    assert_eq!(from_rows.get(0, 2), Some(&3));
+    assert_eq!(from_rows.get(1, 0), Some(&4));
+    assert_eq!(from_rows.get(2, 0), None);
+    assert_eq!(
+        from_rows.transpose(),
+        LalgrsMatrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    );
+}
+
+#[test]
+fn test_from_rows_error() {
+    assert_eq!(
+        LalgrsMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5]]).unwrap_err(),
+        LalgrsError::InvalidMatrixDimensions
+    );
+}
+
+#[test]
+fn test_from_row_slice() {
+    assert_eq!(
+        LalgrsMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    );
+    assert_eq!(
+        LalgrsMatrix::from_row_slice(2, 2, &[1, 2, 3]).unwrap_err(),
+        LalgrsError::InvalidElementCount {
+            expected: 4,
+            found: 3
+        }
+    );
+    assert_eq!(
+        LalgrsMatrix::<f64>::from_row_slice(usize::MAX, 2, &[]).unwrap_err(),
+        LalgrsError::InvalidMatrixDimensions
+    );
+}
+
+#[test]
+fn test_zeros_ones_identity() {
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(2, 3),
+        LalgrsMatrix::from_rows(vec![vec![0.0; 3]; 2]).unwrap()
+    );
+    assert_eq!(
+        LalgrsMatrix::<i32>::ones(3, 1),
+        LalgrsMatrix::from_rows(vec![vec![1], vec![1], vec![1]]).unwrap()
+    );
+    assert_eq!(
+        LalgrsMatrix::<i32>::identity(3),
+        LalgrsMatrix::from_rows(vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]).unwrap()
+    );
+}
+
+#[test]
+fn test_from_diagonal() {
+    assert_eq!(
+        LalgrsMatrix::from_diagonal(&LalgrsVector::new(vec![1, 2, 3])),
+        LalgrsMatrix::from_rows(vec![vec![1, 0, 0], vec![0, 2, 0], vec![0, 0, 3]]).unwrap()
+    );
+}
+
+#[test]
+fn test_from_fn() {
+    assert_eq!(
+        LalgrsMatrix::from_fn(2, 3, |i, j| 10 * i + j),
+        LalgrsMatrix::from_rows(vec![vec![0, 1, 2], vec![10, 11, 12]]).unwrap()
+    );
+}
+
+#[test]
+fn test_random_is_reproducible() {
+    let a = LalgrsMatrix::random_uniform(3, 4, -1.0, 1.0, &mut Rng::seed_from_u64(42));
+    let b = LalgrsMatrix::random_uniform(3, 4, -1.0, 1.0, &mut Rng::seed_from_u64(42));
+    let c = LalgrsMatrix::random_uniform(3, 4, -1.0, 1.0, &mut Rng::seed_from_u64(43));
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    let mut rng = Rng::seed_from_u64(7);
+    for _ in 0..1000 {
+        let x = rng.uniform(2.0, 3.0);
+        assert!((2.0..3.0).contains(&x));
+    }
+}
+
+#[test]
+fn test_random_normal_moments() {
+    let mut rng = Rng::seed_from_u64(1);
+    let samples: Vec<f64> = (0..20000).map(|_| rng.normal()).collect();
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    assert!(mean.abs() < 0.05);
+    assert!((variance - 1.0).abs() < 0.05);
+}
+
+#[test]
+fn test_random_orthogonal() {
+    let q = LalgrsMatrix::random_orthogonal(4, &mut Rng::seed_from_u64(3));
+    let product = (q.transpose() * q).unwrap();
+    assert_close(&product, &LalgrsMatrix::identity(4), 4);
+}
+
+#[test]
+fn test_random_spd() {
+    let a = LalgrsMatrix::random_spd(4, &mut Rng::seed_from_u64(5));
+    assert_eq!(a.transpose(), a);
+    for i in 0..4 {
+        assert!(*a.get(i, i).unwrap() > 0.0);
+    }
+}
@@ -0,0 +1,186 @@
+use lalgrs::{
+    matrix_equations::{
+        solve_continuous_lyapunov, solve_continuous_riccati, solve_discrete_lyapunov,
+        solve_discrete_riccati, solve_sylvester,
+    },
+    LalgrsError, LalgrsMatrix, Rng,
+};
+
+fn product(lhs: &LalgrsMatrix<f64>, rhs: &LalgrsMatrix<f64>) -> LalgrsMatrix<f64> {
+    (lhs.clone() * rhs.clone()).unwrap()
+}
+
+fn sum(terms: &[LalgrsMatrix<f64>]) -> LalgrsMatrix<f64> {
+    terms[1..]
+        .iter()
+        .fold(terms[0].clone(), |sum, term| (sum + term.clone()).unwrap())
+}
+
+/// Random matrix with eigenvalues in the open left half plane
+fn stable_matrix(n: usize, rng: &mut Rng) -> LalgrsMatrix<f64> {
+    let a = LalgrsMatrix::random_normal(n, n, 0.0, 1.0, rng);
+    let shift = a.frobenius_norm();
+    (a + LalgrsMatrix::identity(n) * -shift).unwrap()
+}
+
+#[test]
+fn test_sylvester() {
+    let mut rng = Rng::seed_from_u64(50);
+    let a = LalgrsMatrix::random_normal(5, 5, 0.0, 1.0, &mut rng);
+    let b = (LalgrsMatrix::random_normal(3, 3, 0.0, 1.0, &mut rng)
+        + LalgrsMatrix::identity(3) * 10.0)
+        .unwrap();
+    let c = LalgrsMatrix::random_normal(5, 3, 0.0, 1.0, &mut rng);
+    let x = solve_sylvester(&a, &b, &c).unwrap();
+    assert!(sum(&[product(&a, &x), product(&x, &b)]).approx_eq(&c, 1e-10, 0.0));
+
+    // A and -B share the eigenvalue 1
+    let a = LalgrsMatrix::from_rows(vec![vec![1.0, 0.0], vec![0.0, 2.0]]).unwrap();
+    let b = LalgrsMatrix::from_rows(vec![vec![-1.0, 0.0], vec![0.0, 3.0]]).unwrap();
+    assert_eq!(
+        solve_sylvester(&a, &b, &LalgrsMatrix::identity(2)).unwrap_err(),
+        LalgrsError::SingularMatrix
+    );
+    assert!(matches!(
+        solve_sylvester(&a, &b, &LalgrsMatrix::zeros(2, 3)),
+        Err(LalgrsError::MismatchedMatrixDimensions { .. })
+    ));
+}
+
+#[test]
+fn test_lyapunov() {
+    let mut rng = Rng::seed_from_u64(51);
+    let a = stable_matrix(6, &mut rng);
+    let q = LalgrsMatrix::identity(6);
+    let x = solve_continuous_lyapunov(&a, &q).unwrap();
+    assert_eq!(x, x.transpose());
+    assert!(x.cholesky().is_ok());
+    let residual = sum(&[product(&a, &x), product(&x, &a.transpose()), q.clone()]);
+    assert!(residual.approx_eq(&LalgrsMatrix::zeros(6, 6), 1e-10, 0.0));
+
+    // Spectral radius below one, and a truncated series as reference
+    let a = LalgrsMatrix::random_normal(6, 6, 0.0, 1.0, &mut rng);
+    let a = a.clone() * (0.5 / a.frobenius_norm());
+    let q = LalgrsMatrix::random_normal(6, 6, 0.0, 1.0, &mut rng);
+    let x = solve_discrete_lyapunov(&a, &q).unwrap();
+    let residual = sum(&[
+        product(&product(&a, &x), &a.transpose()),
+        -x.clone(),
+        q.clone(),
+    ]);
+    assert!(residual.approx_eq(&LalgrsMatrix::zeros(6, 6), 1e-12, 0.0));
+    let mut series = q.clone();
+    let mut term = q;
+    for _ in 0..60 {
+        term = product(&product(&a, &term), &a.transpose());
+        series = (series + term.clone()).unwrap();
+    }
+    assert!(x.approx_eq(&series, 1e-12, 0.0));
+
+    // The eigenvalues 2 and 0.5 multiply to one
+    let a = LalgrsMatrix::from_rows(vec![vec![2.0, 1.0], vec![0.0, 0.5]]).unwrap();
+    assert_eq!(
+        solve_discrete_lyapunov(&a, &LalgrsMatrix::identity(2)).unwrap_err(),
+        LalgrsError::SingularMatrix
+    );
+}
+
+#[test]
+fn test_continuous_riccati() {
+    // Double integrator with unit weights, X = [[√3, 1], [1, √3]]
+    let a = LalgrsMatrix::from_rows(vec![vec![0.0, 1.0], vec![0.0, 0.0]]).unwrap();
+    let b = LalgrsMatrix::from_rows(vec![vec![0.0], vec![1.0]]).unwrap();
+    let (q, r) = (LalgrsMatrix::identity(2), LalgrsMatrix::identity(1));
+    let x = solve_continuous_riccati(&a, &b, &q, &r).unwrap();
+    let expected =
+        LalgrsMatrix::from_rows(vec![vec![3f64.sqrt(), 1.0], vec![1.0, 3f64.sqrt()]]).unwrap();
+    assert!(x.approx_eq(&expected, 1e-12, 0.0));
+
+    // Unstable random plant: the LQR gain K = R⁻¹ * Bᵀ * X stabilises it
+    let mut rng = Rng::seed_from_u64(52);
+    let a = LalgrsMatrix::random_normal(5, 5, 0.0, 1.0, &mut rng);
+    let b = LalgrsMatrix::random_normal(5, 2, 0.0, 1.0, &mut rng);
+    let q = LalgrsMatrix::identity(5);
+    let r = LalgrsMatrix::from_rows(vec![vec![2.0, 0.5], vec![0.5, 1.0]]).unwrap();
+    let x = solve_continuous_riccati(&a, &b, &q, &r).unwrap();
+    let k = product(&r.inverse().unwrap(), &product(&b.transpose(), &x));
+    let residual = sum(&[
+        product(&a.transpose(), &x),
+        product(&x, &a),
+        -product(&product(&x, &b), &k),
+        q,
+    ]);
+    assert!(residual.approx_eq(&LalgrsMatrix::zeros(5, 5), 1e-9, 0.0));
+    let closed_loop = (a + -product(&b, &k)).unwrap();
+    assert!(closed_loop
+        .eigenvalues()
+        .unwrap()
+        .iter()
+        .all(|z| z.re < 0.0));
+
+    // Neither controllable nor stable: the Hamiltonian has eigenvalues on the imaginary axis
+    let zero = LalgrsMatrix::zeros(1, 1);
+    let one = LalgrsMatrix::identity(1);
+    assert_eq!(
+        solve_continuous_riccati(&zero, &zero, &one, &one).unwrap_err(),
+        LalgrsError::NoStabilisingSolution
+    );
+    // An unstable mode that the input can not reach
+    assert_eq!(
+        solve_continuous_riccati(&one, &zero, &one, &one).unwrap_err(),
+        LalgrsError::NoStabilisingSolution
+    );
+    assert_eq!(
+        solve_continuous_riccati(&one, &one, &one, &-one.clone()).unwrap_err(),
+        LalgrsError::NotPositiveDefinite
+    );
+}
+
+#[test]
+fn test_discrete_riccati() {
+    // Scalar equation X = X - X² / (1 + X) + 1, solved by the golden ratio
+    let one = LalgrsMatrix::identity(1);
+    let x = solve_discrete_riccati(&one, &one, &one, &one).unwrap();
+    assert!((x[(0, 0)] - (1.0 + 5f64.sqrt()) / 2.0).abs() < 1e-12);
+
+    // A singular and unstable plant
+    let mut rng = Rng::seed_from_u64(53);
+    let mut a = LalgrsMatrix::random_normal(4, 4, 0.0, 1.0, &mut rng);
+    for j in 0..4 {
+        a[(3, j)] = 0.0;
+    }
+    let b = LalgrsMatrix::random_normal(4, 1, 0.0, 1.0, &mut rng);
+    let (q, r) = (LalgrsMatrix::identity(4), LalgrsMatrix::identity(1));
+    let x = solve_discrete_riccati(&a, &b, &q, &r).unwrap();
+    let btxb = product(&product(&b.transpose(), &x), &b);
+    let k = product(
+        &(r + btxb).unwrap().inverse().unwrap(),
+        &product(&product(&b.transpose(), &x), &a),
+    );
+    let residual = sum(&[
+        product(&product(&a.transpose(), &x), &a),
+        -x.clone(),
+        -product(&product(&product(&a.transpose(), &x), &b), &k),
+        q,
+    ]);
+    assert!(residual.approx_eq(&LalgrsMatrix::zeros(4, 4), 1e-9, 0.0));
+    let closed_loop = (a + -product(&b, &k)).unwrap();
+    assert!(closed_loop
+        .eigenvalues()
+        .unwrap()
+        .iter()
+        .all(|z| z.abs() < 1.0));
+
+    // Without state weight, (A, Q) is not detectable and the doubling iteration stalls at X = 0;
+    // the stabilising solution is X = 3, from X = 4X - 4X² / (1 + X)
+    let two = one.clone() * 2.0;
+    let zero = LalgrsMatrix::zeros(1, 1);
+    let x = solve_discrete_riccati(&two, &one, &zero, &one).unwrap();
+    assert!((x[(0, 0)] - 3.0).abs() < 1e-12);
+
+    // An unstable mode that the input can not reach
+    assert_eq!(
+        solve_discrete_riccati(&two, &zero, &one, &one).unwrap_err(),
+        LalgrsError::NoStabilisingSolution
+    );
+}
@@ -0,0 +1,159 @@
+use lalgrs::{assert_matrix_approx_eq, LalgrsError, LalgrsMatrix, LalgrsVector, Rng, Shape};
+
+fn init_spd() -> LalgrsMatrix<f64> {
+    LalgrsMatrix::from_rows(vec![
+        vec![4.0, 1.0, 0.5],
+        vec![1.0, 3.0, 0.25],
+        vec![0.5, 0.25, 2.0],
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_lu() {
+    let mut rng = Rng::seed_from_u64(5);
+    let matrix = LalgrsMatrix::random_uniform(5, 5, -1.0, 1.0, &mut rng);
+    let lu = matrix.lu().unwrap();
+    let permuted = LalgrsMatrix::from_fn(5, 5, |i, j| matrix[(lu.permutation()[i], j)]);
+    assert_matrix_approx_eq!((lu.l() * lu.u()).unwrap(), permuted, 1e-12, 0.0);
+
+    let b = LalgrsVector::new(vec![1.0, -2.0, 0.5, 3.0, 0.0]);
+    let x = matrix.solve(&b).unwrap();
+    let residual = (matrix.clone() * x).unwrap();
+    for (r, e) in residual.values.iter().zip(b.values.iter()) {
+        assert!((r - e).abs() <= 1e-12, "{residual:?} != {b:?}");
+    }
+
+    let inverse = matrix.inverse().unwrap();
+    assert_matrix_approx_eq!(
+        (matrix * inverse).unwrap(),
+        LalgrsMatrix::identity(5),
+        1e-12,
+        0.0
+    );
+}
+
+#[test]
+fn test_lu_determinant() {
+    // A single row swap makes the permutation odd
+    let swapped = LalgrsMatrix::from_rows(vec![vec![0.0, 2.0], vec![3.0, 1.0]]).unwrap();
+    assert!((swapped.determinant().unwrap() + 6.0).abs() < 1e-12);
+    assert!((init_spd().determinant().unwrap() - 21.25).abs() < 1e-12);
+
+    let singular = LalgrsMatrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    assert!(singular.lu().unwrap().is_singular());
+    assert_eq!(singular.inverse(), Err(LalgrsError::SingularMatrix));
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(2, 3).lu(),
+        Err(LalgrsError::NonSquareMatrix {
+            shape: Shape {
+                rows: 2,
+                columns: 3
+            }
+        })
+    );
+}
+
+#[test]
+fn test_expm() {
+    let diagonal = LalgrsMatrix::from_rows(vec![vec![1.0, 0.0], vec![0.0, -2.0]]).unwrap();
+    let expected =
+        LalgrsMatrix::from_rows(vec![vec![1f64.exp(), 0.0], vec![0.0, (-2f64).exp()]]).unwrap();
+    assert_matrix_approx_eq!(diagonal.expm().unwrap(), expected, 1e-14, 0.0);
+
+    // A nilpotent matrix has a finite exponential series
+    let nilpotent = LalgrsMatrix::from_rows(vec![
+        vec![0.0, 1.0, 2.0],
+        vec![0.0, 0.0, 3.0],
+        vec![0.0, 0.0, 0.0],
+    ])
+    .unwrap();
+    let expected = LalgrsMatrix::from_rows(vec![
+        vec![1.0, 1.0, 3.5],
+        vec![0.0, 1.0, 3.0],
+        vec![0.0, 0.0, 1.0],
+    ])
+    .unwrap();
+    assert_matrix_approx_eq!(nilpotent.expm().unwrap(), expected, 1e-14, 0.0);
+
+    // Large enough to need scaling and squaring
+    let angle = 10.0;
+    let generator = LalgrsMatrix::from_rows(vec![vec![0.0, -angle], vec![angle, 0.0]]).unwrap();
+    let rotation = LalgrsMatrix::from_rows(vec![
+        vec![f64::cos(angle), -f64::sin(angle)],
+        vec![f64::sin(angle), f64::cos(angle)],
+    ])
+    .unwrap();
+    assert_matrix_approx_eq!(generator.expm().unwrap(), rotation, 1e-12, 0.0);
+}
+
+#[test]
+fn test_sqrtm_and_logm() {
+    let matrix = init_spd();
+    let root = matrix.sqrtm().unwrap();
+    assert_matrix_approx_eq!((root.clone() * root).unwrap(), matrix, 1e-12, 0.0);
+
+    let log = matrix.logm().unwrap();
+    assert_matrix_approx_eq!(log.expm().unwrap(), matrix, 1e-12, 0.0);
+
+    let mut rng = Rng::seed_from_u64(9);
+    let small = LalgrsMatrix::random_uniform(4, 4, -0.5, 0.5, &mut rng);
+    assert_matrix_approx_eq!(small.expm().unwrap().logm().unwrap(), small, 1e-12, 0.0);
+
+    let singular = LalgrsMatrix::from_rows(vec![vec![1.0, 1.0], vec![1.0, 1.0]]).unwrap();
+    assert_eq!(singular.sqrtm(), Err(LalgrsError::SingularMatrix));
+    assert_eq!(singular.logm(), Err(LalgrsError::SingularMatrix));
+}
+
+#[test]
+fn test_powers() {
+    let matrix = LalgrsMatrix::from_rows(vec![vec![1.0, 1.0], vec![1.0, 0.0]]).unwrap();
+    // Powers of the Fibonacci matrix
+    let expected = LalgrsMatrix::from_rows(vec![vec![89.0, 55.0], vec![55.0, 34.0]]).unwrap();
+    assert_matrix_approx_eq!(matrix.powi(10).unwrap(), expected, 0.0, 0.0);
+    assert_matrix_approx_eq!(matrix.powi(0).unwrap(), LalgrsMatrix::identity(2), 0.0, 0.0);
+    assert_matrix_approx_eq!(
+        (matrix.powi(-3).unwrap() * matrix.powi(3).unwrap()).unwrap(),
+        LalgrsMatrix::identity(2),
+        1e-12,
+        0.0
+    );
+
+    let spd = init_spd();
+    assert_matrix_approx_eq!(spd.powf(0.5).unwrap(), spd.sqrtm().unwrap(), 1e-12, 0.0);
+    assert_matrix_approx_eq!(spd.powf(2.0).unwrap(), spd.powi(2).unwrap(), 0.0, 0.0);
+    let third = spd.powf(1.0 / 3.0).unwrap();
+    assert_matrix_approx_eq!(third.powi(3).unwrap(), spd, 1e-12, 0.0);
+
+    let singular = LalgrsMatrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    assert_eq!(singular.powi(-1), Err(LalgrsError::SingularMatrix));
+    assert!(matches!(
+        spd.powf(f64::NAN),
+        Err(LalgrsError::InvalidParameter { name: "p", .. })
+    ));
+    assert!(matches!(
+        LalgrsMatrix::from_element(1, 1, f64::INFINITY).expm(),
+        Err(LalgrsError::InvalidParameter {
+            name: "element",
+            ..
+        })
+    ));
+    assert!(matches!(
+        LalgrsMatrix::from_rows(vec![vec![1.0, f64::NAN], vec![0.0, 1.0]])
+            .unwrap()
+            .expm(),
+        Err(LalgrsError::InvalidParameter {
+            name: "element",
+            ..
+        })
+    ));
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(2, 3).expm(),
+        Err(LalgrsError::NonSquareMatrix {
+            shape: Shape {
+                rows: 2,
+                columns: 3
+            }
+        })
+    );
+}
@@ -1,4 +1,4 @@
-use lalgrs::{LalgrsError, LalgrsMatrix, LalgrsVector};
+use lalgrs::{LalgrsError, LalgrsMatrix, LalgrsVector, Rational, Shape};
 
 fn init_2x2_matrices() -> (LalgrsMatrix<f64>, LalgrsMatrix<f64>) {
     let matrix1 = LalgrsMatrix::new(vec![vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap();
@@ -55,15 +55,15 @@ fn test_matrix_add_error() {
     assert_eq!(
         (matrix_3x3_1 + matrix_2x2_2).unwrap_err(),
         LalgrsError::MismatchedMatrixDimensions {
-            first_matrix_columns: 3,
-            second_matrix_rows: 2
+            lhs: Shape::new(3, 3),
+            rhs: Shape::new(2, 2)
         }
     );
     assert_eq!(
         (matrix_2x2_1 + matrix_3x3_2).unwrap_err(),
         LalgrsError::MismatchedMatrixDimensions {
-            first_matrix_columns: 2,
-            second_matrix_rows: 3
+            lhs: Shape::new(2, 2),
+            rhs: Shape::new(3, 3)
         }
     );
 }
@@ -75,15 +75,15 @@ fn test_matrix_sub_error() {
     assert_eq!(
         (matrix_3x3_1 - matrix_2x2_2).unwrap_err(),
         LalgrsError::MismatchedMatrixDimensions {
-            first_matrix_columns: 3,
-            second_matrix_rows: 2
+            lhs: Shape::new(3, 3),
+            rhs: Shape::new(2, 2)
         }
     );
     assert_eq!(
         (matrix_2x2_1 - matrix_3x3_2).unwrap_err(),
         LalgrsError::MismatchedMatrixDimensions {
-            first_matrix_columns: 2,
-            second_matrix_rows: 3
+            lhs: Shape::new(2, 2),
+            rhs: Shape::new(3, 3)
         }
     );
 }
@@ -147,15 +147,15 @@ fn test_matrix_mul_error() {
     assert_eq!(
         (matrix_3x3_1 * matrix_2x2_2).unwrap_err(),
         LalgrsError::MismatchedMatrixDimensions {
-            first_matrix_columns: 3,
-            second_matrix_rows: 2
+            lhs: Shape::new(3, 3),
+            rhs: Shape::new(2, 2)
         }
     );
     assert_eq!(
         (matrix_2x2_1 * matrix_3x3_2).unwrap_err(),
         LalgrsError::MismatchedMatrixDimensions {
-            first_matrix_columns: 2,
-            second_matrix_rows: 3
+            lhs: Shape::new(2, 2),
+            rhs: Shape::new(3, 3)
         }
     );
 }
@@ -202,3 +202,168 @@ fn test_matrix_vector_mul() {
         LalgrsVector::new(vec![23.0, 34.0])
     );
 }
+
+#[test]
+fn test_matrix_shape() {
+    let matrix = LalgrsMatrix::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    assert_eq!(matrix.rows(), 2);
+    assert_eq!(matrix.columns(), 3);
+    assert_eq!(matrix.shape(), Shape::new(2, 3));
+    assert_eq!(<(usize, usize)>::from(matrix.shape()), (2, 3));
+    assert_eq!(matrix.shape().to_string(), "2x3");
+    assert_eq!(matrix[(0, 1)], 3);
+    assert_eq!(matrix[(1, 2)], 6);
+    assert_eq!(matrix.transpose().shape(), Shape::new(3, 2));
+
+    let empty_matrix: LalgrsMatrix<i32> = LalgrsMatrix::new(vec![]).unwrap();
+    assert_eq!(empty_matrix.shape(), Shape::new(0, 0));
+    assert!(empty_matrix.is_empty());
+}
+
+#[test]
+fn test_matrix_add_transposed_shape_error() {
+    let matrix_2x3 = LalgrsMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let matrix_3x2 = matrix_2x3.transpose();
+    assert_eq!(
+        (matrix_2x3.clone() + matrix_3x2.clone()).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(2, 3),
+            rhs: Shape::new(3, 2)
+        }
+    );
+    assert_eq!(
+        (matrix_3x2 - matrix_2x3).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(3, 2),
+            rhs: Shape::new(2, 3)
+        }
+    );
+}
+
+#[test]
+fn test_matrix_mul_non_square() {
+    let matrix_2x3 = LalgrsMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let matrix_3x1 = LalgrsMatrix::from_rows(vec![vec![1], vec![0], vec![-1]]).unwrap();
+    assert_eq!(
+        (matrix_2x3.clone() * matrix_3x1.clone()).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![-2], vec![-2]]).unwrap()
+    );
+    assert_eq!(
+        (matrix_3x1 * matrix_2x3.clone()).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(3, 1),
+            rhs: Shape::new(2, 3)
+        }
+    );
+    assert_eq!(
+        (matrix_2x3.clone() * LalgrsVector::new(vec![1, 1, 1])).unwrap(),
+        LalgrsVector::new(vec![6, 15])
+    );
+    assert_eq!(
+        (matrix_2x3 * LalgrsVector::new(vec![1, 1])).unwrap_err(),
+        LalgrsError::MismatchedVectorAndMatrixDimensions {
+            vector_size: 2,
+            matrix_shape: Shape::new(2, 3)
+        }
+    );
+}
+
+#[test]
+fn test_matrix_operations_with_zero_dimension() {
+    let (matrix_3x3, _) = init_3x3_matrices();
+    let matrix_2x0: LalgrsMatrix<f64> = LalgrsMatrix::zeros(2, 0);
+    assert_eq!(
+        (matrix_2x0.clone() + matrix_3x3.clone()).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(2, 0),
+            rhs: Shape::new(3, 3)
+        }
+    );
+    assert_eq!(
+        (matrix_2x0.clone() + matrix_2x0.clone()).unwrap(),
+        matrix_2x0
+    );
+    assert_eq!(
+        (matrix_2x0.clone() * LalgrsMatrix::zeros(5, 3)).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(2, 0),
+            rhs: Shape::new(5, 3)
+        }
+    );
+
+    // The inner dimension is zero, so every element is an empty sum
+    let product = (LalgrsMatrix::<i32>::zeros(3, 0) * LalgrsMatrix::zeros(0, 4)).unwrap();
+    assert_eq!(product, LalgrsMatrix::zeros(3, 4));
+    assert_eq!(
+        (LalgrsMatrix::<i32>::zeros(3, 0) * LalgrsVector::new(vec![])).unwrap(),
+        LalgrsVector::new(vec![0, 0, 0])
+    );
+    assert_eq!(
+        (LalgrsMatrix::<i32>::zeros(0, 3) * LalgrsVector::new(vec![1, 2, 3])).unwrap(),
+        LalgrsVector::new(vec![])
+    );
+}
+
+fn init_large_rational_row() -> LalgrsMatrix<Rational> {
+    let half = Rational::from(i64::MAX / 2);
+    LalgrsMatrix::from_rows(vec![vec![half, half]]).unwrap()
+}
+
+#[test]
+fn test_checked_matrix_arithmetic() {
+    let row = init_large_rational_row();
+    assert_eq!(
+        row.checked_mul(&row.transpose()).unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+    assert_eq!(
+        row.checked_add(&row).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![Rational::from(i64::MAX - 1); 2]]).unwrap()
+    );
+    assert_eq!(
+        (row.checked_add(&row).unwrap())
+            .checked_add(&row)
+            .unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+    assert_eq!(
+        row.transpose()
+            .checked_mul_vector(&LalgrsVector::new(vec![Rational::from(3)]))
+            .unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+
+    // Without overflow, the checked methods agree with the operators
+    let (matrix1, matrix2) = init_3x3_matrices();
+    assert_eq!(
+        matrix1.checked_add(&matrix2).unwrap(),
+        (matrix1.clone() + matrix2.clone()).unwrap()
+    );
+    assert_eq!(
+        matrix1.checked_sub(&matrix2).unwrap(),
+        (matrix1.clone() - matrix2.clone()).unwrap()
+    );
+    assert_eq!(
+        matrix1.checked_mul(&matrix2).unwrap(),
+        (matrix1.clone() * matrix2.clone()).unwrap()
+    );
+    let empty = LalgrsMatrix::new(vec![]).unwrap();
+    assert_eq!(empty.checked_sub(&matrix2).unwrap(), -matrix2.clone());
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(3, 0)
+            .checked_mul(&LalgrsMatrix::zeros(0, 4))
+            .unwrap(),
+        LalgrsMatrix::zeros(3, 4)
+    );
+    assert!(matches!(
+        matrix1.checked_mul(&LalgrsMatrix::zeros(2, 2)),
+        Err(LalgrsError::MismatchedMatrixDimensions { .. })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn test_matrix_operator_overflow_panics() {
+    let row = init_large_rational_row();
+    let _ = row.clone() * row.transpose();
+}
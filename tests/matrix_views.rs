@@ -0,0 +1,304 @@
+use lalgrs::{LalgrsError, LalgrsMatrix, LalgrsVector, MatrixView, Shape};
+
+fn init_3x4_matrix() -> LalgrsMatrix<i32> {
+    LalgrsMatrix::from_rows(vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12],
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_submatrix() {
+    let matrix = init_3x4_matrix();
+    let view = matrix.submatrix(1, 1, Shape::new(2, 2)).unwrap();
+    assert_eq!(view.shape(), Shape::new(2, 2));
+    assert_eq!(view[(0, 0)], 6);
+    assert_eq!(view[(1, 1)], 11);
+    assert_eq!(view.get(2, 0), None);
+    assert_eq!(
+        view.to_matrix(),
+        LalgrsMatrix::from_rows(vec![vec![6, 7], vec![10, 11]]).unwrap()
+    );
+
+    let nested = view.submatrix(1, 0, Shape::new(1, 2)).unwrap();
+    assert_eq!(nested.to_vector(), LalgrsVector::new(vec![10, 11]));
+}
+
+#[test]
+fn test_submatrix_error() {
+    let matrix = init_3x4_matrix();
+    assert_eq!(
+        matrix.submatrix(2, 3, Shape::new(2, 1)).unwrap_err(),
+        LalgrsError::SubmatrixOutOfBounds {
+            row: 2,
+            column: 3,
+            shape: Shape::new(2, 1),
+            matrix_shape: Shape::new(3, 4)
+        }
+    );
+    assert!(matrix.row(3).is_err());
+    assert!(matrix.column(4).is_err());
+    assert!(matrix.strided(0, 0, Shape::new(2, 2), 0, 1).is_err());
+}
+
+#[test]
+fn test_row_column_and_strided_views() {
+    let matrix = init_3x4_matrix();
+    assert_eq!(
+        matrix.row(1).unwrap().to_vector(),
+        LalgrsVector::new(vec![5, 6, 7, 8])
+    );
+    assert_eq!(
+        matrix.column(2).unwrap().to_vector(),
+        LalgrsVector::new(vec![3, 7, 11])
+    );
+    assert_eq!(
+        matrix
+            .strided(0, 1, Shape::new(2, 2), 2, 2)
+            .unwrap()
+            .to_matrix(),
+        LalgrsMatrix::from_rows(vec![vec![2, 4], vec![10, 12]]).unwrap()
+    );
+    assert_eq!(matrix.view().transpose().to_matrix(), matrix.transpose());
+}
+
+#[test]
+fn test_strided_views_with_overflowing_steps() {
+    let mut matrix = LalgrsMatrix::from_rows(vec![vec![1], vec![2]]).unwrap();
+    let out_of_bounds = LalgrsError::SubmatrixOutOfBounds {
+        row: 1,
+        column: 0,
+        shape: Shape::new(2, 1),
+        matrix_shape: Shape::new(2, 1),
+    };
+    assert_eq!(
+        matrix
+            .strided(1, 0, Shape::new(2, 1), usize::MAX, 1)
+            .unwrap_err(),
+        out_of_bounds
+    );
+    assert_eq!(
+        matrix
+            .view_mut()
+            .into_strided(1, 0, Shape::new(2, 1), usize::MAX, 1)
+            .unwrap_err(),
+        out_of_bounds
+    );
+    assert!(matrix
+        .view()
+        .strided(0, 0, Shape::new(2, 1), usize::MAX / 2 + 1, 1)
+        .is_err());
+
+    // The step of a dimension with a single element is never used
+    let row = matrix
+        .view_mut()
+        .into_strided(1, 0, Shape::new(1, 1), usize::MAX, usize::MAX)
+        .unwrap();
+    assert_eq!(row[(0, 0)], 2);
+    assert_eq!(
+        matrix
+            .strided(0, 0, Shape::new(1, 1), usize::MAX, usize::MAX)
+            .unwrap()
+            .to_matrix(),
+        LalgrsMatrix::from_rows(vec![vec![1]]).unwrap()
+    );
+}
+
+#[test]
+fn test_view_from_slice() {
+    let data = [1, 2, 3, 4, 5, 6];
+    let view = MatrixView::from_column_major_slice(&data, Shape::new(2, 3)).unwrap();
+    assert_eq!(
+        view.to_matrix(),
+        LalgrsMatrix::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap()
+    );
+    assert_eq!(
+        MatrixView::from_column_major_slice(&data, Shape::new(2, 2)).unwrap_err(),
+        LalgrsError::InvalidElementCount {
+            expected: 4,
+            found: 6
+        }
+    );
+    assert_eq!(
+        MatrixView::<i32>::from_column_major_slice(&[], Shape::new(usize::MAX, 2)).unwrap_err(),
+        LalgrsError::InvalidMatrixDimensions
+    );
+}
+
+#[test]
+fn test_view_operations() {
+    let matrix = init_3x4_matrix();
+    let left = matrix.submatrix(0, 0, Shape::new(2, 2)).unwrap();
+    let right = matrix.submatrix(0, 2, Shape::new(2, 2)).unwrap();
+
+    assert_eq!(
+        (left + right).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![4, 6], vec![12, 14]]).unwrap()
+    );
+    assert_eq!(
+        (right - left).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![2, 2], vec![2, 2]]).unwrap()
+    );
+    assert_eq!(
+        (left * right).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![17, 20], vec![57, 68]]).unwrap()
+    );
+    assert_eq!(
+        (left * LalgrsVector::new(vec![1, -1])).unwrap(),
+        LalgrsVector::new(vec![-1, -1])
+    );
+    assert_eq!(
+        -left,
+        LalgrsMatrix::from_rows(vec![vec![-1, -2], vec![-5, -6]]).unwrap()
+    );
+    assert_eq!(
+        left * 2,
+        LalgrsMatrix::from_rows(vec![vec![2, 4], vec![10, 12]]).unwrap()
+    );
+
+    let identity = LalgrsMatrix::<i32>::identity(2);
+    assert_eq!((left * &identity).unwrap(), left.to_matrix());
+    assert_eq!((&identity * left).unwrap(), left.to_matrix());
+    assert_eq!(
+        (identity.clone() + left).unwrap(),
+        (&identity + &left.to_matrix()).unwrap()
+    );
+
+    assert_eq!(
+        (left + matrix.row(0).unwrap()).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(2, 2),
+            rhs: Shape::new(1, 4)
+        }
+    );
+}
+
+#[test]
+fn test_mutable_views() {
+    let mut matrix = init_3x4_matrix();
+    matrix.row_mut(0).unwrap().fill(0);
+    matrix.column_mut(3).unwrap()[(2, 0)] = 100;
+    assert_eq!(
+        matrix,
+        LalgrsMatrix::from_rows(vec![
+            vec![0, 0, 0, 0],
+            vec![5, 6, 7, 8],
+            vec![9, 10, 11, 100],
+        ])
+        .unwrap()
+    );
+
+    let source = LalgrsMatrix::from_rows(vec![vec![-1, -2], vec![-3, -4]]).unwrap();
+    matrix
+        .submatrix_mut(1, 1, Shape::new(2, 2))
+        .unwrap()
+        .copy_from(&source.view())
+        .unwrap();
+    assert_eq!(
+        matrix
+            .submatrix(1, 1, Shape::new(2, 2))
+            .unwrap()
+            .to_matrix(),
+        source
+    );
+    assert!(matrix
+        .submatrix_mut(0, 0, Shape::new(1, 1))
+        .unwrap()
+        .copy_from(&source.view())
+        .is_err());
+}
+
+#[test]
+fn test_split_mutable_views() {
+    let mut matrix = LalgrsMatrix::<i32>::zeros(4, 4);
+    let (top, bottom) = matrix.view_mut().split_at_row(1).unwrap();
+    let (mut top_left, mut top_right) = top.split_at_column(2).unwrap();
+    let (mut bottom_left, mut bottom_right) = bottom.split_at_column(3).unwrap();
+    top_left.fill(1);
+    top_right.fill(2);
+    bottom_left.fill(3);
+    bottom_right.fill(4);
+    assert_eq!(top_left.shape(), Shape::new(1, 2));
+    assert_eq!(bottom_right.shape(), Shape::new(3, 1));
+    assert_eq!(
+        matrix,
+        LalgrsMatrix::from_rows(vec![
+            vec![1, 1, 2, 2],
+            vec![3, 3, 3, 4],
+            vec![3, 3, 3, 4],
+            vec![3, 3, 3, 4],
+        ])
+        .unwrap()
+    );
+
+    assert!(matrix.view_mut().split_at_row(5).is_err());
+    assert!(matrix.view_mut().split_at_column(5).is_err());
+}
+
+#[test]
+fn test_mutable_view_operations() {
+    let mut matrix = init_3x4_matrix();
+    let left = matrix
+        .submatrix(0, 0, Shape::new(3, 2))
+        .unwrap()
+        .to_matrix();
+    let right = matrix
+        .submatrix(0, 2, Shape::new(3, 2))
+        .unwrap()
+        .to_matrix();
+    let sum = (&left + &right).unwrap();
+
+    // Mutable views are consumed by the operators, like matrices
+    let (left_view, right_view) = matrix.view_mut().split_at_column(2).unwrap();
+    assert_eq!((left_view + right_view).unwrap(), sum);
+    let (left_view, right_view) = matrix.view_mut().split_at_column(2).unwrap();
+    assert_eq!(
+        (right_view - left.view()).unwrap(),
+        (&right - &left).unwrap()
+    );
+    assert_eq!((&right + left_view).unwrap(), sum);
+    let (left_view, right_view) = matrix.view_mut().split_at_column(2).unwrap();
+    assert_eq!(
+        (left_view * &right.transpose()).unwrap(),
+        (&left * &right.transpose()).unwrap()
+    );
+    assert_eq!(
+        (right.transpose() * right_view).unwrap(),
+        (right.transpose() * right.clone()).unwrap()
+    );
+
+    let (left_view, _) = matrix.view_mut().split_at_column(2).unwrap();
+    assert_eq!(-left_view, -left.clone());
+    let (left_view, _) = matrix.view_mut().split_at_column(2).unwrap();
+    assert_eq!(left_view * 2, left.clone() * 2);
+    let (left_view, _) = matrix.view_mut().split_at_column(2).unwrap();
+    assert_eq!(
+        (left_view * LalgrsVector::new(vec![1, 0])).unwrap(),
+        LalgrsVector::new(vec![1, 5, 9])
+    );
+}
+
+#[test]
+fn test_read_and_write_split_views_concurrently() {
+    // The rows of a column major matrix are interleaved, so each half lies between elements of the other
+    let mut matrix = LalgrsMatrix::from_fn(64, 64, |i, j| (i * 64 + j) as i64);
+    let (top, mut bottom) = matrix.view_mut().split_at_row(32).unwrap();
+    let sum = std::thread::scope(|scope| {
+        let reader = scope.spawn(move || {
+            let view = top.as_view();
+            (0..100)
+                .map(|_| view.iter().sum::<i64>())
+                .collect::<Vec<_>>()
+        });
+        for _ in 0..100 {
+            bottom.apply(|x| *x = -*x);
+        }
+        reader.join().unwrap()
+    });
+    let expected: i64 = (0..32 * 64).sum();
+    assert!(sum.iter().all(|s| *s == expected));
+    assert_eq!(matrix[(31, 63)], 31 * 64 + 63);
+    assert_eq!(matrix[(32, 0)], 32 * 64);
+}
@@ -0,0 +1,153 @@
+use lalgrs::{LalgrsError, LalgrsMatrix, LalgrsVector, Rational, Rng};
+
+fn init_hilbert(n: usize) -> LalgrsMatrix<f64> {
+    LalgrsMatrix::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64)
+}
+
+#[test]
+fn test_norms() {
+    let matrix = LalgrsMatrix::from_rows(vec![vec![1.0, -2.0], vec![3.0, 4.0]]).unwrap();
+    assert_eq!(matrix.one_norm(), 6.0);
+    assert_eq!(matrix.infinity_norm(), 7.0);
+    assert!((matrix.frobenius_norm() - 30f64.sqrt()).abs() < 1e-15);
+    // The spectral norm lies between the Frobenius norm divided by the square root of the rank and the Frobenius norm
+    let spectral = matrix.spectral_norm().unwrap();
+    assert!(
+        spectral <= matrix.frobenius_norm() && spectral >= matrix.frobenius_norm() / 2f64.sqrt()
+    );
+
+    let diagonal = LalgrsMatrix::from_rows(vec![vec![3.0, 0.0], vec![0.0, -4.0]]).unwrap();
+    assert!((diagonal.spectral_norm().unwrap() - 4.0).abs() < 1e-15);
+
+    // Huge elements do not overflow
+    let huge = LalgrsMatrix::from_element(2, 2, 1e200);
+    assert!((huge.frobenius_norm() - 2e200).abs() < 1e186);
+
+    let rationals = LalgrsMatrix::from_rows(vec![
+        vec![Rational::new(1, 2).unwrap(), Rational::from(-3)],
+        vec![Rational::new(-1, 4).unwrap(), Rational::from(1)],
+    ])
+    .unwrap();
+    assert_eq!(rationals.one_norm(), 4.0);
+    assert_eq!(rationals.infinity_norm(), 3.5);
+
+    let empty = LalgrsMatrix::<f64>::zeros(0, 0);
+    assert_eq!(empty.one_norm(), 0.0);
+    assert_eq!(empty.infinity_norm(), 0.0);
+    assert_eq!(empty.frobenius_norm(), 0.0);
+    assert_eq!(empty.spectral_norm(), Ok(0.0));
+}
+
+#[test]
+fn test_condition_number() {
+    let diagonal = LalgrsMatrix::from_rows(vec![vec![3.0, 0.0], vec![0.0, -4.0]]).unwrap();
+    assert!((diagonal.condition_number().unwrap() - 4.0 / 3.0).abs() < 1e-14);
+
+    let orthogonal = LalgrsMatrix::from_rows(vec![vec![0.6, -0.8], vec![0.8, 0.6]]).unwrap();
+    assert!((orthogonal.condition_number().unwrap() - 1.0).abs() < 1e-12);
+
+    let singular = LalgrsMatrix::from_rows(vec![vec![1.0, 0.0], vec![0.0, 0.0]]).unwrap();
+    assert_eq!(singular.condition_number(), Ok(f64::INFINITY));
+    assert_eq!(singular.condition_estimate(), Ok(f64::INFINITY));
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(0, 0).condition_number(),
+        Err(LalgrsError::EmptyMatrix)
+    );
+}
+
+#[test]
+fn test_condition_estimate() {
+    let mut rng = Rng::seed_from_u64(13);
+    for n in [1, 2, 5, 10] {
+        for _ in 0..10 {
+            let matrix = LalgrsMatrix::random_uniform(n, n, -1.0, 1.0, &mut rng);
+            let exact = matrix.one_norm() * matrix.inverse().unwrap().one_norm();
+            let estimate = matrix.condition_estimate().unwrap();
+            assert!(
+                estimate <= exact * (1.0 + 1e-10) && estimate >= exact / 3.0,
+                "{estimate} is not close to {exact}"
+            );
+        }
+    }
+
+    let hilbert = init_hilbert(8);
+    let exact = hilbert.one_norm() * hilbert.inverse().unwrap().one_norm();
+    let estimate = hilbert.condition_estimate().unwrap();
+    assert!((estimate - exact).abs() / exact < 1e-3);
+}
+
+#[test]
+fn test_check_condition() {
+    let lu = init_hilbert(8).lu().unwrap();
+    assert!(matches!(
+        lu.check_condition(1e8),
+        Err(LalgrsError::IllConditioned { estimate }) if estimate > 1e10
+    ));
+    assert!(lu.check_condition(1e12).is_ok());
+
+    let well_conditioned = LalgrsMatrix::from_rows(vec![vec![2.0, 1.0], vec![1.0, 3.0]])
+        .unwrap()
+        .lu()
+        .unwrap();
+    let estimate = well_conditioned.check_condition(10.0).unwrap();
+    // ‖A‖₁ = 4 and ‖A⁻¹‖₁ = 4 / 5
+    assert!((estimate - 3.2).abs() < 1e-12);
+    assert!(matches!(
+        well_conditioned.check_condition(f64::NAN),
+        Err(LalgrsError::InvalidParameter { name: "limit", .. })
+    ));
+
+    let singular = LalgrsMatrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]])
+        .unwrap()
+        .lu()
+        .unwrap();
+    assert_eq!(
+        singular.check_condition(1e12),
+        Err(LalgrsError::SingularMatrix)
+    );
+}
+
+#[test]
+fn test_solve_checked() {
+    // x = (1, ..., 1) solves the Hilbert system whose right hand side holds the row sums
+    let hilbert = init_hilbert(10);
+    let ones = LalgrsVector::new(vec![1.0; 10]);
+    let b = (hilbert.clone() * ones.clone()).unwrap();
+    assert!(matches!(
+        hilbert.solve_checked(&b, 1e10),
+        Err(LalgrsError::IllConditioned { estimate }) if estimate > 1e12
+    ));
+    let lu = hilbert.lu().unwrap();
+    assert!(matches!(
+        lu.solve_matrix_checked(&LalgrsMatrix::identity(10), 1e10),
+        Err(LalgrsError::IllConditioned { .. })
+    ));
+    // With a looser limit the solve goes ahead, and loses about log10(κ) digits
+    let x = lu.solve_checked(&b, 1e16).unwrap();
+    assert!(x.approx_eq(&ones, 1e-2, 0.0));
+    assert!(!x.approx_eq(&ones, 1e-12, 0.0));
+
+    let small = init_hilbert(3);
+    let b = (small.clone() * LalgrsVector::new(vec![1.0; 3])).unwrap();
+    let x = small.solve_checked(&b, 1e4).unwrap();
+    assert!(x.approx_eq(&LalgrsVector::new(vec![1.0; 3]), 1e-12, 0.0));
+    let inverse = small
+        .lu()
+        .unwrap()
+        .solve_matrix_checked(&LalgrsMatrix::identity(3), 1e4);
+    assert!(inverse
+        .unwrap()
+        .approx_eq(&small.inverse().unwrap(), 1e-12, 0.0));
+
+    assert!(matches!(
+        small.solve_checked(&LalgrsVector::new(vec![1.0; 2]), 1e4),
+        Err(LalgrsError::MismatchedVectorAndMatrixDimensions { .. })
+    ));
+    assert!(matches!(
+        small
+            .lu()
+            .unwrap()
+            .solve_matrix_checked(&LalgrsMatrix::zeros(2, 1), 1e4),
+        Err(LalgrsError::MismatchedMatrixDimensions { .. })
+    ));
+}
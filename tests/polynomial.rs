@@ -0,0 +1,204 @@
+use lalgrs::{Complex, LalgrsError, LalgrsMatrix, LalgrsVector, Polynomial, Rational, Rng};
+
+fn rational(numerator: i64, denominator: i64) -> Rational {
+    Rational::new(numerator, denominator).unwrap()
+}
+
+/// Sorts complex numbers by real part, then imaginary part
+fn sorted(mut values: Vec<Complex>) -> Vec<Complex> {
+    values.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+    values
+}
+
+fn assert_complex_close(actual: &[Complex], expected: &[Complex], tolerance: f64) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((*a - *e).abs() <= tolerance, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_polynomial_arithmetic() {
+    // p = 1 - 3x + 2x², q = x - 1
+    let p = Polynomial::new(vec![
+        Rational::from(1),
+        Rational::from(-3),
+        Rational::from(2),
+    ]);
+    let q = Polynomial::new(vec![Rational::from(-1), Rational::from(1)]);
+    assert_eq!(p.degree(), Some(2));
+    assert_eq!(p.evaluate(&rational(1, 2)), Rational::from(0));
+    assert_eq!(p.evaluate(&Rational::from(3)), Rational::from(10));
+    assert_eq!(p.to_string(), "2x^2 - 3x + 1");
+    assert_eq!(
+        (p.clone() + q.clone()).coefficients(),
+        &[Rational::from(0), Rational::from(-2), Rational::from(2)]
+    );
+    assert_eq!((p.clone() - p.clone()), Polynomial::zero());
+    assert_eq!((p.clone() - p.clone()).degree(), None);
+    assert_eq!(
+        p.clone() * q.clone(),
+        Polynomial::from_roots(&[Rational::from(1), rational(1, 2), Rational::from(1)])
+            * Polynomial::new(vec![Rational::from(2)])
+    );
+    assert_eq!(
+        p.derivative(),
+        Polynomial::new(vec![Rational::from(-3), Rational::from(4)])
+    );
+    assert_eq!(
+        Polynomial::new(vec![Rational::from(5)]).derivative(),
+        Polynomial::zero()
+    );
+
+    // 2x² - 3x + 1 = (x - 1)(2x - 1), and x² + 1 = (x - 1)(x + 1) + 2
+    let (quotient, remainder) = p.div_rem(&q).unwrap();
+    assert_eq!(
+        quotient,
+        Polynomial::new(vec![Rational::from(-1), Rational::from(2)])
+    );
+    assert!(remainder.is_zero());
+    let r = Polynomial::new(vec![
+        Rational::from(1),
+        Rational::from(0),
+        Rational::from(1),
+    ]);
+    let (quotient, remainder) = r.div_rem(&q).unwrap();
+    assert_eq!(quotient.to_string(), "x + 1");
+    assert_eq!(remainder.to_string(), "2");
+    let (quotient, remainder) = q.div_rem(&r).unwrap();
+    assert_eq!((quotient, remainder), (Polynomial::zero(), q.clone()));
+    assert_eq!(
+        p.div_rem(&Polynomial::zero()).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "divisor",
+            value: 0.0
+        }
+    );
+    // x^2 = (x + max)(x - max) + max^2, whose remainder does not fit
+    assert_eq!(
+        Polynomial::new(vec![
+            Rational::from(0),
+            Rational::from(0),
+            Rational::from(1)
+        ])
+        .div_rem(&Polynomial::new(vec![
+            Rational::from(-i64::MAX),
+            Rational::from(1)
+        ]))
+        .unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+
+    // Trailing zeros are dropped
+    assert_eq!(
+        Polynomial::new(vec![1.0, -1.0, 0.0, 0.0]).to_string(),
+        "-x + 1"
+    );
+    assert_eq!(
+        Polynomial::new(vec![0.0, 0.5, 0.0, -1.0]).to_string(),
+        "-x^3 + 0.5x"
+    );
+    assert_eq!(
+        Polynomial::new(vec![1.0, 2.0]).evaluate_vector(&LalgrsVector::new(vec![0.0, 1.0, 2.0])),
+        LalgrsVector::new(vec![1.0, 3.0, 5.0])
+    );
+}
+
+#[test]
+fn test_polynomial_fit() {
+    // Points exactly on 1 - 2x + 0.5x³
+    let x = LalgrsVector::new(vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]);
+    let exact = Polynomial::new(vec![1.0, -2.0, 0.0, 0.5]);
+    let y = exact.evaluate_vector(&x);
+    let fit = Polynomial::fit(&x, &y, 3).unwrap();
+    for (a, e) in fit.coefficients().iter().zip(exact.coefficients()) {
+        assert!((a - e).abs() < 1e-10, "{fit:?}");
+    }
+    assert_eq!(
+        LalgrsMatrix::vandermonde(&LalgrsVector::new(vec![2.0, 3.0]), 3),
+        LalgrsMatrix::from_rows(vec![vec![1.0, 2.0, 4.0], vec![1.0, 3.0, 9.0]]).unwrap()
+    );
+
+    // Least squares line through (0, 0), (1, 1), (2, 1), (3, 2): y = 0.1 + 0.6x
+    let x = LalgrsVector::new(vec![0.0, 1.0, 2.0, 3.0]);
+    let y = LalgrsVector::new(vec![0.0, 1.0, 1.0, 2.0]);
+    let line = Polynomial::fit(&x, &y, 1).unwrap();
+    assert!((line.coefficients()[0] - 0.1).abs() < 1e-12);
+    assert!((line.coefficients()[1] - 0.6).abs() < 1e-12);
+
+    assert_eq!(
+        Polynomial::fit(&x, &LalgrsVector::new(vec![1.0]), 1).unwrap_err(),
+        LalgrsError::MismatchedVectorDimensions {
+            vector1: 4,
+            vector2: 1
+        }
+    );
+    assert_eq!(
+        Polynomial::fit(&x, &y, 4).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "degree",
+            value: 4.0
+        }
+    );
+}
+
+#[test]
+fn test_polynomial_roots() {
+    let cubic = Polynomial::from_roots(&[3.0, -1.0, 2.0]);
+    assert_complex_close(
+        &sorted(cubic.roots().unwrap()),
+        &[Complex::from(-1.0), Complex::from(2.0), Complex::from(3.0)],
+        1e-12,
+    );
+
+    // x⁴ + 1 has the four primitive eighth roots of unity as roots
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    assert_complex_close(
+        &sorted(
+            Polynomial::new(vec![1.0, 0.0, 0.0, 0.0, 1.0])
+                .roots()
+                .unwrap(),
+        ),
+        &[
+            Complex::new(-h, -h),
+            Complex::new(-h, h),
+            Complex::new(h, -h),
+            Complex::new(h, h),
+        ],
+        1e-12,
+    );
+
+    // x²(x - 1)(x² + 4): the zero roots are exact
+    let p = Polynomial::new(vec![0.0, 0.0, -4.0, 4.0, -1.0, 1.0]);
+    let roots = p.roots().unwrap();
+    assert_eq!(&roots[..2], &[Complex::default(), Complex::default()]);
+    assert_complex_close(
+        &sorted(roots[2..].to_vec()),
+        &[
+            Complex::new(0.0, -2.0),
+            Complex::new(0.0, 2.0),
+            Complex::from(1.0),
+        ],
+        1e-12,
+    );
+
+    assert_eq!(Polynomial::new(vec![2.0]).roots().unwrap(), vec![]);
+    assert_eq!(Polynomial::zero().roots().unwrap(), vec![]);
+}
+
+#[test]
+fn test_random_polynomial_roots() {
+    let mut rng = Rng::seed_from_u64(45);
+    for degree in [5, 12, 20] {
+        let coefficients: Vec<f64> = (0..=degree).map(|_| rng.normal()).collect();
+        let p = Polynomial::new(coefficients);
+        let roots = p.roots().unwrap();
+        assert_eq!(roots.len(), degree);
+        let scale: f64 = p.coefficients().iter().map(|c| c.abs()).sum();
+        for root in roots {
+            let value = p.evaluate_complex(root);
+            let magnitude = root.abs().max(1.0).powi(degree as i32);
+            assert!(value.abs() < 1e-10 * scale * magnitude, "{root}: {value}");
+        }
+    }
+}
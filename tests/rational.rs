@@ -0,0 +1,60 @@
+use lalgrs::{LalgrsError, LalgrsMatrix, One, Rational, Zero};
+
+fn r(numerator: i64, denominator: i64) -> Rational {
+    Rational::new(numerator, denominator).unwrap()
+}
+
+#[test]
+fn test_rational_normalisation() {
+    let half = r(-3, -6);
+    assert_eq!((half.numerator(), half.denominator()), (1, 2));
+    assert_eq!(r(4, -6), r(-2, 3));
+    assert_eq!(r(0, -5), Rational::zero());
+    assert_eq!("6/4".parse::<Rational>().unwrap(), r(3, 2));
+    assert_eq!(" -7 ".parse::<Rational>().unwrap(), Rational::from(-7));
+    assert_eq!(
+        Rational::new(1, 0).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "denominator",
+            value: 0.0
+        }
+    );
+    assert_eq!(
+        Rational::new(i64::MIN, -1).unwrap_err(),
+        LalgrsError::ArithmeticOverflow
+    );
+    assert_eq!(Rational::new(i64::MIN, -2).unwrap(), r(1 << 62, 1));
+    assert_eq!(
+        "1/0".parse::<Rational>().unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "denominator",
+            value: 0.0
+        }
+    );
+    assert_eq!(
+        "a/2".parse::<Rational>().unwrap_err(),
+        LalgrsError::InvalidRational("a/2".to_string())
+    );
+}
+
+#[test]
+fn test_rational_arithmetic() {
+    assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+    assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+    assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+    assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+    assert_eq!(-r(2, 3), r(-2, 3));
+    assert!(r(1, 3) < r(1, 2) && r(-1, 2) < r(-1, 3));
+    assert_eq!(r(3, 4).recip(), Some(r(4, 3)));
+    assert_eq!(Rational::zero().recip(), None);
+    assert_eq!(Rational::one().checked_div(&Rational::zero()), None);
+    assert_eq!(Rational::from(i64::MAX).checked_add(&Rational::one()), None);
+    assert_eq!(r(1, 4).to_f64(), 0.25);
+    assert_eq!(r(-5, 3).to_string(), "-5/3");
+    assert_eq!(r(8, 4).to_string(), "2");
+
+    // Rational matrices use the generic operators
+    let matrix = LalgrsMatrix::from_rows(vec![vec![r(1, 2), r(1, 3)]]).unwrap();
+    let doubled = (matrix.clone() + matrix).unwrap();
+    assert_eq!(doubled[(0, 1)], r(2, 3));
+}
@@ -0,0 +1,137 @@
+use lalgrs::{Complex, LalgrsError, LalgrsMatrix, Rng};
+
+fn assert_orthogonal(q: &LalgrsMatrix<f64>, tolerance: f64) {
+    let n = q.rows();
+    let product = (q.transpose() * q).unwrap();
+    assert!(product.approx_eq(&LalgrsMatrix::identity(n), tolerance, 0.0));
+}
+
+/// Matrix with the real eigenvalue `-1`, the complex pairs `1 ± 2i` and `-3 ± i`, and the real eigenvalues `0.5` and `4`
+fn mixed_spectrum(rng: &mut Rng) -> LalgrsMatrix<f64> {
+    let d = LalgrsMatrix::from_rows(vec![
+        vec![-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        vec![0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 0.0],
+        vec![0.0, -2.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+        vec![0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0],
+        vec![0.0, 0.0, 0.0, 0.0, -3.0, 1.0, 0.0],
+        vec![0.0, 0.0, 0.0, 0.0, -1.0, -3.0, 0.0],
+        vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 4.0],
+    ])
+    .unwrap();
+    let v = LalgrsMatrix::random_normal(7, 7, 0.0, 1.0, rng);
+    ((v.clone() * d).unwrap() * v.inverse().unwrap()).unwrap()
+}
+
+fn assert_quasi_triangular(t: &LalgrsMatrix<f64>) {
+    let n = t.rows();
+    for j in 0..n {
+        for i in j + 2..n {
+            assert_eq!(t[(i, j)], 0.0);
+        }
+        // Blocks do not overlap, and 2x2 blocks have equal diagonal elements
+        if j + 1 < n && t[(j + 1, j)] != 0.0 {
+            assert!(j + 2 >= n || t[(j + 2, j + 1)] == 0.0);
+            assert!((t[(j, j)] - t[(j + 1, j + 1)]).abs() < 1e-12 * t.frobenius_norm());
+            assert!(t[(j, j + 1)] * t[(j + 1, j)] < 0.0);
+        }
+    }
+}
+
+#[test]
+fn test_hessenberg() {
+    let mut rng = Rng::seed_from_u64(49);
+    let a = LalgrsMatrix::random_normal(6, 6, 0.0, 1.0, &mut rng);
+    let hessenberg = a.hessenberg().unwrap();
+    assert_orthogonal(&hessenberg.q, 1e-13);
+    assert!(hessenberg.reconstruct().approx_eq(&a, 1e-12, 0.0));
+    for j in 0..6 {
+        for i in j + 2..6 {
+            assert_eq!(hessenberg.h[(i, j)], 0.0);
+        }
+    }
+
+    assert!(matches!(
+        LalgrsMatrix::<f64>::zeros(2, 3).hessenberg(),
+        Err(LalgrsError::NonSquareMatrix { .. })
+    ));
+}
+
+#[test]
+fn test_real_and_complex_schur() {
+    let mut rng = Rng::seed_from_u64(49);
+    let a = mixed_spectrum(&mut rng);
+    let schur = a.schur().unwrap();
+    assert_orthogonal(&schur.q, 1e-12);
+    assert_quasi_triangular(&schur.t);
+    assert!(schur.reconstruct().approx_eq(&a, 1e-10, 0.0));
+
+    let mut eigenvalues = schur.eigenvalues();
+    eigenvalues.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+    let expected = [
+        Complex::new(-3.0, -1.0),
+        Complex::new(-3.0, 1.0),
+        Complex::from(-1.0),
+        Complex::from(0.5),
+        Complex::new(1.0, -2.0),
+        Complex::new(1.0, 2.0),
+        Complex::from(4.0),
+    ];
+    for (e, x) in eigenvalues.iter().zip(&expected) {
+        assert!((*e - *x).abs() < 1e-9, "{e} != {x}");
+    }
+
+    let complex = schur.to_complex();
+    assert_eq!(a.complex_schur().unwrap(), complex);
+    let n = a.rows();
+    for j in 0..n {
+        for i in j + 1..n {
+            assert_eq!(complex.t[(i, j)], Complex::default());
+        }
+    }
+    let reconstructed = complex.reconstruct();
+    for i in 0..n {
+        for j in 0..n {
+            assert!((reconstructed[(i, j)] - Complex::from(a[(i, j)])).abs() < 1e-10);
+        }
+    }
+    for (z, e) in complex.eigenvalues().iter().zip(schur.eigenvalues()) {
+        assert!((*z - e).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_schur_reordering() {
+    let mut rng = Rng::seed_from_u64(50);
+    let a = mixed_spectrum(&mut rng);
+
+    // Stable eigenvalues first, as needed for the invariant subspaces of Riccati equations
+    let mut schur = a.schur().unwrap();
+    let selected = schur.reorder(|z| z.re < 0.0).unwrap();
+    assert_eq!(selected, 3);
+    assert_orthogonal(&schur.q, 1e-12);
+    assert_quasi_triangular(&schur.t);
+    assert!(schur.reconstruct().approx_eq(&a, 1e-9, 0.0));
+    let eigenvalues = schur.eigenvalues();
+    assert!(eigenvalues[..3].iter().all(|z| z.re < 0.0));
+    assert!(eigenvalues[3..].iter().all(|z| z.re > 0.0));
+
+    // The leading columns of Q span an invariant subspace: A * Q₁ = Q₁ * T₁₁
+    let q1 = LalgrsMatrix::from_fn(7, 3, |i, j| schur.q[(i, j)]);
+    let t11 = LalgrsMatrix::from_fn(3, 3, |i, j| schur.t[(i, j)]);
+    let lhs = (a.clone() * q1.clone()).unwrap();
+    assert!(lhs.approx_eq(&(q1 * t11).unwrap(), 1e-9, 0.0));
+
+    let mut complex = a.complex_schur().unwrap();
+    assert_eq!(complex.reorder(|z| z.im > 0.0), 2);
+    let eigenvalues = complex.eigenvalues();
+    assert!(eigenvalues[..2].iter().all(|z| z.im > 0.0));
+    let reconstructed = complex.reconstruct();
+    for i in 0..7 {
+        for j in 0..7 {
+            assert!((reconstructed[(i, j)] - Complex::from(a[(i, j)])).abs() < 1e-9);
+            if i > j {
+                assert_eq!(complex.t[(i, j)], Complex::default());
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use lalgrs::{
+    LalgrsError, LalgrsMatrix, LalgrsVector, SMatrix, SMatrix2, SVector, SVector3, Shape,
+};
+
+#[test]
+fn test_static_matrix_constructors() {
+    let from_rows = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let from_columns = SMatrix::from_columns([[1, 4], [2, 5], [3, 6]]);
+    assert_eq!(from_rows, from_columns);
+    assert_eq!(from_rows.shape(), Shape::new(2, 3));
+    assert_eq!(from_rows[(1, 0)], 4);
+    assert_eq!(from_rows.get(2, 0), None);
+    assert_eq!(from_rows.row(1), Some(SVector::new([4, 5, 6])));
+    assert_eq!(from_rows.column(2), Some(SVector::new([3, 6])));
+    assert_eq!(
+        from_rows.transpose(),
+        SMatrix::from_rows([[1, 4], [2, 5], [3, 6]])
+    );
+    assert_eq!(
+        SMatrix2::<i32>::identity(),
+        SMatrix::from_diagonal(&SVector::new([1, 1]))
+    );
+}
+
+#[test]
+fn test_static_matrix_operations() {
+    let a = SMatrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+    let b = SMatrix::from_rows([[5.0, 6.0], [7.0, 8.0]]);
+    assert_eq!(a + b, SMatrix::from_rows([[6.0, 8.0], [10.0, 12.0]]));
+    assert_eq!(b - a, SMatrix::from_rows([[4.0, 4.0], [4.0, 4.0]]));
+    assert_eq!(-a, SMatrix::from_rows([[-1.0, -2.0], [-3.0, -4.0]]));
+    assert_eq!(a * 2.0, SMatrix::from_rows([[2.0, 4.0], [6.0, 8.0]]));
+    assert_eq!(a * b, SMatrix::from_rows([[19.0, 22.0], [43.0, 50.0]]));
+    assert_eq!(a * SVector::new([1.0, 1.0]), SVector::new([3.0, 7.0]));
+
+    // Non square products change the static shape
+    let row = SMatrix::from_rows([[1, 2, 3]]);
+    let column = SMatrix::from_rows([[1], [2], [3]]);
+    assert_eq!(row * column, SMatrix::from_rows([[14]]));
+    assert_eq!((column * row).shape(), Shape::new(3, 3));
+
+    // Operands are copied, not moved
+    let copy = a;
+    assert_eq!(copy * a, a * copy);
+}
+
+#[test]
+fn test_static_vector_operations() {
+    let x = SVector3::new([1.0, 0.0, 0.0]);
+    let y = SVector3::new([0.0, 1.0, 0.0]);
+    assert_eq!(x.cross(&y), SVector::new([0.0, 0.0, 1.0]));
+    assert_eq!(x.dot(&y), 0.0);
+    assert_eq!((x + y) * 2.0, SVector::new([2.0, 2.0, 0.0]));
+    assert_eq!(x - y, SVector::new([1.0, -1.0, 0.0]));
+    assert_eq!(SVector::new([3.0, 4.0]).norm(), 5.0);
+}
+
+#[test]
+fn test_static_dynamic_conversions() {
+    let fixed = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let dynamic: LalgrsMatrix<i32> = fixed.into();
+    assert_eq!(
+        dynamic,
+        LalgrsMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    );
+    assert_eq!(SMatrix::<i32, 2, 3>::try_from(&dynamic).unwrap(), fixed);
+    assert_eq!(fixed.view().to_matrix(), dynamic);
+    assert_eq!(
+        SMatrix::<i32, 3, 2>::try_from(dynamic).unwrap_err(),
+        LalgrsError::MismatchedMatrixDimensions {
+            lhs: Shape::new(2, 3),
+            rhs: Shape::new(3, 2)
+        }
+    );
+
+    let vector: LalgrsVector<i32> = SVector::new([1, 2]).into();
+    assert_eq!(vector, LalgrsVector::new(vec![1, 2]));
+    assert_eq!(
+        SVector::<i32, 2>::try_from(&vector).unwrap(),
+        SVector::new([1, 2])
+    );
+    assert_eq!(
+        SVector::<i32, 3>::try_from(vector).unwrap_err(),
+        LalgrsError::MismatchedVectorDimensions {
+            vector1: 2,
+            vector2: 3
+        }
+    );
+}
@@ -0,0 +1,177 @@
+use lalgrs::{assert_vector_approx_eq, statistics::Axis, LalgrsError, LalgrsMatrix, LalgrsVector};
+
+fn init_data() -> LalgrsMatrix<f64> {
+    // Four observations of three variables; the third is a multiple of the first
+    LalgrsMatrix::from_rows(vec![
+        vec![1.0, 4.0, -2.0],
+        vec![2.0, 1.0, -4.0],
+        vec![3.0, 3.0, -6.0],
+        vec![6.0, 0.0, -12.0],
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_mean_variance_std() {
+    let data = init_data();
+    assert_vector_approx_eq!(
+        data.mean(Axis::Columns).unwrap(),
+        LalgrsVector::new(vec![3.0, 2.0, -6.0]),
+        1e-15,
+        0.0
+    );
+    assert_vector_approx_eq!(
+        data.mean(Axis::Rows).unwrap(),
+        LalgrsVector::new(vec![1.0, -1.0 / 3.0, 0.0, -2.0]),
+        1e-15,
+        0.0
+    );
+    assert_vector_approx_eq!(
+        data.variance(Axis::Columns, 0).unwrap(),
+        LalgrsVector::new(vec![3.5, 2.5, 14.0]),
+        1e-14,
+        0.0
+    );
+    assert_vector_approx_eq!(
+        data.variance(Axis::Columns, 1).unwrap(),
+        LalgrsVector::new(vec![14.0 / 3.0, 10.0 / 3.0, 56.0 / 3.0]),
+        1e-14,
+        0.0
+    );
+    assert_vector_approx_eq!(
+        data.std(Axis::Columns, 0).unwrap(),
+        LalgrsVector::new(vec![3.5f64.sqrt(), 2.5f64.sqrt(), 14f64.sqrt()]),
+        1e-14,
+        0.0
+    );
+    assert!((data.variance(Axis::Rows, 0).unwrap().values[0] - 6.0).abs() < 1e-14);
+
+    assert!(matches!(
+        data.variance(Axis::Columns, 4),
+        Err(LalgrsError::InvalidParameter { name: "ddof", .. })
+    ));
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(0, 3).mean(Axis::Columns),
+        Err(LalgrsError::EmptyMatrix)
+    );
+}
+
+#[test]
+fn test_weighted_statistics() {
+    let data = init_data();
+    let weights = LalgrsVector::new(vec![1.0, 0.0, 0.0, 3.0]);
+    assert_vector_approx_eq!(
+        data.weighted_mean(Axis::Columns, &weights).unwrap(),
+        LalgrsVector::new(vec![4.75, 1.0, -9.5]),
+        1e-15,
+        0.0
+    );
+    // Only the first and last rows count, with deviations (-3.75, 1.25) in the first column
+    let variance = data.weighted_variance(Axis::Columns, &weights, 0).unwrap();
+    assert!((variance.values[0] - (3.75 * 3.75 + 3.0 * 1.25 * 1.25) / 4.0).abs() < 1e-14);
+    // Scaling the weights changes nothing, and unit weights give the unweighted statistics
+    let scaled = weights.map(|w| w * 10.0);
+    assert_vector_approx_eq!(
+        data.weighted_std(Axis::Columns, &scaled, 1).unwrap(),
+        data.weighted_std(Axis::Columns, &weights, 1).unwrap(),
+        1e-13,
+        0.0
+    );
+    let unit = LalgrsVector::new(vec![1.0; 4]);
+    assert_eq!(
+        data.weighted_variance(Axis::Columns, &unit, 1).unwrap(),
+        data.variance(Axis::Columns, 1).unwrap()
+    );
+
+    assert_eq!(
+        data.weighted_mean(Axis::Rows, &weights),
+        Err(LalgrsError::MismatchedVectorDimensions {
+            vector1: 4,
+            vector2: 3
+        })
+    );
+    for invalid in [vec![1.0, -1.0, 0.0, 0.0], vec![0.0; 4]] {
+        assert!(matches!(
+            data.weighted_mean(Axis::Columns, &LalgrsVector::new(invalid)),
+            Err(LalgrsError::InvalidParameter {
+                name: "weights",
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn test_median_and_quantile() {
+    let data = init_data();
+    assert_vector_approx_eq!(
+        data.median(Axis::Columns).unwrap(),
+        LalgrsVector::new(vec![2.5, 2.0, -5.0]),
+        1e-15,
+        0.0
+    );
+    assert_vector_approx_eq!(
+        data.median(Axis::Rows).unwrap(),
+        LalgrsVector::new(vec![1.0, 1.0, 3.0, 0.0]),
+        1e-15,
+        0.0
+    );
+    assert_vector_approx_eq!(
+        data.quantile(Axis::Columns, 0.0).unwrap(),
+        LalgrsVector::new(vec![1.0, 0.0, -12.0]),
+        0.0,
+        0.0
+    );
+    assert_vector_approx_eq!(
+        data.quantile(Axis::Columns, 1.0).unwrap(),
+        LalgrsVector::new(vec![6.0, 4.0, -2.0]),
+        0.0,
+        0.0
+    );
+    // Position 0.25 * 3 = 0.75 between the first and second smallest elements
+    assert_vector_approx_eq!(
+        data.quantile(Axis::Columns, 0.25).unwrap(),
+        LalgrsVector::new(vec![1.75, 0.75, -7.5]),
+        1e-15,
+        0.0
+    );
+    assert!(matches!(
+        data.quantile(Axis::Columns, 1.5),
+        Err(LalgrsError::InvalidParameter { name: "q", .. })
+    ));
+    assert!(data.quantile(Axis::Columns, f64::NAN).is_err());
+}
+
+#[test]
+fn test_covariance_and_correlation() {
+    let data = init_data();
+    let covariance = data.covariance_matrix(1).unwrap();
+    assert_eq!(covariance.shape().rows, 3);
+    assert_vector_approx_eq!(
+        LalgrsVector::new(vec![
+            covariance[(0, 0)],
+            covariance[(1, 1)],
+            covariance[(2, 2)],
+        ]),
+        data.variance(Axis::Columns, 1).unwrap(),
+        1e-14,
+        0.0
+    );
+    // Σ (x - 3)(y - 2) = (-2)(2) + (-1)(-1) + 0 + 3(-2) = -9
+    assert!((covariance[(0, 1)] + 3.0).abs() < 1e-14);
+    assert_eq!(covariance[(0, 1)], covariance[(1, 0)]);
+
+    let correlation = data.correlation_matrix().unwrap();
+    assert_eq!(correlation[(0, 0)], 1.0);
+    assert_eq!(correlation[(0, 2)], -1.0);
+    assert!((correlation[(0, 1)] + 3.0 / (14f64 / 3.0 * 10.0 / 3.0).sqrt()).abs() < 1e-14);
+
+    let constant = LalgrsMatrix::from_rows(vec![vec![1.0, 5.0], vec![2.0, 5.0]]).unwrap();
+    assert!(constant.correlation_matrix().unwrap()[(0, 1)].is_nan());
+    assert!(matches!(
+        LalgrsMatrix::from_rows(vec![vec![1.0, 2.0]])
+            .unwrap()
+            .correlation_matrix(),
+        Err(LalgrsError::InvalidParameter { name: "ddof", .. })
+    ));
+}
@@ -0,0 +1,125 @@
+use lalgrs::{assert_matrix_approx_eq, LalgrsError, LalgrsMatrix, LalgrsVector, Rng};
+
+fn assert_orthonormal_columns(matrix: &LalgrsMatrix<f64>) {
+    let gram = (matrix.transpose() * matrix.clone()).unwrap();
+    assert_matrix_approx_eq!(gram, LalgrsMatrix::identity(matrix.columns()), 1e-12, 0.0);
+}
+
+fn init_rank_two() -> LalgrsMatrix<f64> {
+    // 3x5 matrix whose third row is the sum of the first two
+    LalgrsMatrix::from_rows(vec![
+        vec![1.0, 2.0, 0.0, -1.0, 3.0],
+        vec![0.0, 1.0, 1.0, 2.0, -1.0],
+        vec![1.0, 3.0, 1.0, 1.0, 2.0],
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_pseudo_inverse_penrose_conditions() {
+    let a = init_rank_two();
+    let pinv = a.pseudo_inverse(None).unwrap();
+    assert_eq!(pinv.shape(), (5, 3).into());
+    let a_pinv = (a.clone() * pinv.clone()).unwrap();
+    let pinv_a = (pinv.clone() * a.clone()).unwrap();
+    assert_matrix_approx_eq!((a_pinv.clone() * a.clone()).unwrap(), a, 1e-12, 0.0);
+    assert_matrix_approx_eq!((pinv_a.clone() * pinv.clone()).unwrap(), pinv, 1e-12, 0.0);
+    assert_matrix_approx_eq!(a_pinv.transpose(), a_pinv, 1e-12, 0.0);
+    assert_matrix_approx_eq!(pinv_a.transpose(), pinv_a, 1e-12, 0.0);
+
+    // For an invertible matrix the pseudo-inverse is the inverse
+    let mut rng = Rng::seed_from_u64(1);
+    let square = LalgrsMatrix::random_spd(4, &mut rng);
+    let inverse = square.pseudo_inverse(None).unwrap();
+    assert_matrix_approx_eq!(
+        (square * inverse).unwrap(),
+        LalgrsMatrix::identity(4),
+        1e-12,
+        0.0
+    );
+}
+
+#[test]
+fn test_minimum_norm_solution() {
+    // x + y = 2 has minimum norm solution (1, 1)
+    let a = LalgrsMatrix::from_rows(vec![vec![1.0, 1.0]]).unwrap();
+    let x = (a.pseudo_inverse(None).unwrap() * LalgrsVector::new(vec![2.0])).unwrap();
+    assert!((x.values[0] - 1.0).abs() < 1e-12 && (x.values[1] - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_subspace_dimensions() {
+    let a = init_rank_two();
+    assert_eq!(a.rank(None).unwrap(), 2);
+
+    let column_space = a.column_space(None).unwrap();
+    let row_space = a.row_space(None).unwrap();
+    let null_space = a.null_space(None).unwrap();
+    let left_null_space = a.left_null_space(None).unwrap();
+    assert_eq!(column_space.shape(), (3, 2).into());
+    assert_eq!(row_space.shape(), (5, 2).into());
+    assert_eq!(null_space.shape(), (5, 3).into());
+    assert_eq!(left_null_space.shape(), (3, 1).into());
+    for basis in [&column_space, &row_space, &null_space, &left_null_space] {
+        assert_orthonormal_columns(basis);
+    }
+
+    assert_matrix_approx_eq!(
+        (a.clone() * null_space.clone()).unwrap(),
+        LalgrsMatrix::zeros(3, 3),
+        1e-12,
+        0.0
+    );
+    assert_matrix_approx_eq!(
+        (a.transpose() * left_null_space.clone()).unwrap(),
+        LalgrsMatrix::zeros(5, 1),
+        1e-12,
+        0.0
+    );
+    assert_matrix_approx_eq!(
+        (row_space.transpose() * null_space).unwrap(),
+        LalgrsMatrix::zeros(2, 3),
+        1e-12,
+        0.0
+    );
+    // The left null space is spanned by (1, 1, -1) / sqrt(3)
+    let y = left_null_space[(0, 0)].signum() / 3f64.sqrt();
+    assert_matrix_approx_eq!(
+        left_null_space,
+        LalgrsMatrix::from_rows(vec![vec![y], vec![y], vec![-y]]).unwrap(),
+        1e-12,
+        0.0
+    );
+}
+
+#[test]
+fn test_tolerance() {
+    let nearly_singular = LalgrsMatrix::from_rows(vec![vec![1.0, 0.0], vec![0.0, 1e-9]]).unwrap();
+    assert_eq!(nearly_singular.rank(None).unwrap(), 2);
+    assert_eq!(nearly_singular.rank(Some(1e-6)).unwrap(), 1);
+    assert_eq!(
+        nearly_singular.null_space(None).unwrap().shape(),
+        (2, 0).into()
+    );
+    assert_eq!(
+        nearly_singular.pseudo_inverse(Some(1e-6)).unwrap(),
+        LalgrsMatrix::from_rows(vec![vec![1.0, 0.0], vec![0.0, 0.0]]).unwrap()
+    );
+    let null_space = nearly_singular.null_space(Some(1e-6)).unwrap();
+    assert_eq!(null_space.shape(), (2, 1).into());
+    assert!((null_space[(1, 0)].abs() - 1.0).abs() < 1e-12);
+
+    assert_eq!(
+        nearly_singular.null_space(Some(-1.0)).unwrap_err(),
+        LalgrsError::InvalidParameter {
+            name: "tolerance",
+            value: -1.0
+        }
+    );
+    assert_eq!(
+        LalgrsMatrix::<f64>::zeros(0, 0)
+            .pseudo_inverse(None)
+            .unwrap_err(),
+        LalgrsError::EmptyMatrix
+    );
+}
@@ -75,3 +75,56 @@ fn test_vector_sub_empty() {
     assert_eq!((empty_vec.clone() - vec2.clone()).unwrap(), -vec2);
     assert_eq!((empty_vec.clone() - empty_vec.clone()).unwrap(), empty_vec);
 }
+
+#[test]
+fn test_vector_iteration() {
+    let mut vec = LalgrsVector::new(vec![1.0, 2.0, 3.0]);
+    // Iterating by reference leaves the vector intact
+    assert_eq!(vec.iter().sum::<f64>(), 6.0);
+    let mut total = 0.0;
+    for value in &vec {
+        total += value;
+    }
+    assert_eq!(total, 6.0);
+    assert_eq!(vec.size(), 3);
+
+    for value in vec.iter_mut() {
+        *value *= 2.0;
+    }
+    for value in &mut vec {
+        *value += 1.0;
+    }
+    assert_eq!(vec, LalgrsVector::new(vec![3.0, 5.0, 7.0]));
+
+    let owned: Vec<f64> = vec.clone().into_iter().collect();
+    assert_eq!(owned, vec![3.0, 5.0, 7.0]);
+
+    let collected: LalgrsVector<i32> = (1..=3).collect();
+    assert_eq!(collected, LalgrsVector::new(vec![1, 2, 3]));
+    let mut extended = collected.clone();
+    extended.extend(vec![4, 5]);
+    extended.extend(&[6]);
+    assert_eq!(extended, LalgrsVector::new(vec![1, 2, 3, 4, 5, 6]));
+}
+
+#[test]
+fn test_vector_map_and_fold() {
+    let (vec1, vec2) = init_vecs_size_3();
+    assert_eq!(vec1.map(|v| v * v), LalgrsVector::new(vec![1.0, 4.0, 9.0]));
+    assert_eq!(vec1.map(|v| *v as i64), LalgrsVector::new(vec![1, 2, 3]));
+    assert_eq!(
+        vec1.zip_map(&vec2, |a, b| a * b).unwrap(),
+        LalgrsVector::new(vec![2.0, 6.0, 12.0])
+    );
+    assert_eq!(vec1.fold(0.0, |acc, v| acc + v * v), 14.0);
+    assert_eq!(vec1.fold(f64::MIN, |acc, v| acc.max(*v)), 3.0);
+
+    let (vec_size_2, _) = init_vecs_size_2();
+    assert_eq!(
+        vec1.zip_map(&vec_size_2, |a, b| a + b).unwrap_err(),
+        LalgrsError::MismatchedVectorDimensions {
+            vector1: 3,
+            vector2: 2
+        }
+    );
+}